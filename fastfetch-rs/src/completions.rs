@@ -0,0 +1,90 @@
+//! Shell completion scripts for the `completions` subcommand
+
+use clap::{Arg, Command, CommandFactory, ValueEnum, builder::PossibleValuesParser};
+use clap_complete::{Generator, Shell};
+use clap_complete_nushell::Nushell;
+use libfastfetch::{ModuleKind, logo::database::LOGOS};
+use std::io;
+
+/// Shells completion scripts can be generated for
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Nushell,
+    Powershell,
+    Elvish,
+}
+
+/// Write the completion script for `shell` to stdout
+pub fn print<C: CommandFactory>(shell: CompletionShell) {
+    let mut command = with_value_hints(C::command());
+    let name = command.get_name().to_string();
+    let mut out = io::stdout();
+
+    match shell {
+        CompletionShell::Bash => generate(Shell::Bash, &mut command, &name, &mut out),
+        CompletionShell::Zsh => generate(Shell::Zsh, &mut command, &name, &mut out),
+        CompletionShell::Fish => generate(Shell::Fish, &mut command, &name, &mut out),
+        CompletionShell::Powershell => generate(Shell::PowerShell, &mut command, &name, &mut out),
+        CompletionShell::Elvish => generate(Shell::Elvish, &mut command, &name, &mut out),
+        CompletionShell::Nushell => generate(Nushell, &mut command, &name, &mut out),
+    }
+}
+
+fn generate(generator: impl Generator, command: &mut Command, name: &str, out: &mut io::Stdout) {
+    clap_complete::generate(generator, command, name, out);
+}
+
+/// Offer candidates for arguments parsed from free-form strings
+///
+/// `--modules` accepts any name at runtime so unknown ones can be skipped
+/// with a warning, and the others are parsed with `FromStr`; the candidates
+/// are only added for completion.
+fn with_value_hints(command: Command) -> Command {
+    let hint = |values: Vec<&'static str>| {
+        move |arg: Arg| arg.value_parser(PossibleValuesParser::new(values))
+    };
+
+    let module_ids = || ModuleKind::all().iter().map(|kind| kind.id()).collect();
+    command
+        .mut_arg("modules", hint(module_ids()))
+        .mut_arg("help_module", hint(module_ids()))
+        .mut_arg("logo", hint(LOGOS.iter().map(|logo| logo.name).collect()))
+        .mut_arg(
+            "format",
+            hint(vec!["text", "json", "yaml", "toml", "pipe", "csv"]),
+        )
+        .mut_arg("color", hint(vec!["auto", "always", "never"]))
+        .mut_arg("key_align", hint(vec!["left", "right", "none"]))
+        .mut_arg("icons", hint(vec!["none", "nerd", "emoji"]))
+        .mut_arg("layout", hint(vec!["side", "top", "grid"]))
+        .mut_arg("logo_position", hint(vec!["left", "right", "bottom"]))
+        .mut_arg("temperature_unit", hint(vec!["celsius", "fahrenheit"]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_hints() {
+        let command = with_value_hints(crate::Args::command());
+        command.clone().debug_assert();
+
+        let modules = command
+            .get_arguments()
+            .find(|arg| arg.get_id() == "modules")
+            .unwrap();
+        let values = modules.get_possible_values();
+        assert_eq!(values.len(), ModuleKind::all().len());
+        assert!(values.iter().any(|value| value.matches("cputemp", false)));
+
+        let matches = command.get_matches_from(["fastfetch-rs", "--format", "csv"]);
+        assert_eq!(
+            matches.get_one::<String>("format").map(String::as_str),
+            Some("csv")
+        );
+    }
+}
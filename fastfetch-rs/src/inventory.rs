@@ -0,0 +1,106 @@
+//! Querying several machines for the `inventory` subcommand
+//!
+//! Every host runs fastfetch-rs itself with `--format json`, over ssh in
+//! batch mode so a host asking for a password fails instead of hanging. Hosts are
+//! queried in parallel; the host `local` is this machine, run without ssh.
+
+use libfastfetch::output::HostReport;
+use rayon::prelude::*;
+use std::process::{Command, Output};
+
+/// Host name that stands for this machine
+pub const LOCAL_HOST: &str = "local";
+
+/// Seconds ssh waits for a host to answer
+const CONNECT_TIMEOUT: u32 = 10;
+
+/// Hosts listed one per line; blank lines and `#` comments are skipped
+pub fn parse_hosts(content: &str) -> Result<Vec<String>, String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(|host| parse_host(host).map_err(|err| format!("Invalid host '{host}': {err}")))
+        .collect()
+}
+
+/// A host as given to ssh, which would read one starting with `-` as an option
+pub fn parse_host(host: &str) -> Result<String, String> {
+    match host.starts_with('-') {
+        true => Err("hosts can't start with '-'".to_string()),
+        false => Ok(host.to_string()),
+    }
+}
+
+/// Query every host with `remote_command`, passing `modules` along
+pub fn query(hosts: &[String], remote_command: &str, modules: Option<&str>) -> Vec<HostReport> {
+    hosts
+        .par_iter()
+        .map(|host| query_host(host, remote_command, modules))
+        .collect()
+}
+
+fn query_host(host: &str, remote_command: &str, modules: Option<&str>) -> HostReport {
+    let mut args = vec!["--format", "json"];
+    if let Some(modules) = modules {
+        args.extend(["--modules", modules]);
+    }
+
+    let output = if host == LOCAL_HOST {
+        std::env::current_exe().and_then(|exe| Command::new(exe).args(&args).output())
+    } else {
+        // ssh hands the remote shell one line, so the arguments are quoted
+        // to reach fastfetch-rs as they are
+        let quoted: Vec<_> = args.iter().map(|arg| shell_quote(arg)).collect();
+        let command = format!("{remote_command} {}", quoted.join(" "));
+        let timeout = format!("ConnectTimeout={CONNECT_TIMEOUT}");
+        Command::new("ssh")
+            .args(["-o", "BatchMode=yes", "-o", &timeout, host, "--", &command])
+            .output()
+    };
+
+    match output {
+        Err(err) => HostReport::failed(host, format!("Failed to run ssh: {err}")),
+        Ok(output) if !output.status.success() => HostReport::failed(host, failure(&output)),
+        Ok(output) => HostReport::from_json(host, &String::from_utf8_lossy(&output.stdout)),
+    }
+}
+
+/// `arg` in single quotes for a POSIX shell
+fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// The last thing a failed command printed, or its exit status
+fn failure(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map_or_else(|| output.status.to_string(), str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hosts() {
+        let content = "# homelab\nnas\n\n  admin@pi  # in the garage\n";
+        assert_eq!(parse_hosts(content).unwrap(), ["nas", "admin@pi"]);
+        assert!(parse_hosts("nas\n-oProxyCommand=sh\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_host() {
+        assert_eq!(parse_host("admin@nas"), Ok("admin@nas".to_string()));
+        assert!(parse_host("-oProxyCommand=touch /tmp/owned").is_err());
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("os,kernel"), "'os,kernel'");
+        assert_eq!(shell_quote("os; rm -rf ~"), "'os; rm -rf ~'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    }
+}
@@ -1,15 +1,57 @@
-use clap::Parser;
-use libfastfetch::{Application, Config, ModuleKind};
+mod completions;
+mod inventory;
+mod manpage;
+mod tui;
+
+use anyhow::{Context, bail};
+use clap::{
+    Parser, Subcommand,
+    builder::{PossibleValue, PossibleValuesParser},
+};
+use completions::CompletionShell;
+use libfastfetch::{
+    Application, Color, Config, ConfigBuilder, ConfigFile, ConfigFormat, DetectionResult, IconSet,
+    KeyAlign, Layout, ModuleKind, OutputFormat, RealSystemContext, RunReport, TemperatureUnit,
+    Template, TextStyle,
+    config::{
+        PRESETS, Preset,
+        check::{check_file, check_source},
+        file::ModuleSpec,
+        generate_config,
+        migrate::Migration,
+    },
+    logo::{Logo, LogoPosition, database::LOGOS},
+    modules::create_module,
+    output::{
+        ColorMode, check_width, colors_enabled, render_inventory, set_colors_enabled,
+        snapshot::{Snapshot, render_diff},
+        terminal_width,
+    },
+    platform::xdg,
+};
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 /// A fast system information tool written in Rust
+///
+/// Settings are read from config.jsonc (or config.toml) in $XDG_CONFIG_HOME/fastfetch-rs,
+/// ~/.config/fastfetch-rs or /etc/fastfetch-rs when one exists; command line flags
+/// override them.
 #[derive(Parser, Debug)]
 #[command(name = "fastfetch-rs")]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// List of modules to display (comma-separated)
     ///
-    /// Available modules: os, host, kernel, cpu, memory
-    /// If not specified, all modules will be displayed
+    /// Use --list-modules to see the available modules.
+    /// If not specified, all modules except the opt-in ones will be
+    /// displayed: sampling modules such as diskio, layout modules such as
+    /// separator, and custom and text, which need settings of their own.
+    /// --list-modules marks them [opt-in].
     #[arg(short, long, value_delimiter = ',')]
     modules: Option<Vec<String>>,
 
@@ -21,26 +63,566 @@ struct Args {
     #[arg(long)]
     values_only: bool,
 
+    /// Write the results as text, json, yaml, toml, pipe or csv [default: text]
+    ///
+    /// json, yaml and toml give one record per module with its id, label, status and
+    /// value or error, without the logo or colors. pipe is the same as --pipe.
+    /// csv has a row per module with its id, label, value, status and
+    /// detection time.
+    #[arg(long, value_name = "FORMAT")]
+    format: Option<OutputFormat>,
+
+    /// Print plain "id=value" lines without logo, colors or padding, for scripts
+    #[arg(long, conflicts_with = "format")]
+    pipe: bool,
+
+    /// When to use colors: auto, always or never
+    ///
+    /// auto colors a terminal but not a pipe or file, and follows the
+    /// NO_COLOR and CLICOLOR_FORCE environment variables.
+    #[arg(long, value_name = "WHEN", default_value_t = ColorMode::Auto)]
+    color: ColorMode,
+
+    /// Leave out modules whose detection failed instead of printing the error
+    #[arg(long)]
+    hide_errors: bool,
+
+    /// Leave out modules with nothing to show instead of printing "Not available"
+    #[arg(long)]
+    hide_unavailable: bool,
+
+    /// Show how long each module's detection took, and the total
+    #[arg(long)]
+    stat: bool,
+
+    /// End with two rows of the terminal's colors
+    #[arg(long)]
+    color_blocks: bool,
+
+    /// Draw a box around the modules, with the title in its top border
+    #[arg(long)]
+    boxed: bool,
+
+    /// Logo and module arrangement: side, top (logo above) or grid (two columns) [default: side]
+    #[arg(long, value_name = "LAYOUT")]
+    layout: Option<Layout>,
+
+    /// Clear the screen and refresh every SECONDS, like a system monitor
+    ///
+    /// Modules that don't change while running, such as OS or CPU model,
+    /// are detected once; the rest are detected again on every refresh.
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    live: Option<u64>,
+
+    /// Print each module as soon as it and the ones before it are detected
+    ///
+    /// Slow modules then don't hold back the first lines. The box is left out
+    /// and the grid layout shows one column, since both need every line first.
+    #[arg(long, conflicts_with_all = ["live", "diff", "format", "pipe"])]
+    stream: bool,
+
+    /// Save the results to FILE, as YAML or as TOML for a .toml file, for a later --diff
+    #[arg(long, value_name = "FILE")]
+    snapshot: Option<PathBuf>,
+
+    /// Show what changed since a --snapshot instead of the usual output
+    ///
+    /// Exits with status 1 if anything changed. Modules whose values always
+    /// move, like uptime, are best left out with --modules.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["live", "format", "pipe"])]
+    diff: Option<PathBuf>,
+
+    /// Show this distro's logo instead of the detected one, e.g. arch
+    ///
+    /// Case, spaces and dashes don't matter and unambiguous abbreviations work.
+    #[arg(long, value_name = "NAME")]
+    logo: Option<String>,
+
+    /// Show ASCII art from a file instead of a distro logo; ANSI color escapes are kept
+    #[arg(long, value_name = "PATH", conflicts_with = "logo")]
+    logo_source: Option<PathBuf>,
+
+    /// Put the logo on the left or right of the modules, or under them [default: left]
+    #[arg(long, value_name = "POSITION")]
+    logo_position: Option<LogoPosition>,
+
+    /// Draw the logo's first color in COLOR: a name, a 256-color index or #rrggbb
+    ///
+    /// Distro logos use one color. Custom art (--logo-source) can switch
+    /// between colors 1 to 9 with $1 to $9.
+    #[arg(long = "logo-color-1", value_name = "COLOR")]
+    logo_color_1: Option<Color>,
+
+    /// Draw the logo's color 2 in COLOR
+    #[arg(long = "logo-color-2", value_name = "COLOR")]
+    logo_color_2: Option<Color>,
+
+    /// Draw the logo's color 3 in COLOR
+    #[arg(long = "logo-color-3", value_name = "COLOR")]
+    logo_color_3: Option<Color>,
+
+    /// Draw the logo's color 4 in COLOR
+    #[arg(long = "logo-color-4", value_name = "COLOR")]
+    logo_color_4: Option<Color>,
+
+    /// Draw the logo's color 5 in COLOR
+    #[arg(long = "logo-color-5", value_name = "COLOR")]
+    logo_color_5: Option<Color>,
+
+    /// Draw the logo's color 6 in COLOR
+    #[arg(long = "logo-color-6", value_name = "COLOR")]
+    logo_color_6: Option<Color>,
+
+    /// Draw the logo's color 7 in COLOR
+    #[arg(long = "logo-color-7", value_name = "COLOR")]
+    logo_color_7: Option<Color>,
+
+    /// Draw the logo's color 8 in COLOR
+    #[arg(long = "logo-color-8", value_name = "COLOR")]
+    logo_color_8: Option<Color>,
+
+    /// Draw the logo's color 9 in COLOR
+    #[arg(long = "logo-color-9", value_name = "COLOR")]
+    logo_color_9: Option<Color>,
+
+    /// Text between a label and its value [default: ": "]
+    #[arg(long, value_name = "TEXT", allow_hyphen_values = true)]
+    separator: Option<String>,
+
+    /// Show every value on one line, e.g. "Arch | 6.9.1 | i7-9700K", for status bars
+    #[arg(long, conflicts_with = "stream")]
+    oneline: bool,
+
+    /// Text between the values of --oneline [default: " | "]
+    #[arg(long, value_name = "TEXT", allow_hyphen_values = true)]
+    delimiter: Option<String>,
+
+    /// Label alignment: left, right, or none to leave labels unpadded [default: left]
+    #[arg(long, value_name = "ALIGN")]
+    key_align: Option<KeyAlign>,
+
+    /// Pad labels to a fixed width instead of the widest label
+    #[arg(long, value_name = "WIDTH")]
+    key_width: Option<usize>,
+
+    /// Glyphs in front of labels: none, nerd (needs a Nerd Font) or emoji [default: none]
+    #[arg(long, value_name = "SET")]
+    icons: Option<IconSet>,
+
+    /// Wrap values to COLUMNS instead of the terminal's width; 0 turns wrapping off
+    ///
+    /// COLUMNS is at least 20. Values always keep 20 columns after their
+    /// label, so with a long label or a logo lines may still end up wider.
+    #[arg(long, value_name = "COLUMNS", value_parser = parse_width)]
+    width: Option<usize>,
+
+    /// Cut values longer than COLUMNS short with "…"; 0 leaves them whole
+    #[arg(long, value_name = "COLUMNS")]
+    max_length: Option<usize>,
+
+    /// Color and style of labels, e.g. "bold blue" or "#ff8800"
+    #[arg(long, value_name = "STYLE")]
+    key_color: Option<TextStyle>,
+
+    /// Color and style of values
+    #[arg(long, value_name = "STYLE")]
+    value_color: Option<TextStyle>,
+
+    /// Unit for temperature readings (celsius or fahrenheit)
+    #[arg(long, value_name = "UNIT")]
+    temperature_unit: Option<TemperatureUnit>,
+
+    /// Sampling window in milliseconds for rate-based modules such as diskio and netio [default: 200]
+    #[arg(long, value_name = "MS")]
+    sample_interval: Option<u64>,
+
+    /// List out-of-tree and other taint-causing kernel modules by name
+    #[arg(long)]
+    show_tainted_modules: bool,
+
+    /// Replace a module's output with a template, e.g. kernel="{name} {version}{?arch} ({arch}){?}"
+    ///
+    /// {field} inserts a field ({value} is the usual output), {field:<N} pads it,
+    /// {?field}..{?} shows only when it is set and {#green}..{#} adds color.
+    /// May be given several times.
+    #[arg(long, value_name = "MODULE=TEMPLATE", value_parser = parse_module_format)]
+    module_format: Vec<(ModuleKind, Template)>,
+
+    /// Show LABEL instead of a module's name, e.g. host=Box. May be given several times.
+    #[arg(long, value_name = "MODULE=LABEL", value_parser = parse_label)]
+    label: Vec<(ModuleKind, String)>,
+
+    /// Override a module option of the config, e.g. cputemp.unit=fahrenheit
+    ///
+    /// Options are those of the config's "options" section. May be given several times.
+    #[arg(long, value_name = "MODULE.OPTION=VALUE")]
+    set: Vec<String>,
+
+    /// Hide USB hubs and root hubs from the usb module
+    #[arg(long)]
+    usb_hide_hubs: bool,
+
+    /// Shell command whose output the custom module displays
+    #[arg(long, value_name = "COMMAND")]
+    custom_command: Option<String>,
+
+    /// Label for the custom module
+    #[arg(long, value_name = "LABEL", requires = "custom_command")]
+    custom_label: Option<String>,
+
+    /// Static text shown by the text module, e.g. a motto or machine label
+    #[arg(long, value_name = "TEXT")]
+    text: Option<String>,
+
+    /// Color of the text module (a name like "cyan" or a "#rrggbb" code)
+    #[arg(long, value_name = "COLOR", requires = "text")]
+    text_color: Option<Color>,
+
+    /// Time in milliseconds the custom command may run before it is killed [default: 1000]
+    #[arg(long, value_name = "MS")]
+    custom_timeout: Option<u64>,
+
+    /// Config file to use instead of the default config.jsonc/config.toml
+    ///
+    /// Either a path or a name looked up as <NAME>, <NAME>.jsonc or <NAME>.toml in
+    /// $XDG_CONFIG_HOME/fastfetch-rs, ~/.config/fastfetch-rs and /etc/fastfetch-rs
+    #[arg(short, long, value_name = "PATH")]
+    config: Option<String>,
+
+    /// Use a bundled preset instead of a config file
+    #[arg(long, value_name = "NAME", value_parser = preset_names(), conflicts_with = "config")]
+    preset: Option<String>,
+
+    /// Write a commented default config to PATH (default: ~/.config/fastfetch-rs/config.jsonc)
+    ///
+    /// A path ending in .toml gets TOML; "-" prints to stdout. Existing files are not overwritten.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "")]
+    gen_config: Option<String>,
+
+    /// Like --gen-config, but spell out every per-module option
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "", conflicts_with = "gen_config")]
+    gen_config_full: Option<String>,
+
+    /// Convert a config of the original fastfetch, writing it to OUTPUT
+    ///
+    /// OUTPUT defaults to ~/.config/fastfetch-rs/config.jsonc; a path ending in .toml
+    /// gets TOML and "-" prints to stdout. Options without an equivalent are reported.
+    #[arg(long, value_names = ["UPSTREAM", "OUTPUT"], num_args = 1..=2)]
+    migrate_config: Option<Vec<String>>,
+
+    /// Validate the config (or --config/--preset) and report problems with line numbers
+    ///
+    /// Exits with a non-zero status if there are errors.
+    #[arg(long)]
+    check_config: bool,
+
     /// List all available modules
     #[arg(long)]
     list_modules: bool,
+
+    /// List the logos --logo and logo.name can pick
+    #[arg(long)]
+    list_logos: bool,
+
+    /// Print a logo in its colors, e.g. to try one before choosing it
+    #[arg(long, value_name = "NAME")]
+    print_logo: Option<String>,
+
+    /// Describe a module: what it shows, where, its options and format fields
+    #[arg(long, value_name = "MODULE")]
+    help_module: Option<String>,
+
+    /// Print a man page in roff format
+    #[arg(long, hide = true)]
+    gen_man: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+impl Args {
+    /// The --logo-color-N flags that were given, by N
+    fn logo_colors(&self) -> impl Iterator<Item = (usize, Color)> {
+        [
+            self.logo_color_1,
+            self.logo_color_2,
+            self.logo_color_3,
+            self.logo_color_4,
+            self.logo_color_5,
+            self.logo_color_6,
+            self.logo_color_7,
+            self.logo_color_8,
+            self.logo_color_9,
+        ]
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, color)| Some((index + 1, color?)))
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print a shell completion script
+    ///
+    /// For example: fastfetch-rs completions bash > /usr/share/bash-completion/completions/fastfetch-rs
+    Completions {
+        #[arg(value_enum)]
+        shell: CompletionShell,
+    },
+    /// Run one module and print its value, e.g. for scripts
+    ///
+    /// With --json the module's fields are printed as a JSON object instead,
+    /// e.g. fastfetch-rs get memory --json | jq .used. Exits with a non-zero
+    /// status if the module fails or has nothing to show.
+    Get {
+        /// Module id or alias, see --list-modules
+        #[arg(value_name = "MODULE")]
+        module: String,
+
+        /// Print the module's fields as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Query several machines over ssh and show their results side by side
+    ///
+    /// Each host runs fastfetch-rs with --format json, and --modules when
+    /// given. The results are a table, or one document with --format. Exits
+    /// with a non-zero status if any host failed.
+    Inventory {
+        /// Hosts as given to ssh, e.g. admin@nas; "local" is this machine
+        #[arg(value_name = "HOST", value_parser = inventory::parse_host)]
+        hosts: Vec<String>,
+
+        /// File listing hosts, one per line; # starts a comment
+        #[arg(long, short = 'f', value_name = "PATH")]
+        hosts_file: Option<PathBuf>,
+
+        /// Command that runs fastfetch-rs on the hosts, as the remote shell
+        /// reads it; the arguments after it are quoted
+        #[arg(long, value_name = "COMMAND", default_value = "fastfetch-rs")]
+        remote_command: String,
+    },
+    /// Build a config interactively: toggle and reorder modules and pick a
+    /// logo with a live preview, then save
+    ///
+    /// Starts from the current config (or --config/--preset and --modules)
+    /// and keeps its other settings.
+    Tui {
+        /// Where to save the config, or - to print it; defaults to the
+        /// config location. An existing file is not overwritten.
+        #[arg(value_name = "PATH")]
+        output: Option<String>,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    set_colors_enabled(
+        args.color
+            .resolve(&RealSystemContext, io::stdout().is_terminal()),
+    );
+
+    if args.gen_man {
+        return Ok(manpage::render::<Args>(&mut std::io::stdout())?);
+    }
+    if let Some(Command::Completions { shell }) = args.command {
+        completions::print::<Args>(shell);
+        return Ok(());
+    }
+    if let Some(Command::Inventory {
+        hosts,
+        hosts_file,
+        remote_command,
+    }) = &args.command
+    {
+        return inventory(&args, hosts, hosts_file.as_deref(), remote_command);
+    }
 
     // Handle --list-modules flag
     if args.list_modules {
         println!("Available modules:");
         for kind in ModuleKind::all() {
-            println!("  - {} ({})", kind.name().to_lowercase(), kind.name());
+            let aliases: Vec<_> = kind.aliases().collect();
+            let aliases = if aliases.is_empty() {
+                String::new()
+            } else {
+                format!(", also {}", aliases.join(", "))
+            };
+            let opt_in = if kind.is_opt_in() { " [opt-in]" } else { "" };
+            println!("  - {} ({}{aliases}){opt_in}", kind.id(), kind.name());
         }
         return Ok(());
     }
 
-    let builder: libfastfetch::ConfigBuilder = Config::builder()
-        .values_only(args.values_only)
-        .parallel(!args.no_parallel);
+    if args.list_logos {
+        println!("Available logos:");
+        for logo in LOGOS {
+            let aliases = if logo.aliases.is_empty() {
+                String::new()
+            } else {
+                format!(" (also {})", logo.aliases.join(", "))
+            };
+            println!("  - {}{aliases}", logo.name);
+        }
+        return Ok(());
+    }
+    if let Some(name) = &args.print_logo {
+        let Some(logo) = Logo::named(name) else {
+            bail!("Unknown logo '{name}'; see --list-logos");
+        };
+        println!("{}", logo.lines().join("\n"));
+        return Ok(());
+    }
+    let gen_config = match (&args.gen_config, &args.gen_config_full) {
+        (Some(path), _) => Some((path, false)),
+        (_, Some(path)) => Some((path, true)),
+        _ => None,
+    };
+    if let Some((path, full)) = gen_config {
+        return write_default_config(path, full);
+    }
+
+    if let Some(paths) = &args.migrate_config {
+        return migrate_config(&paths[0], paths.get(1).map_or("", String::as_str));
+    }
+    if args.check_config {
+        return check_config(&args);
+    }
+
+    // The config file sets the baseline; only flags that were given override it
+    let config_file = match (args.preset.as_deref(), args.config.as_deref()) {
+        (Some(preset), _) => Some(ConfigFile::load_preset(preset)?),
+        (None, Some(name)) => Some(ConfigFile::load_named(&RealSystemContext, name)?),
+        (None, None) => ConfigFile::load_default(&RealSystemContext)?,
+    };
+    // Fit the terminal unless the config or --width says otherwise
+    let mut builder = Config::builder();
+    if io::stdout().is_terminal()
+        && let Some(columns) = terminal_width(&RealSystemContext)
+    {
+        builder = builder.width(columns);
+    }
+    if let Some(file) = &config_file {
+        builder = file.apply(builder)?;
+    }
+    if !args.set.is_empty() {
+        builder = ConfigFile::from_settings(&args.set)?.apply(builder)?;
+    }
+    // After the config so its aliases name modules too
+    if let Some(name) = &args.help_module {
+        let Ok(kind) = builder.parse_module(name) else {
+            bail!("Unknown module '{name}'; see --list-modules");
+        };
+        println!("{}", module_help(kind));
+        return Ok(());
+    }
+
+    if let Some(name) = &args.logo {
+        builder = builder.with_logo_name(name).map_err(anyhow::Error::msg)?;
+    }
+    if let Some(path) = args.logo_source.clone() {
+        builder = builder.with_logo_source(path);
+    }
+    if let Some(position) = args.logo_position {
+        builder = builder.logo_position(position);
+    }
+    for (index, color) in args.logo_colors() {
+        builder = builder
+            .logo_color(index, color)
+            .map_err(anyhow::Error::msg)?;
+    }
+    if args.values_only {
+        builder = builder.values_only(true);
+    }
+    if let Some(format) = args.format {
+        builder = builder.output_format(format);
+    }
+    if args.pipe {
+        builder = builder.output_format(OutputFormat::Pipe);
+    }
+    if args.hide_errors {
+        builder = builder.hide_errors(true);
+    }
+    if args.hide_unavailable {
+        builder = builder.hide_unavailable(true);
+    }
+    if args.stat {
+        builder = builder.stat(true);
+    }
+    if args.color_blocks {
+        builder = builder.color_blocks(true);
+    }
+    if args.boxed {
+        builder = builder.boxed(true);
+    }
+    if let Some(layout) = args.layout {
+        builder = builder.layout(layout);
+    }
+    if args.no_parallel {
+        builder = builder.parallel(false);
+    }
+    if args.show_tainted_modules {
+        builder = builder.show_tainted_modules(true);
+    }
+    if args.usb_hide_hubs {
+        builder = builder.usb_hide_hubs(true);
+    }
+    if let Some(separator) = args.separator.clone() {
+        builder = builder.separator(separator);
+    }
+    if args.oneline {
+        builder = builder.oneline(true);
+    }
+    if let Some(delimiter) = args.delimiter.clone() {
+        builder = builder.delimiter(delimiter);
+    }
+    if let Some(align) = args.key_align {
+        builder = builder.key_align(align);
+    }
+    if let Some(icons) = args.icons {
+        builder = builder.icons(icons);
+    }
+    if let Some(width) = args.key_width {
+        builder = builder.key_width(width);
+    }
+    if let Some(columns) = args.max_length {
+        builder = builder.max_length(columns);
+    }
+    if let Some(columns) = args.width {
+        builder = builder.width(columns);
+    }
+    if let Some(style) = args.key_color {
+        builder = builder.key_style(style);
+    }
+    if let Some(style) = args.value_color {
+        builder = builder.value_style(style);
+    }
+    if let Some(unit) = args.temperature_unit {
+        builder = builder.temperature_unit(unit);
+    }
+    if let Some(ms) = args.sample_interval {
+        builder = builder.sample_interval(Duration::from_millis(ms));
+    }
+    if let Some(ms) = args.custom_timeout {
+        builder = builder.custom_timeout(Duration::from_millis(ms));
+    }
+
+    for (kind, template) in args.module_format.iter().cloned() {
+        builder = builder.module_format(kind, template);
+    }
+    for (kind, label) in args.label.iter().cloned() {
+        builder = builder.module_label(kind, label);
+    }
+
+    let builder = match args.custom_command.clone() {
+        Some(command) => builder.custom_command(command, args.custom_label.clone()),
+        None => builder,
+    };
+
+    let builder = match args.text.clone() {
+        Some(text) => builder.text(text, args.text_color),
+        None => builder,
+    };
 
     let builder = if let Some(ref module_names) = args.modules {
         builder.with_module_names(module_names.clone())
@@ -48,10 +630,24 @@ fn main() -> anyhow::Result<()> {
         builder
     };
 
+    if let Some(Command::Tui { output }) = &args.command {
+        // The tui edits the config file; --modules stands in for its list
+        let mut file = config_file.unwrap_or_default();
+        if let Some(modules) = &args.modules {
+            file.modules = Some(modules.iter().cloned().map(ModuleSpec::Id).collect());
+        }
+        return build_config(builder, file, output.as_deref().unwrap_or(""));
+    }
+
+    if let Some(Command::Get { module, json }) = &args.command {
+        return get(builder, module, *json);
+    }
+
     let outcome = builder.build();
 
-    if let Some(module_names) = args.modules.as_ref() {
-        if outcome.unknown_modules.len() == module_names.len() {
+    // Unknown names may come from --modules or the config file
+    if !outcome.unknown_modules.is_empty() {
+        if outcome.config.modules().is_empty() {
             eprintln!("Error: No valid modules specified");
             std::process::exit(1);
         }
@@ -61,11 +657,310 @@ fn main() -> anyhow::Result<()> {
         }
     }
 
+    // The logo file is read when rendering; catch a wrong path up front
+    if let Some(logo) = outcome.config.logo()
+        && logo.ascii_art.is_none()
+        && let Some(path) = &logo.source
+    {
+        fs::metadata(path).with_context(|| format!("Cannot read logo file {}", path.display()))?;
+    }
+
     let app = Application::new(outcome.config);
-    let results = app.run();
-    let output = app.render(&results);
+    let report = match args.stream {
+        true => app.run_streaming(|line| println!("{line}")),
+        false => app.run(),
+    };
+
+    // Read the old snapshot before a new one may replace it
+    let changes = match &args.diff {
+        Some(path) => Some(read_snapshot(path)?.diff(&Snapshot::new(&report.modules))),
+        None => None,
+    };
+    if let Some(path) = &args.snapshot {
+        let content = Snapshot::new(&report.modules).render(Snapshot::format_for(path));
+        fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    if let Some(changes) = changes {
+        if changes.is_empty() {
+            println!("No changes");
+            return Ok(());
+        }
+        println!("{}", render_diff(&changes, colors_enabled()));
+        std::process::exit(1);
+    }
+
+    match args.live {
+        Some(seconds) => live(&app, report, Duration::from_secs(seconds)),
+        None if args.stream => Ok(()),
+        None => {
+            println!("{}", app.render(&report));
+            Ok(())
+        }
+    }
+}
+
+/// The `--help-module` text for `kind`
+fn module_help(kind: ModuleKind) -> String {
+    let metadata = create_module(kind).metadata();
+    let mut lines = vec![
+        format!("{} ({})", kind.name(), kind.id()),
+        metadata.description.to_string(),
+        String::new(),
+        format!("Platforms: {}", metadata.platforms.join(", ")),
+    ];
+    let mut section = |title: &str, rows: &[(String, &str)]| {
+        let width = rows
+            .iter()
+            .map(|(key, _)| key.len())
+            .max()
+            .unwrap_or_default();
+        lines.push(String::new());
+        lines.push(format!("{title}:"));
+        lines.extend(
+            rows.iter()
+                .map(|(key, text)| format!("  {key:<width$}  {text}")),
+        );
+    };
+    if !metadata.options.is_empty() {
+        let rows: Vec<_> = metadata
+            .options
+            .iter()
+            .map(|(key, text)| (key.to_string(), *text))
+            .collect();
+        section("Options", &rows);
+    }
+    let rows: Vec<_> = std::iter::once(("value", "The module's usual output"))
+        .chain(metadata.fields.iter().copied())
+        .map(|(field, text)| (format!("{{{field}}}"), text))
+        .collect();
+    section("Format placeholders", &rows);
+    lines.join("\n")
+}
+
+/// Load a snapshot for --diff
+fn read_snapshot(path: &Path) -> anyhow::Result<Snapshot> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    Snapshot::parse(&content, Snapshot::format_for(path))
+        .map_err(|err| anyhow::anyhow!("Invalid snapshot {}: {err}", path.display()))
+}
+
+/// Handle --live: redraw the output every `interval` until interrupted
+fn live(app: &Application, mut report: RunReport, interval: Duration) -> anyhow::Result<()> {
+    let mut stdout = io::stdout();
+    loop {
+        // Move home and clear, so the output doesn't scroll
+        writeln!(stdout, "\x1b[H\x1b[2J{}", app.render(&report))?;
+        stdout.flush()?;
+        std::thread::sleep(interval);
+        report = app.refresh(&report);
+    }
+}
 
-    println!("{output}");
+/// Handle --check-config
+fn check_config(args: &Args) -> anyhow::Result<()> {
+    let ctx = &RealSystemContext;
+    let (name, diagnostics) = match (args.preset.as_deref(), args.config.as_deref()) {
+        (Some(name), _) => {
+            let preset = Preset::find(name).context("Unknown preset")?;
+            let path = preset.path();
+            let diagnostics = check_source(preset.source, ConfigFormat::Jsonc, None, &path);
+            (path, diagnostics)
+        }
+        (None, Some(name)) => {
+            let path = ConfigFile::find_named(ctx, name)?;
+            let diagnostics = check_file(ctx, &path)?;
+            (path, diagnostics)
+        }
+        (None, None) => {
+            let path = ConfigFile::find_default(ctx).context("No config file found")?;
+            let diagnostics = check_file(ctx, &path)?;
+            (path, diagnostics)
+        }
+    };
+
+    for diagnostic in &diagnostics {
+        println!("{}: {diagnostic}", name.display());
+    }
+    let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+    let warnings = diagnostics.len() - errors;
+    if errors > 0 {
+        eprintln!(
+            "{}: {errors} error(s), {warnings} warning(s)",
+            name.display()
+        );
+        std::process::exit(1);
+    }
+    println!("{}: OK ({warnings} warning(s))", name.display());
+    Ok(())
+}
+
+/// Accepted --preset values, described in --help
+fn preset_names() -> PossibleValuesParser {
+    PossibleValuesParser::new(
+        PRESETS
+            .iter()
+            .map(|preset| PossibleValue::new(preset.name).help(preset.description)),
+    )
+}
+
+/// Parse a --width argument
+fn parse_width(arg: &str) -> Result<usize, String> {
+    check_width(arg.parse().map_err(|err| format!("{err}"))?)
+}
+
+/// Parse a --module-format argument
+fn parse_module_format(arg: &str) -> Result<(ModuleKind, Template), String> {
+    let (module, template) = arg
+        .split_once('=')
+        .ok_or("expected MODULE=TEMPLATE, e.g. kernel=\"{name} {version}\"")?;
+    let template = template.parse().map_err(|err| format!("{err}"))?;
+    Ok((module.trim().parse()?, template))
+}
+
+/// Parse a --label argument
+fn parse_label(arg: &str) -> Result<(ModuleKind, String), String> {
+    let (module, label) = arg
+        .split_once('=')
+        .ok_or("expected MODULE=LABEL, e.g. host=Box")?;
+    Ok((module.trim().parse()?, label.to_string()))
+}
+
+/// Handle --gen-config and --gen-config-full
+fn write_default_config(path: &str, full: bool) -> anyhow::Result<()> {
+    let path = output_path(path, "--gen-config")?;
+    let content = generate_config(ConfigFormat::from_path(&path), full);
+    write_config(&path, &content)
+}
+
+/// Handle the inventory subcommand
+fn inventory(
+    args: &Args,
+    hosts: &[String],
+    hosts_file: Option<&Path>,
+    remote_command: &str,
+) -> anyhow::Result<()> {
+    let mut hosts = hosts.to_vec();
+    if let Some(path) = hosts_file {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let listed = inventory::parse_hosts(&content)
+            .map_err(anyhow::Error::msg)
+            .with_context(|| format!("Failed to read hosts from {}", path.display()))?;
+        hosts.extend(listed);
+    }
+    if hosts.is_empty() {
+        bail!("No hosts given; list them after inventory or in a file with --hosts-file");
+    }
+
+    let modules = args.modules.as_ref().map(|modules| modules.join(","));
+    let reports = inventory::query(&hosts, remote_command, modules.as_deref());
+    let format = match args.pipe {
+        true => OutputFormat::Pipe,
+        false => args.format.unwrap_or_default(),
+    };
+    println!("{}", render_inventory(&reports, format));
+
+    if reports.iter().any(|report| report.result.is_err()) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Print the value or the fields of one module
+fn get(builder: ConfigBuilder, name: &str, json: bool) -> anyhow::Result<()> {
+    let Ok(kind) = builder.parse_module(name) else {
+        bail!("Unknown module '{name}'; see --list-modules");
+    };
+    if kind.is_layout() {
+        bail!("{kind} only lays out the other modules and has no value");
+    }
+    let app = Application::new(builder.build().config);
+    let info = match app.detect_one(kind) {
+        DetectionResult::Detected(info) => info,
+        DetectionResult::Unavailable => bail!("{kind} is not available on this system"),
+        DetectionResult::Error(err) => bail!("{kind}: {err}"),
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        println!("{info}");
+    }
+    Ok(())
+}
+
+/// Handle the tui subcommand
+fn build_config(base: ConfigBuilder, file: ConfigFile, output: &str) -> anyhow::Result<()> {
+    let path = output_path(output, "tui")?;
+    // Don't let the user pick everything only to fail on saving
+    check_new(&path)?;
+
+    let Some(file) = tui::run(base, file)? else {
+        return Ok(());
+    };
+    let content = file
+        .render(ConfigFormat::from_path(&path))
+        .map_err(anyhow::Error::msg)?;
+    write_config(&path, &content)
+}
+
+/// Handle --migrate-config
+fn migrate_config(upstream: &str, output: &str) -> anyhow::Result<()> {
+    let content =
+        fs::read_to_string(upstream).with_context(|| format!("Failed to read {upstream}"))?;
+    let migration = Migration::from_upstream(&content)
+        .map_err(|err| anyhow::anyhow!("Invalid config {upstream}: {err}"))?;
+
+    let path = output_path(output, "--migrate-config")?;
+    let content = migration
+        .render(ConfigFormat::from_path(&path), upstream)
+        .map_err(anyhow::Error::msg)?;
+    write_config(&path, &content)?;
+
+    for item in &migration.unsupported {
+        eprintln!("Warning: Not converted: {item}");
+    }
+    Ok(())
+}
+
+/// Where to write a config: `path`, or the default config location when empty
+fn output_path(path: &str, flag: &str) -> anyhow::Result<PathBuf> {
+    if !path.is_empty() {
+        return Ok(PathBuf::from(path));
+    }
+    xdg::config_home(&RealSystemContext)
+        .map(|home| home.join("fastfetch-rs").join("config.jsonc"))
+        .with_context(|| format!("Cannot determine the config directory; pass a path to {flag}"))
+}
+
+/// Fail if a config would overwrite an existing file
+fn check_new(path: &Path) -> anyhow::Result<()> {
+    if path.as_os_str() != "-" && path.exists() {
+        bail!(
+            "{} already exists; remove it first or choose another path",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Write a config file, or print it for "-", without overwriting an existing one
+fn write_config(path: &Path, content: &str) -> anyhow::Result<()> {
+    if path.as_os_str() == "-" {
+        print!("{content}");
+        return Ok(());
+    }
+
+    check_new(path)?;
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
 
+    println!("Wrote {}", path.display());
     Ok(())
 }
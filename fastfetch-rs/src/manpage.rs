@@ -0,0 +1,141 @@
+//! Man page for `--gen-man`
+
+use clap::CommandFactory;
+use clap_mangen::{
+    Man,
+    roff::{Roff, bold, italic, roman},
+};
+use libfastfetch::{ModuleKind, config::documented_keys};
+use std::io::{self, Write};
+
+const EXAMPLES: &[(&str, &str)] = &[
+    (
+        "fastfetch-rs",
+        "Show the default modules next to the distro logo.",
+    ),
+    (
+        "fastfetch-rs -m os,kernel,memory --values-only",
+        "Show three modules without labels.",
+    ),
+    ("fastfetch-rs --preset minimal", "Use a bundled preset."),
+    (
+        "fastfetch-rs --module-format 'kernel={name} {version}'",
+        "Replace a module's output with a template.",
+    ),
+    (
+        "fastfetch-rs --gen-config",
+        "Write a commented default config to ~/.config/fastfetch-rs/config.jsonc.",
+    ),
+    (
+        "fastfetch-rs --check-config",
+        "Validate the config and report problems with line numbers.",
+    ),
+];
+
+/// Write the man page for `C` to `out`
+pub fn render<C: CommandFactory>(out: &mut dyn Write) -> io::Result<()> {
+    let man = Man::new(C::command());
+    let mut page = Vec::new();
+    man.render_title(&mut page)?;
+    man.render_name_section(&mut page)?;
+    man.render_synopsis_section(&mut page)?;
+    man.render_description_section(&mut page)?;
+    man.render_options_section(&mut page)?;
+    man.render_subcommands_section(&mut page)?;
+
+    let mut roff = Roff::new();
+    modules_section(&mut roff);
+    config_section(&mut roff);
+    examples_section(&mut roff);
+    files_section(&mut roff);
+    roff.to_writer(&mut page)?;
+
+    man.render_version_section(&mut page)?;
+    man.render_authors_section(&mut page)?;
+
+    // Every section is written with roff's apostrophe preamble in front;
+    // the page only needs it once, ahead of the title
+    let preamble = Roff::new().render();
+    let page = String::from_utf8_lossy(&page).replace(&preamble, "");
+    write!(out, "{preamble}{page}")
+}
+
+fn modules_section(roff: &mut Roff) {
+    roff.control("SH", ["MODULES"]);
+    roff.text([roman(
+        "Modules are selected with --modules or the modules key of the config, by id or alias. \
+         Opt-in modules are only shown when listed.",
+    )]);
+    for kind in ModuleKind::all() {
+        let mut description = kind.name().to_string();
+        let aliases: Vec<_> = kind.aliases().collect();
+        if !aliases.is_empty() {
+            description.push_str(&format!("; also {}", aliases.join(", ")));
+        }
+        if kind.is_opt_in() {
+            description.push_str(" (opt-in)");
+        }
+        roff.control("TP", []);
+        roff.text([bold(kind.id())]);
+        roff.text([roman(description)]);
+    }
+}
+
+fn config_section(roff: &mut Roff) {
+    roff.control("SH", ["CONFIGURATION"]);
+    roff.text([roman(
+        "Settings are read from a JSONC or TOML config file; command line flags override them. \
+         Every key is optional. Besides module ids, the modules list accepts tables with a \
+         type key and per-entry options such as label, format, if and if_os. include lists \
+         configs to apply first, and aliases adds module names.",
+    )]);
+    for (key, description) in documented_keys() {
+        roff.control("TP", []);
+        roff.text([bold(key)]);
+        roff.text([roman(description)]);
+    }
+}
+
+fn examples_section(roff: &mut Roff) {
+    roff.control("SH", ["EXAMPLES"]);
+    for (command, description) in EXAMPLES {
+        roff.control("TP", []);
+        roff.text([bold(*command)]);
+        roff.text([roman(*description)]);
+    }
+}
+
+fn files_section(roff: &mut Roff) {
+    roff.control("SH", ["FILES"]);
+    for path in [
+        "$XDG_CONFIG_HOME/fastfetch-rs/config.jsonc",
+        "~/.config/fastfetch-rs/config.jsonc",
+        "/etc/fastfetch-rs/config.jsonc",
+    ] {
+        roff.control("TP", []);
+        roff.text([italic(path)]);
+        roff.text([roman(
+            "Searched in this order; config.toml is used when no config.jsonc exists.",
+        )]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_man_page_sections() {
+        let mut out = Vec::new();
+        render::<crate::Args>(&mut out).unwrap();
+        let page = String::from_utf8(out).unwrap();
+
+        for section in ["OPTIONS", "MODULES", "CONFIGURATION", "EXAMPLES", "FILES"] {
+            assert!(page.contains(&format!(".SH {section}")), "{section}");
+        }
+        assert!(page.contains("\\fBcputemp\\fR"));
+        assert_eq!(page.matches(&Roff::new().render()).count(), 1);
+        assert!(page.starts_with(&Roff::new().render()));
+        assert!(page.contains("\\fBdisplay.separator\\fR"));
+    }
+}
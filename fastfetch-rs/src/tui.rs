@@ -0,0 +1,464 @@
+//! Interactive config builder for the `tui` subcommand
+//!
+//! Lists the entries of the config, then every module it doesn't list, each
+//! with a checkbox next to a live preview of the output. Each entry is
+//! detected the first time it's shown, so toggling and reordering redraw
+//! instantly. Saving hands back the config with the chosen entries and logo;
+//! everything else in it is kept as it was.
+
+use ansi_to_tui::IntoText;
+use libfastfetch::{
+    Application, ConfigBuilder, ConfigFile, ModuleEntry, ModuleKind, OutputFormat,
+    RealSystemContext, RenderedModule, RunReport, config::file::ModuleSpec, logo::database::LOGOS,
+};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::Text,
+    widgets::{Block, List, ListItem, ListState, Paragraph},
+};
+use std::{collections::HashMap, io, time::Duration};
+
+const HELP: &str = " ↑↓ move · space toggle · shift+↑↓ reorder · ←→ logo · s save · q quit";
+
+/// Logo picked for the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogoChoice {
+    /// The detected distro's logo
+    Detected,
+    /// No logo at all
+    Hidden,
+    /// A logo from the database, by name
+    Named(&'static str),
+}
+
+/// One line of the module list
+#[derive(Debug, Clone)]
+struct Row {
+    /// Stays with the entry when it's moved; keys its detection result
+    id: usize,
+    spec: ModuleSpec,
+    shown: bool,
+}
+
+impl Row {
+    /// The module name, with the entry's own label if it has one
+    fn name(&self) -> String {
+        match &self.spec {
+            ModuleSpec::Entry(entry) if entry.label.is_some() => {
+                format!(
+                    "{} ({})",
+                    self.spec.name(),
+                    entry.label.as_deref().unwrap_or_default()
+                )
+            }
+            spec => spec.name().to_string(),
+        }
+    }
+}
+
+/// Entries and logo picked so far
+#[derive(Debug)]
+struct Picker {
+    /// The config's entries, shown and in display order, then every other module
+    rows: Vec<Row>,
+    cursor: usize,
+    /// Index into [`logo_choices`]
+    logo: usize,
+    /// The logo the config started with
+    initial_logo: LogoChoice,
+}
+
+impl Picker {
+    /// Start from the entries `specs`, with module names looked up by `builder`
+    fn new(specs: &[ModuleSpec], builder: &ConfigBuilder, logo: LogoChoice) -> Self {
+        let listed: Vec<_> = specs
+            .iter()
+            .filter_map(|spec| builder.parse_module(spec.name()).ok())
+            .collect();
+        let others = ModuleKind::all()
+            .iter()
+            .filter(|kind| !listed.contains(kind))
+            .map(|kind| (ModuleSpec::Id(kind.id().to_string()), false));
+        let rows = specs
+            .iter()
+            .map(|spec| (spec.clone(), true))
+            .chain(others)
+            .enumerate()
+            .map(|(id, (spec, shown))| Row { id, spec, shown })
+            .collect();
+        Self {
+            rows,
+            cursor: 0,
+            logo: logo_choices()
+                .iter()
+                .position(|choice| *choice == logo)
+                .unwrap_or_default(),
+            initial_logo: logo,
+        }
+    }
+
+    /// The shown rows, in order
+    fn shown(&self) -> Vec<&Row> {
+        self.rows.iter().filter(|row| row.shown).collect()
+    }
+
+    /// The entries of the shown rows, as written to the config
+    fn specs(&self) -> Vec<ModuleSpec> {
+        self.shown()
+            .into_iter()
+            .map(|row| row.spec.clone())
+            .collect()
+    }
+
+    fn logo(&self) -> LogoChoice {
+        logo_choices()[self.logo]
+    }
+
+    /// The picked logo, unless it's the one the config started with
+    ///
+    /// Custom art reads as the detected logo, so it's only replaced once
+    /// another logo is picked.
+    fn changed_logo(&self) -> Option<LogoChoice> {
+        Some(self.logo()).filter(|logo| *logo != self.initial_logo)
+    }
+
+    fn toggle(&mut self) {
+        let row = &mut self.rows[self.cursor];
+        row.shown = !row.shown;
+    }
+
+    fn move_cursor(&mut self, up: bool) {
+        self.cursor = match up {
+            true => self.cursor.saturating_sub(1),
+            false => (self.cursor + 1).min(self.rows.len() - 1),
+        };
+    }
+
+    /// Swap the row under the cursor with its neighbour, keeping it selected
+    fn reorder(&mut self, up: bool) {
+        let other = match up {
+            true => self.cursor.checked_sub(1),
+            false => Some(self.cursor + 1).filter(|&next| next < self.rows.len()),
+        };
+        if let Some(other) = other {
+            self.rows.swap(self.cursor, other);
+            self.cursor = other;
+        }
+    }
+
+    fn cycle_logo(&mut self, forward: bool) {
+        let count = logo_choices().len();
+        self.logo = match forward {
+            true => (self.logo + 1) % count,
+            false => (self.logo + count - 1) % count,
+        };
+    }
+}
+
+/// The detected logo, none, then every logo of the database
+fn logo_choices() -> Vec<LogoChoice> {
+    [LogoChoice::Detected, LogoChoice::Hidden]
+        .into_iter()
+        .chain(LOGOS.iter().map(|logo| LogoChoice::Named(logo.name)))
+        .collect()
+}
+
+fn logo_label(choice: LogoChoice) -> &'static str {
+    match choice {
+        LogoChoice::Detected => "detected",
+        LogoChoice::Hidden => "none",
+        LogoChoice::Named(name) => name,
+    }
+}
+
+/// Detection results, rendered with the settings the builder started from
+struct Preview {
+    base: ConfigBuilder,
+    /// Results by row id
+    detected: HashMap<usize, RenderedModule>,
+}
+
+impl Preview {
+    fn new(base: ConfigBuilder) -> Self {
+        // Show plain text output whatever the config asks for
+        let base = base.output_format(OutputFormat::Text).stat(false);
+        Self {
+            base,
+            detected: HashMap::new(),
+        }
+    }
+
+    /// The row's entry with its own options, or `None` for an unknown module
+    fn entry(&self, row: &Row) -> Option<ModuleEntry> {
+        row.spec.resolve(row.id, &self.base).ok()?.ok()
+    }
+
+    /// Detect the rows not seen before, all at once
+    fn detect(&mut self, rows: &[&Row]) {
+        // Conditions are left out so every entry yields the result of its row
+        let missing: Vec<_> = rows
+            .iter()
+            .filter(|row| !self.detected.contains_key(&row.id))
+            .filter_map(|row| Some((row.id, self.entry(row)?.with_conditions(Vec::new()))))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        let config = self
+            .base
+            .clone()
+            .with_module_entries(
+                missing
+                    .iter()
+                    .map(|(_, entry)| Ok::<_, String>(entry.clone())),
+            )
+            .build()
+            .config;
+        let modules = Application::new(config).run().modules;
+        for ((id, _), module) in missing.into_iter().zip(modules) {
+            self.detected.insert(id, module);
+        }
+    }
+
+    fn render(&mut self, picker: &Picker) -> String {
+        let shown = picker.shown();
+        self.detect(&shown);
+
+        let rows: Vec<_> = shown
+            .into_iter()
+            .filter_map(|row| Some((row.id, self.entry(row)?)))
+            .filter(|(_, entry)| entry.is_shown(&RealSystemContext))
+            .collect();
+        let builder = self
+            .base
+            .clone()
+            .with_module_entries(rows.iter().map(|(_, entry)| Ok::<_, String>(entry.clone())));
+        let builder = match picker.changed_logo() {
+            None => builder,
+            Some(LogoChoice::Detected) => builder.with_detected_logo(),
+            Some(LogoChoice::Hidden) => builder.without_logo(),
+            Some(LogoChoice::Named(name)) => builder
+                .with_logo_name(name)
+                .expect("database logos are known"),
+        };
+        let report = RunReport {
+            modules: rows
+                .iter()
+                .filter_map(|(id, _)| self.detected.get(id).cloned())
+                .collect(),
+            total: Duration::ZERO,
+        };
+        Application::new(builder.build().config).render(&report)
+    }
+}
+
+/// Let the user pick entries and a logo for `file`, previewed with the
+/// settings of `base`
+///
+/// Returns `file` with the choice applied when the user saves, or `None`
+/// when they quit.
+pub fn run(base: ConfigBuilder, file: ConfigFile) -> io::Result<Option<ConfigFile>> {
+    let config = base.clone().build().config;
+    let specs = file.effective_modules().map_or_else(
+        || {
+            config
+                .modules()
+                .iter()
+                .map(|entry| ModuleSpec::Id(entry.kind.id().to_string()))
+                .collect()
+        },
+        <[ModuleSpec]>::to_vec,
+    );
+    let logo = match config.logo() {
+        None => LogoChoice::Hidden,
+        Some(logo) => logo
+            .name
+            .as_deref()
+            .and_then(|name| LOGOS.iter().find(|known| known.name == name))
+            .map_or(LogoChoice::Detected, |known| LogoChoice::Named(known.name)),
+    };
+
+    let mut picker = Picker::new(&specs, &base, logo);
+    let mut preview = Preview::new(base);
+    let mut terminal = ratatui::init();
+    let saved = event_loop(&mut terminal, &mut picker, &mut preview);
+    ratatui::restore();
+    Ok(saved?.then(|| save(file, &picker)))
+}
+
+/// `file` with the picked entries, and the picked logo if it was changed
+fn save(mut file: ConfigFile, picker: &Picker) -> ConfigFile {
+    file.modules = Some(picker.specs());
+    match picker.changed_logo() {
+        None => {}
+        Some(LogoChoice::Hidden) => file.logo.enabled = Some(false),
+        Some(logo) => {
+            file.logo.enabled = None;
+            file.logo.ascii = None;
+            file.logo.source = None;
+            file.logo.name = match logo {
+                LogoChoice::Named(name) => Some(name.to_string()),
+                _ => None,
+            };
+        }
+    }
+    file
+}
+
+/// Handle keys until the user saves (`true`) or quits (`false`)
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    picker: &mut Picker,
+    preview: &mut Preview,
+) -> io::Result<bool> {
+    loop {
+        let output = preview.render(picker);
+        terminal.draw(|frame| draw(frame, picker, &output))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Up if shift => picker.reorder(true),
+            KeyCode::Down if shift => picker.reorder(false),
+            KeyCode::Char('K') => picker.reorder(true),
+            KeyCode::Char('J') => picker.reorder(false),
+            KeyCode::Up | KeyCode::Char('k') => picker.move_cursor(true),
+            KeyCode::Down | KeyCode::Char('j') => picker.move_cursor(false),
+            KeyCode::Char(' ') | KeyCode::Enter => picker.toggle(),
+            KeyCode::Left | KeyCode::Char('h') => picker.cycle_logo(false),
+            KeyCode::Right | KeyCode::Char('l') => picker.cycle_logo(true),
+            KeyCode::Char('s') => return Ok(true),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, picker: &Picker, output: &str) {
+    let [main, help] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [list_area, preview_area] =
+        Layout::horizontal([Constraint::Length(24), Constraint::Min(0)]).areas(main);
+
+    let items: Vec<_> = picker
+        .rows
+        .iter()
+        .map(|row| {
+            let item = ListItem::new(format!(
+                "[{}] {}",
+                if row.shown { 'x' } else { ' ' },
+                row.name()
+            ));
+            if row.shown { item } else { item.dim() }
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::bordered().title(" Modules "))
+        .highlight_style(Style::new().reversed());
+    let mut state = ListState::default().with_selected(Some(picker.cursor));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let text = output
+        .into_text()
+        .unwrap_or_else(|_| Text::raw(output.to_string()));
+    let title = format!(" Preview · logo: {} ", logo_label(picker.logo()));
+    frame.render_widget(
+        Paragraph::new(text).block(Block::bordered().title(title)),
+        preview_area,
+    );
+    frame.render_widget(Paragraph::new(HELP).dim(), help);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libfastfetch::ConfigFormat;
+
+    fn ids(specs: &[ModuleSpec]) -> Vec<&str> {
+        specs.iter().map(ModuleSpec::name).collect()
+    }
+
+    #[test]
+    fn test_picker() {
+        let specs = [
+            ModuleSpec::Id("os".to_string()),
+            ModuleSpec::Id("cpu".to_string()),
+        ];
+        let mut picker = Picker::new(&specs, &ConfigBuilder::default(), LogoChoice::Hidden);
+        assert_eq!(picker.rows.len(), ModuleKind::all().len());
+        assert_eq!(ids(&picker.specs()), ["os", "cpu"]);
+
+        // Move os below cpu, then show the module after them
+        picker.reorder(false);
+        picker.move_cursor(false);
+        picker.toggle();
+        let third = picker.rows[2].spec.name().to_string();
+        assert_eq!(ids(&picker.specs()), ["cpu", "os", third.as_str()]);
+
+        assert_eq!(picker.logo(), LogoChoice::Hidden);
+        picker.cycle_logo(false);
+        assert_eq!(picker.logo(), LogoChoice::Detected);
+        picker.cycle_logo(false);
+        assert_eq!(picker.logo(), LogoChoice::Named("linux"));
+    }
+
+    #[test]
+    fn test_save_keeps_settings() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": [
+                    "os",
+                    { "type": "custom", "command": "uptime -p", "label": "Up" },
+                    { "type": "custom", "command": "date", "label": "Now", "if": "os == linux" },
+                ],
+                "logo": { "ascii": "<>" },
+                "display": { "separator": " -> " },
+                "labels": { "os": "System" },
+                "options": { "cputemp": { "unit": "fahrenheit" } },
+            }"#,
+        )
+        .unwrap();
+        let builder = file.apply(ConfigBuilder::default()).unwrap();
+        let mut picker = Picker::new(
+            file.effective_modules().unwrap(),
+            &builder,
+            LogoChoice::Detected,
+        );
+        // Both custom entries are rows of their own; hide the first
+        assert_eq!(picker.rows[1].name(), "custom (Up)");
+        assert_eq!(picker.rows[2].name(), "custom (Now)");
+        picker.move_cursor(false);
+        picker.toggle();
+
+        let saved = save(file, &picker);
+        for format in [ConfigFormat::Jsonc, ConfigFormat::Toml] {
+            let text = saved.render(format).unwrap();
+            let file = ConfigFile::parse(&text, format).unwrap();
+            let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+
+            let entries = config.modules();
+            assert_eq!(entries.len(), 2, "{format:?}");
+            assert_eq!(entries[1].label.as_deref(), Some("Now"));
+            assert_eq!(entries[1].overrides.custom_command.as_deref(), Some("date"));
+            assert_eq!(entries[1].conditions.len(), 1);
+            assert_eq!(config.label_for(&entries[0]).as_deref(), Some("System"));
+            assert_eq!(config.display().separator, " -> ");
+            assert_eq!(config.logo().unwrap().ascii_art.as_deref(), Some("<>"));
+            assert_eq!(
+                config.module_options().temperature_unit,
+                libfastfetch::TemperatureUnit::Fahrenheit
+            );
+        }
+    }
+}
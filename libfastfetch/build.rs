@@ -0,0 +1,10 @@
+//! Expose build details to the Version module
+
+fn main() {
+    // TARGET and PROFILE are only visible to build scripts
+    for var in ["TARGET", "PROFILE"] {
+        let value = std::env::var(var).unwrap_or_default();
+        println!("cargo:rustc-env=FASTFETCH_BUILD_{var}={value}");
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -3,16 +3,70 @@
 //! Executes modules according to configuration and delegates formatting
 //! to the output layer. This is the minimal vertical slice that wires
 //! configuration → detection → output.
+//!
+//! Results are either collected into a [`RunReport`] or, for streaming
+//! output, handed out by a [`ModuleStream`] as each module finishes.
 
 use crate::{
-    config::Config,
+    config::{Config, ModuleEntry},
     context::{RealSystemContext, SystemContext},
     logo::Logo,
-    modules::{create_module, ModuleKind},
-    output::{OutputFormatter, RenderedModule},
+    modules::{create_module_with_options, ModuleInfo, ModuleKind},
+    output::{
+        colors_enabled, render_structured, BarMode, OutputFormatter, RenderedModule, TextStyle,
+    },
     DetectionResult,
 };
 use rayon::prelude::*;
+use std::{
+    collections::BTreeMap,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Results of one run of the configured modules
+#[derive(Debug, Clone)]
+pub struct RunReport {
+    /// Module results in display order, each with its detection time
+    pub modules: Vec<RenderedModule>,
+    /// Wall-clock time of the whole run
+    pub total: Duration,
+}
+
+/// Module results in display order, each as soon as it and the modules
+/// before it are done
+///
+/// Modules are detected on another thread, in parallel if configured, and
+/// sent back with their position; results that arrive early wait until
+/// the modules before them are in.
+#[derive(Debug)]
+pub struct ModuleStream {
+    receiver: Receiver<(usize, RenderedModule)>,
+    /// Results that arrived before the ones ahead of them
+    pending: BTreeMap<usize, RenderedModule>,
+    next: usize,
+}
+
+impl Iterator for ModuleStream {
+    type Item = RenderedModule;
+
+    fn next(&mut self) -> Option<RenderedModule> {
+        loop {
+            if let Some(module) = self.pending.remove(&self.next) {
+                self.next += 1;
+                return Some(module);
+            }
+            match self.receiver.recv() {
+                Ok((index, module)) => {
+                    self.pending.insert(index, module);
+                }
+                // A module that never came back doesn't hold up the rest
+                Err(_) => self.next = *self.pending.keys().next()?,
+            }
+        }
+    }
+}
 
 /// Orchestrates module execution and output formatting.
 #[derive(Debug, Clone)]
@@ -26,39 +80,235 @@ impl Application {
     }
 
     /// Run configured modules, optionally in parallel.
-    pub fn run(&self) -> Vec<RenderedModule> {
+    ///
+    /// Entries whose conditions don't hold on this machine are skipped.
+    pub fn run(&self) -> RunReport {
+        self.detect(None)
+    }
+
+    /// Run again, reusing the results in `previous` of modules whose output
+    /// doesn't change while the system is up, such as OS or CPU model.
+    pub fn refresh(&self, previous: &RunReport) -> RunReport {
+        self.detect(Some(previous))
+    }
+
+    /// Run configured modules like [`Application::run`], handing out each
+    /// result as soon as it is its turn to be shown
+    pub fn stream(&self) -> ModuleStream {
+        let (sender, receiver) = mpsc::channel();
+        let app = self.clone();
+        thread::spawn(move || {
+            let ctx = RealSystemContext;
+            let colors = app.colors();
+            let entries = app.shown_entries(&ctx);
+            let detect = |(index, entry): (usize, &&ModuleEntry)| {
+                // The receiver may have been dropped; nothing is left to do then
+                let _ = sender.send((index, app.detect_module(entry, colors, &ctx)));
+            };
+            if app.config.parallel() {
+                entries.par_iter().enumerate().for_each(detect);
+            } else {
+                entries.iter().enumerate().for_each(detect);
+            }
+        });
+        ModuleStream {
+            receiver,
+            pending: BTreeMap::new(),
+            next: 0,
+        }
+    }
+
+    /// Run configured modules and pass each line of text output to `emit`
+    /// as soon as it can be shown
+    ///
+    /// Returns the results of the run, as [`Application::run`] would.
+    pub fn run_streaming(&self, mut emit: impl FnMut(&str)) -> RunReport {
+        let start = Instant::now();
+        let expected: Vec<RenderedModule> = self
+            .shown_entries(&RealSystemContext)
+            .into_iter()
+            .map(|entry| {
+                RenderedModule::unavailable(entry.kind).with_label(self.config.label_for(entry))
+            })
+            .collect();
+        let mut lines = self.formatter().stream(&expected);
+        lines.start().iter().for_each(|line| emit(line));
+
+        let mut modules = Vec::with_capacity(expected.len());
+        for module in self.stream() {
+            lines.push(&module).iter().for_each(|line| emit(line));
+            modules.push(module);
+        }
+
+        let total = start.elapsed();
+        lines.finish(total).iter().for_each(|line| emit(line));
+        RunReport { modules, total }
+    }
+
+    /// Detect a single module with the configured options, whether or not
+    /// it's among the modules shown
+    ///
+    /// The first entry of `kind` in the config supplies its own options.
+    pub fn detect_one(&self, kind: ModuleKind) -> DetectionResult<ModuleInfo> {
+        let entry = self
+            .config
+            .modules()
+            .iter()
+            .find(|entry| entry.kind == kind)
+            .cloned()
+            .unwrap_or_else(|| ModuleEntry::new(kind));
+        let options = self.config.options_for(&entry);
+        create_module_with_options(kind, &options).detect(&RealSystemContext)
+    }
+
+    fn detect(&self, previous: Option<&RunReport>) -> RunReport {
+        let start = Instant::now();
         let ctx = RealSystemContext;
-        
-        if self.config.parallel() {
-            self.config
-                .modules()
-                .par_iter()
-                .map(|&kind| Self::detect_module(kind, &ctx))
-                .collect()
+        let colors = self.colors();
+        let entries = self.shown_entries(&ctx);
+        let detect = |(index, entry): (usize, &&ModuleEntry)| {
+            let cached = previous
+                .and_then(|previous| previous.modules.get(index))
+                .filter(|module| entry.kind.is_static() && module.kind == entry.kind);
+            match cached {
+                Some(module) => module.clone(),
+                None => self.detect_module(entry, colors, &ctx),
+            }
+        };
+
+        let modules = if self.config.parallel() {
+            entries.par_iter().enumerate().map(detect).collect()
         } else {
-            self.config
-                .modules()
-                .iter()
-                .copied()
-                .map(|kind| Self::detect_module(kind, &ctx))
-                .collect()
+            entries.iter().enumerate().map(detect).collect()
+        };
+
+        RunReport {
+            modules,
+            total: start.elapsed(),
         }
     }
 
-    /// Render output for a set of module results.
-    pub fn render(&self, modules: &[RenderedModule]) -> String {
+    /// Whether values are colored; structured documents carry plain values
+    fn colors(&self) -> bool {
+        colors_enabled()
+            && !self.config.values_only()
+            && !self.config.output_format().is_structured()
+    }
+
+    /// Configured entries whose conditions hold on this machine
+    fn shown_entries(&self, ctx: &dyn SystemContext) -> Vec<&ModuleEntry> {
+        self.config
+            .modules()
+            .iter()
+            .filter(|entry| entry.is_shown(ctx))
+            .collect()
+    }
+
+    /// Formatter for text output with the configured logo and layout
+    fn formatter(&self) -> OutputFormatter {
         let logo = self.config.logo().and_then(Logo::from_config);
+        OutputFormatter::new(self.config.values_only(), logo)
+            .with_display(self.config.display().clone())
+    }
+
+    /// Render output for the results of a run.
+    pub fn render(&self, report: &RunReport) -> String {
+        let format = self.config.output_format();
+        if format.is_structured() {
+            return render_structured(&report.modules, self.config.display(), format, report.total);
+        }
+
+        self.formatter()
+            .with_total(report.total)
+            .render(&report.modules)
+    }
 
-        let formatter = OutputFormatter::new(self.config.values_only(), logo);
-        formatter.render(modules)
+    /// `value` with each line's percentage drawn as a bar, as `mode` asks
+    fn with_bars(&self, info: &ModuleInfo, mode: BarMode, value: String) -> String {
+        let percentages = info.percentages();
+        if mode == BarMode::Off || percentages.is_empty() {
+            return value;
+        }
+        let style = &self.config.display().bar;
+        value
+            .lines()
+            .enumerate()
+            .map(|(index, line)| match percentages.get(index) {
+                Some(&percent) => style.apply(mode, percent, line),
+                None => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
-    fn detect_module(kind: ModuleKind, ctx: &dyn SystemContext) -> RenderedModule {
-        let module = create_module(kind);
-        match module.detect(ctx) {
-            DetectionResult::Detected(info) => RenderedModule::value(kind, info.to_string()),
+    fn detect_module(
+        &self,
+        entry: &ModuleEntry,
+        colors: bool,
+        ctx: &dyn SystemContext,
+    ) -> RenderedModule {
+        let kind = entry.kind;
+        let options = self.config.options_for(entry);
+        let module = create_module_with_options(kind, &options);
+        let start = Instant::now();
+        let detected = module.detect(ctx);
+        let elapsed = start.elapsed();
+        let rendered = match detected {
+            DetectionResult::Detected(info) => {
+                let bar = options.bars.get(&kind).copied().unwrap_or_default();
+                let rendered = match options.formats.get(&kind) {
+                    Some(template) => {
+                        let value = template.render(&|name| info.field(name), colors);
+                        RenderedModule::templated(kind, self.with_bars(&info, bar, value))
+                    }
+                    None => {
+                        RenderedModule::value(kind, self.with_bars(&info, bar, info.to_string()))
+                    }
+                };
+                rendered
+                    .with_percentages(info.percentages())
+                    .with_thresholds(options.thresholds.get(&kind).cloned())
+            }
             DetectionResult::Unavailable => RenderedModule::unavailable(kind),
             DetectionResult::Error(err) => RenderedModule::error(kind, err.to_string()),
         }
+        .with_max_length(options.max_lengths.get(&kind).copied());
+
+        let value_style = match kind {
+            // The Text module's own color counts as a per-module style
+            ModuleKind::Text => self
+                .config
+                .value_style_for(entry)
+                .or(options.text_color.map(TextStyle::from)),
+            _ => self.config.value_style_for(entry),
+        };
+        rendered
+            .with_label(self.config.label_for(entry))
+            .with_key_style(self.config.key_style_for(entry))
+            .with_value_style(value_style)
+            .with_elapsed(elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_stream_order() {
+        let (sender, receiver) = mpsc::channel();
+        for index in [2, 0, 3, 1] {
+            let module = RenderedModule::value(ModuleKind::Text, index.to_string());
+            sender.send((index, module)).unwrap();
+        }
+        drop(sender);
+
+        let stream = ModuleStream {
+            receiver,
+            pending: BTreeMap::new(),
+            next: 0,
+        };
+        let values: Vec<_> = stream.filter_map(|module| module.value).collect();
+        assert_eq!(values, ["0", "1", "2", "3"]);
     }
 }
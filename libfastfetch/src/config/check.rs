@@ -0,0 +1,273 @@
+//! Config validation for `--check-config`
+//!
+//! Loading a config stops at the first problem and only warns about unknown
+//! modules. Checking instead collects everything it can find, with line
+//! numbers where the offending text can be located, so a config can be
+//! validated in CI before it's deployed.
+
+use super::{ConfigBuilder, ConfigError, ConfigFile, ConfigFormat};
+use crate::context::SystemContext;
+use std::{fmt, path::Path};
+
+/// Keys that still work but have a better replacement, with a hint
+pub const DEPRECATED_KEYS: &[(&str, &str)] =
+    &[("options.custom.label", "use labels.custom instead")];
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One problem found in a config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// 1-based line the problem is on, when it could be located
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn warning(line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Whether the finding makes the config unusable
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        match self.line {
+            Some(line) => write!(f, "line {line}: {severity}: {}", self.message),
+            None => write!(f, "{severity}: {}", self.message),
+        }
+    }
+}
+
+/// Check the config file at `path`
+pub fn check_file(ctx: &dyn SystemContext, path: &Path) -> Result<Vec<Diagnostic>, ConfigError> {
+    let content = ctx.read_file(path).map_err(|source| ConfigError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(check_source(
+        &content,
+        ConfigFormat::from_path(path),
+        Some(ctx),
+        path,
+    ))
+}
+
+/// Check config text; `path` names it for resolving includes, which are
+/// only followed into files when a context is given
+pub fn check_source(
+    content: &str,
+    format: ConfigFormat,
+    ctx: Option<&dyn SystemContext>,
+    path: &Path,
+) -> Vec<Diagnostic> {
+    let mut file = match ConfigFile::parse(content, format) {
+        Ok(file) => file,
+        Err(message) => {
+            let (line, message) = split_json_location(format, &message);
+            return vec![Diagnostic::error(line, message)];
+        }
+    };
+
+    let mut diagnostics: Vec<_> = deprecated_keys(content, format)
+        .into_iter()
+        .map(|(key, hint)| {
+            Diagnostic::warning(
+                find_line(content, last_segment(key)),
+                format!("'{key}' is deprecated; {hint}"),
+            )
+        })
+        .collect();
+
+    if let Err(err) = file.resolve_includes(ctx, path) {
+        diagnostics.push(Diagnostic::error(
+            find_line(content, "include"),
+            err.to_string(),
+        ));
+        return diagnostics;
+    }
+
+    let builder = match file.apply(ConfigBuilder::default()) {
+        Ok(builder) => builder,
+        Err(err) => {
+            let line = match &err {
+                ConfigError::Value { key, .. } => find_line(content, last_segment(key)),
+                _ => None,
+            };
+            diagnostics.push(Diagnostic::error(line, err.to_string()));
+            return diagnostics;
+        }
+    };
+
+    let outcome = builder.build();
+    for name in &outcome.unknown_modules {
+        diagnostics.push(Diagnostic::error(
+            find_line(content, name),
+            format!("Unknown module '{name}'"),
+        ));
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line.unwrap_or(usize::MAX));
+    diagnostics
+}
+
+/// Deprecated keys set in the config, with their hints
+fn deprecated_keys(content: &str, format: ConfigFormat) -> Vec<(&'static str, &'static str)> {
+    // The typed config can't tell which keys were spelled out, so look at the raw document
+    let value: Option<serde_json::Value> = match format {
+        ConfigFormat::Jsonc => ConfigFile::jsonc_value(content).ok(),
+        ConfigFormat::Toml => toml::from_str(content).ok(),
+    };
+    let Some(value) = value else {
+        return Vec::new();
+    };
+
+    DEPRECATED_KEYS
+        .iter()
+        .copied()
+        .filter(|(key, _)| {
+            key.split('.')
+                .try_fold(&value, |value, segment| value.get(segment))
+                .is_some()
+        })
+        .collect()
+}
+
+/// Pull the "at line N column M" suffix out of a JSON error message
+fn split_json_location(format: ConfigFormat, message: &str) -> (Option<usize>, String) {
+    if format == ConfigFormat::Jsonc
+        && let Some((text, location)) = message.rsplit_once(" at line ")
+        && let Some(line) = location
+            .split_whitespace()
+            .next()
+            .and_then(|line| line.parse().ok())
+    {
+        return (Some(line), text.to_string());
+    }
+    (None, message.to_string())
+}
+
+/// Last part of a dotted key like `modules[2].color`
+fn last_segment(key: &str) -> &str {
+    key.rsplit('.').next().unwrap_or(key)
+}
+
+/// First line mentioning `name` as a quoted string or a bare TOML key,
+/// skipping comment lines
+fn find_line(content: &str, name: &str) -> Option<usize> {
+    let quoted = format!("\"{name}\"");
+    content
+        .lines()
+        .position(|line| {
+            let line = line.trim_start();
+            !(line.starts_with("//") || line.starts_with('#'))
+                && (line.contains(&quoted)
+                    || line
+                        .strip_prefix(name)
+                        .is_some_and(|rest| rest.trim_start().starts_with('=')))
+        })
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::generate_config;
+
+    fn check(content: &str, format: ConfigFormat) -> Vec<String> {
+        check_source(content, format, None, Path::new("config"))
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        assert!(check(r#"{ "modules": ["os", "ram"] }"#, ConfigFormat::Jsonc).is_empty());
+        for format in [ConfigFormat::Jsonc, ConfigFormat::Toml] {
+            assert!(check(&generate_config(format, true), format).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_unknown_modules() {
+        let config = r#"{
+            // "cpux" in a comment isn't reported
+            "modules": [
+                "os",
+                "cpux",
+                { "type": "kernal" },
+            ],
+        }"#;
+        assert_eq!(
+            check(config, ConfigFormat::Jsonc),
+            [
+                "line 5: error: Unknown module 'cpux'",
+                "line 6: error: Unknown module 'kernal'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        let diagnostics = check_source(
+            "{\n  \"display\": {\n    \"colour\": \"red\"\n  }\n}",
+            ConfigFormat::Jsonc,
+            None,
+            Path::new("config.jsonc"),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_error());
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert!(diagnostics[0].message.starts_with("unknown field `colour`"));
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let config = "modules = [\"os\"]\n\n[display]\nkey_align = \"center\"\n";
+        assert_eq!(
+            check(config, ConfigFormat::Toml),
+            ["line 4: error: Invalid value for 'display.key_align': \
+              Unknown key alignment: center (expected left, right or none)"]
+        );
+    }
+
+    #[test]
+    fn test_deprecated_key() {
+        let config = r#"{
+            "options": {
+                "custom": { "command": "uptime -p", "label": "Up" },
+            },
+        }"#;
+        assert_eq!(
+            check(config, ConfigFormat::Jsonc),
+            ["line 3: warning: 'options.custom.label' is deprecated; use labels.custom instead"]
+        );
+    }
+}
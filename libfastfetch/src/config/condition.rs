@@ -0,0 +1,249 @@
+//! Conditions on module entries
+//!
+//! A module entry can be limited to some machines so one config works on a
+//! laptop, a desktop and a server alike:
+//!
+//! ```jsonc
+//! "modules": [
+//!     { "type": "battery", "if": "chassis == laptop" },
+//!     { "type": "wm", "if_os": ["linux", "freebsd"] },
+//!     { "type": "custom", "command": "zpool list -H -o health", "if": "hostname != laptop" },
+//! ]
+//! ```
+//!
+//! `if` compares a fact with `==` or `!=`; values are matched
+//! case-insensitively. The facts are `os` (`linux`, `macos`, `windows`,
+//! `freebsd`), `chassis` (`laptop`, `desktop`, `server`, `tablet`,
+//! `handheld`, `other` or `unknown`), `hostname` and `env.NAME` for an
+//! environment variable. `if_os` is a shorthand for one or several `os`
+//! values. Entries whose conditions don't hold are left out entirely.
+
+use crate::context::SystemContext;
+use std::{fmt, str::FromStr};
+
+/// A fact about the machine a condition can test
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fact {
+    Os,
+    Chassis,
+    Hostname,
+    /// An environment variable
+    Env(String),
+}
+
+impl Fact {
+    /// Current value of the fact, if it can be determined
+    pub fn value(&self, ctx: &dyn SystemContext) -> Option<String> {
+        match self {
+            Self::Os => Some(std::env::consts::OS.to_string()),
+            Self::Chassis => Some(chassis(ctx).to_string()),
+            #[cfg(unix)]
+            Self::Hostname => ctx.get_hostname().ok(),
+            #[cfg(not(unix))]
+            Self::Hostname => ctx.get_env("COMPUTERNAME"),
+            Self::Env(name) => ctx.get_env(name),
+        }
+    }
+}
+
+impl FromStr for Fact {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "os" => Ok(Self::Os),
+            "chassis" => Ok(Self::Chassis),
+            "hostname" | "host" => Ok(Self::Hostname),
+            _ => match s.strip_prefix("env.") {
+                Some(name) if !name.is_empty() => Ok(Self::Env(name.to_string())),
+                _ => Err(format!(
+                    "Unknown fact '{s}'; expected os, chassis, hostname or env.NAME"
+                )),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Os => f.write_str("os"),
+            Self::Chassis => f.write_str("chassis"),
+            Self::Hostname => f.write_str("hostname"),
+            Self::Env(name) => write!(f, "env.{name}"),
+        }
+    }
+}
+
+/// When a module entry is shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// The OS is one of these, e.g. `["linux", "freebsd"]`
+    Os(Vec<String>),
+    /// `fact == value`, or `fact != value` when `negate` is set
+    Compare {
+        fact: Fact,
+        negate: bool,
+        value: String,
+    },
+}
+
+impl Condition {
+    /// Whether the condition holds on this machine
+    ///
+    /// A fact that can't be determined equals nothing.
+    pub fn holds(&self, ctx: &dyn SystemContext) -> bool {
+        match self {
+            Self::Os(names) => names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(std::env::consts::OS)),
+            Self::Compare {
+                fact,
+                negate,
+                value,
+            } => {
+                let equal = fact
+                    .value(ctx)
+                    .is_some_and(|actual| actual.trim().eq_ignore_ascii_case(value));
+                equal != *negate
+            }
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    /// Parse `fact == value` or `fact != value`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (fact, negate, value) = if let Some((fact, value)) = s.split_once("!=") {
+            (fact, true, value)
+        } else if let Some((fact, value)) = s.split_once("==") {
+            (fact, false, value)
+        } else {
+            return Err(format!(
+                "Expected 'FACT == VALUE' or 'FACT != VALUE', got '{s}'"
+            ));
+        };
+
+        let value = value.trim().trim_matches(['"', '\'']);
+        if value.is_empty() {
+            return Err(format!("Missing value in condition '{s}'"));
+        }
+        Ok(Self::Compare {
+            fact: fact.trim().parse()?,
+            negate,
+            value: value.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Os(names) => write!(f, "os in [{}]", names.join(", ")),
+            Self::Compare {
+                fact,
+                negate,
+                value,
+            } => {
+                let op = if *negate { "!=" } else { "==" };
+                write!(f, "{fact} {op} {value}")
+            }
+        }
+    }
+}
+
+/// Broad kind of machine, from the SMBIOS chassis type
+fn chassis(ctx: &dyn SystemContext) -> &'static str {
+    ctx.read_file(std::path::Path::new("/sys/class/dmi/id/chassis_type"))
+        .ok()
+        .and_then(|code| code.trim().parse().ok())
+        .map_or("unknown", chassis_category)
+}
+
+/// Group SMBIOS chassis type codes (DSP0134, 7.4.1) into broad categories
+fn chassis_category(code: u32) -> &'static str {
+    match code {
+        // Desktop, low profile, pizza box, mini tower, tower, space-saving,
+        // all in one, docking station, lunch box, sealed-case PC, mini PC, stick PC
+        3..=7 | 13 | 15 | 16 | 24 | 35 | 36 => "desktop",
+        // Portable, laptop, notebook, sub notebook, convertible, detachable
+        8..=10 | 14 | 31 | 32 => "laptop",
+        // Main server chassis, rack mount, multi-system, blade, blade enclosure
+        17 | 23 | 25 | 28 | 29 => "server",
+        11 => "handheld",
+        30 => "tablet",
+        2 => "unknown",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RealSystemContext;
+
+    #[test]
+    fn test_parse_condition() {
+        assert_eq!(
+            "chassis == laptop".parse(),
+            Ok(Condition::Compare {
+                fact: Fact::Chassis,
+                negate: false,
+                value: "laptop".to_string()
+            })
+        );
+        assert_eq!(
+            "env.XDG_SESSION_TYPE != 'wayland'"
+                .parse::<Condition>()
+                .unwrap()
+                .to_string(),
+            "env.XDG_SESSION_TYPE != wayland"
+        );
+        assert!("chassis laptop".parse::<Condition>().is_err());
+        assert!("colour == red".parse::<Condition>().is_err());
+        assert!("os ==".parse::<Condition>().is_err());
+    }
+
+    #[test]
+    fn test_os_condition() {
+        let ctx = RealSystemContext;
+        let os = std::env::consts::OS;
+        assert!(Condition::Os(vec!["plan9".into(), os.to_uppercase()]).holds(&ctx));
+        assert!(!Condition::Os(vec!["plan9".into()]).holds(&ctx));
+        assert!(
+            format!("os == {os}")
+                .parse::<Condition>()
+                .unwrap()
+                .holds(&ctx)
+        );
+        assert!("os != plan9".parse::<Condition>().unwrap().holds(&ctx));
+    }
+
+    #[test]
+    fn test_missing_fact() {
+        let ctx = RealSystemContext;
+        let unset = "env.FASTFETCH_RS_SURELY_UNSET";
+        assert!(
+            !format!("{unset} == 1")
+                .parse::<Condition>()
+                .unwrap()
+                .holds(&ctx)
+        );
+        assert!(
+            format!("{unset} != 1")
+                .parse::<Condition>()
+                .unwrap()
+                .holds(&ctx)
+        );
+    }
+
+    #[test]
+    fn test_chassis_category() {
+        assert_eq!(chassis_category(10), "laptop");
+        assert_eq!(chassis_category(3), "desktop");
+        assert_eq!(chassis_category(23), "server");
+        assert_eq!(chassis_category(1), "other");
+    }
+}
@@ -0,0 +1,1431 @@
+//! Configuration file loading
+//!
+//! The config file is either JSONC (plain JSON that also allows `//` and
+//! `/* */` comments and trailing commas) or TOML. Both deserialize into the
+//! same [`ConfigFile`], so every setting is available in either format. It
+//! lives at `~/.config/fastfetch-rs/config.jsonc` (or `config.toml`) and
+//! looks like:
+//!
+//! ```jsonc
+//! {
+//!     "modules": ["title", "separator", "os", "kernel", "cpu", "memory"],
+//!     "logo": { "enabled": true },
+//!     "display": { "values_only": false, "separator": ": ", "key_align": "left" },
+//!     "general": { "parallel": true, "sample_interval": 200 },
+//!     "formats": { "kernel": "{name} {version}{?arch} ({arch}){?}" },
+//!     "options": {
+//!         "cputemp": { "unit": "fahrenheit" },
+//!     },
+//! }
+//! ```
+//!
+//! or, in TOML:
+//!
+//! ```toml
+//! modules = ["title", "separator", "os", "kernel", "cpu", "memory"]
+//!
+//! [display]
+//! values_only = false
+//!
+//! [formats]
+//! kernel = "{name} {version}{?arch} ({arch}){?}"
+//! ```
+//!
+//! Formats use the [`Template`](crate::output::Template) syntax.
+//!
+//! Besides plain ids, `modules` accepts tables that give one entry its own
+//! options, so a kind can be listed more than once:
+//!
+//! ```jsonc
+//! "modules": [
+//!     "os",
+//!     { "type": "custom", "command": "uptime -p", "label": "Up" },
+//!     { "type": "custom", "command": "date +%F", "label": "Date" },
+//!     { "type": "battery", "if": "chassis == laptop" },
+//! ]
+//! ```
+//!
+//! `if` and `if_os` limit an entry to some machines; see
+//! [`condition`](super::condition).
+//!
+//! A file can build on others with `include`; see [`include`](super::include)
+//! for how layers are resolved:
+//!
+//! ```jsonc
+//! {
+//!     "include": ["preset:minimal", "base.jsonc"],
+//!     "display": { "separator": " -> " },
+//! }
+//! ```
+//!
+//! Every key is optional. Values from the file are applied to a
+//! [`ConfigBuilder`] first so CLI flags set afterwards take precedence.
+
+use super::{Condition, ConfigBuilder, ModuleEntry};
+use crate::{
+    ModuleKind, ModuleOverrides, TemperatureUnit,
+    context::SystemContext,
+    output::{BarMode, Color, Template, TextStyle, Thresholds, check_width},
+    platform::xdg,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// Directory under the config home holding fastfetch-rs configuration
+pub const CONFIG_DIR: &str = "fastfetch-rs";
+
+/// File name of the JSONC config
+pub const JSONC_FILE: &str = "config.jsonc";
+
+/// File name of the TOML config
+pub const TOML_FILE: &str = "config.toml";
+
+/// System-wide config directory, searched after the user's
+pub const SYSTEM_CONFIG_DIR: &str = "/etc/fastfetch-rs";
+
+/// Syntax of a config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Jsonc,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Pick the format from a file extension; anything but `.toml` is read as JSONC
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Jsonc,
+        }
+    }
+}
+
+/// Errors raised while loading a config file
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The file could not be read
+    #[error("Failed to read config {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+
+    /// A config named with `--config` was not found in any search location
+    #[error("Config '{name}' not found. Searched:{}", list_paths(tried))]
+    NotFound { name: String, tried: Vec<PathBuf> },
+
+    /// `--preset` named a preset that isn't bundled
+    #[error("Unknown preset '{name}'. Available: {available}")]
+    UnknownPreset { name: String, available: String },
+
+    /// The file is not valid JSONC or doesn't match the expected layout
+    #[error("Invalid config {}: {message}", path.display())]
+    Parse { path: PathBuf, message: String },
+
+    /// A value is well-formed but not accepted, e.g. an unknown color
+    #[error("Invalid value for '{key}': {message}")]
+    Value { key: String, message: String },
+
+    /// A config includes itself, directly or through other files
+    #[error("Config include cycle: {}", chain.join(" -> "))]
+    IncludeCycle { chain: Vec<String> },
+
+    /// Includes are nested deeper than [`MAX_INCLUDE_DEPTH`](super::include::MAX_INCLUDE_DEPTH)
+    #[error("Config includes nested more than {max} levels deep at '{include}'")]
+    IncludeDepth { include: String, max: usize },
+}
+
+/// Deserialized contents of a config file
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// JSON schema reference for editor completion; ignored
+    #[serde(rename = "$schema")]
+    pub schema: Option<String>,
+    /// Configs applied before this one, in order; a path or `preset:NAME`
+    #[serde(
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub include: Vec<String>,
+    /// Modules to display, in order
+    pub modules: Option<Vec<ModuleSpec>>,
+    pub logo: LogoSection,
+    pub display: DisplaySection,
+    pub general: GeneralSection,
+    /// Extra module names, mapped to the module id they stand for
+    pub aliases: BTreeMap<String, String>,
+    /// Labels shown instead of module names, keyed by module id
+    pub labels: BTreeMap<String, String>,
+    /// Label styles like "bold blue", keyed by module id
+    pub key_colors: BTreeMap<String, String>,
+    /// Value styles, keyed by module id
+    pub value_colors: BTreeMap<String, String>,
+    /// Output templates keyed by module id
+    pub formats: BTreeMap<String, String>,
+    /// Columns value lines are cut short at, keyed by module id
+    pub max_lengths: BTreeMap<String, usize>,
+    /// Options keyed by the id of the module they apply to
+    pub options: OptionsSection,
+    /// Resolved `include`s, applied before this file's own settings
+    #[serde(skip)]
+    pub(crate) layers: Vec<ConfigFile>,
+}
+
+/// An item of `"modules"`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ModuleSpec {
+    /// A module id using the shared options, e.g. `"kernel"`
+    Id(String),
+    /// A module with options of its own
+    Entry(Box<EntrySpec>),
+}
+
+/// A `{ "type": "custom", "command": "uptime -p" }` item of `"modules"`
+///
+/// Keys mirror the `"options"` sections, plus `label` and `format` for how
+/// the entry is shown.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntrySpec {
+    /// Module id
+    #[serde(rename = "type", alias = "module")]
+    pub kind: String,
+    pub format: Option<String>,
+    pub bar: Option<String>,
+    #[serde(default)]
+    pub thresholds: BTreeMap<String, String>,
+    pub max_length: Option<usize>,
+    pub unit: Option<String>,
+    pub sample_interval: Option<u64>,
+    pub show_tainted: Option<bool>,
+    pub hide_hubs: Option<bool>,
+    pub command: Option<String>,
+    pub timeout: Option<u64>,
+    pub text: Option<String>,
+    pub color: Option<String>,
+    pub label: Option<String>,
+    pub key_color: Option<String>,
+    pub value_color: Option<String>,
+    /// A condition like "chassis == laptop"
+    #[serde(rename = "if")]
+    pub condition: Option<String>,
+    /// Operating systems the entry is shown on
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub if_os: Vec<String>,
+}
+
+impl ModuleSpec {
+    /// The module name as written, which may be an alias
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Id(id) => id,
+            Self::Entry(spec) => &spec.kind,
+        }
+    }
+
+    /// Resolve the item at `index` of the list, with module names looked
+    /// up by `builder` so aliases apply
+    ///
+    /// An unknown module id is not an error here: it comes back as
+    /// `Ok(Err(id))` so it can be skipped with a warning like on the CLI.
+    pub fn resolve(
+        &self,
+        index: usize,
+        builder: &ConfigBuilder,
+    ) -> Result<Result<ModuleEntry, String>, ConfigError> {
+        let spec = match self {
+            Self::Id(id) => {
+                return Ok(builder
+                    .parse_module(id)
+                    .map(ModuleEntry::new)
+                    .map_err(|_| id.clone()));
+            }
+            Self::Entry(spec) => spec,
+        };
+        let Ok(kind) = builder.parse_module(&spec.kind) else {
+            return Ok(Err(spec.kind.clone()));
+        };
+
+        let key = |name: &str| format!("modules[{index}].{name}");
+        let overrides = ModuleOverrides {
+            temperature_unit: parse_optional::<TemperatureUnit>(&key("unit"), &spec.unit)?,
+            format: parse_optional::<Template>(&key("format"), &spec.format)?,
+            bar: parse_optional::<BarMode>(&key("bar"), &spec.bar)?,
+            thresholds: parse_thresholds(&key("thresholds"), &spec.thresholds)?,
+            max_length: spec.max_length,
+            sample_interval: spec.sample_interval.map(Duration::from_millis),
+            show_tainted_modules: spec.show_tainted,
+            usb_hide_hubs: spec.hide_hubs,
+            custom_command: spec.command.clone(),
+            custom_timeout: spec.timeout.map(Duration::from_millis),
+            text: spec.text.clone(),
+            text_color: parse_optional::<Color>(&key("color"), &spec.color)?,
+        };
+        let mut conditions = Vec::new();
+        if !spec.if_os.is_empty() {
+            conditions.push(Condition::Os(spec.if_os.clone()));
+        }
+        conditions.extend(parse_optional::<Condition>(&key("if"), &spec.condition)?);
+
+        let entry = ModuleEntry::with_overrides(kind, overrides)
+            .with_label(spec.label.clone())
+            .with_styles(
+                parse_optional::<TextStyle>(&key("key_color"), &spec.key_color)?,
+                parse_optional::<TextStyle>(&key("value_color"), &spec.value_color)?,
+            )
+            .with_conditions(conditions);
+        Ok(Ok(entry))
+    }
+}
+
+/// `"logo"` section
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogoSection {
+    /// Set to false to hide the logo
+    pub enabled: Option<bool>,
+    /// Custom ASCII art used instead of the detected distro logo
+    pub ascii: Option<String>,
+    /// Logo from the database used instead of the detected one, e.g. "arch"
+    pub name: Option<String>,
+    /// File with ASCII art, which may contain ANSI escapes; relative to the
+    /// config file unless it starts with `~/`
+    pub source: Option<PathBuf>,
+    /// Colors replacing the logo's, by number: `{ "1": "blue", "2": "#ff8800" }`
+    pub colors: BTreeMap<String, String>,
+    /// "left", "right" or "bottom"
+    pub position: Option<String>,
+}
+
+/// `"display"` section
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DisplaySection {
+    pub values_only: Option<bool>,
+    /// Leave out modules whose detection failed
+    pub hide_errors: Option<bool>,
+    /// Leave out modules with nothing to show
+    pub hide_unavailable: Option<bool>,
+    /// Show each module's detection time
+    pub stat: Option<bool>,
+    /// End with swatches of the terminal's colors
+    pub colors: Option<bool>,
+    /// Draw a box around the module lines
+    pub boxed: Option<bool>,
+    /// "side", "top" or "grid"
+    pub layout: Option<String>,
+    /// Text between a label and its value
+    pub separator: Option<String>,
+    /// Show every value on one line
+    pub oneline: Option<bool>,
+    /// Text between the values on one line
+    pub delimiter: Option<String>,
+    /// Columns value lines are cut short at with `…`
+    pub max_length: Option<usize>,
+    /// "left", "right" or "none"
+    pub key_align: Option<String>,
+    /// Fixed width labels are padded to
+    pub key_width: Option<usize>,
+    /// Glyphs in front of labels: "none", "nerd" or "emoji"
+    pub icons: Option<String>,
+    /// Columns a line may take up, at least 20; 0 turns wrapping off
+    pub width: Option<usize>,
+    /// Cells in percentage bars
+    pub bar_width: Option<usize>,
+    /// Text of a filled bar cell
+    pub bar_filled: Option<String>,
+    /// Text of an empty bar cell
+    pub bar_empty: Option<String>,
+    /// Style of every label, e.g. "bold blue"
+    pub key_color: Option<String>,
+    /// Style of every value
+    pub value_color: Option<String>,
+}
+
+/// `"general"` section
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GeneralSection {
+    pub parallel: Option<bool>,
+    /// Sampling window in milliseconds for diskio and netio
+    pub sample_interval: Option<u64>,
+}
+
+/// `"options"` section
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OptionsSection {
+    pub memory: CapacityOptions,
+    pub swap: CapacityOptions,
+    pub battery: CapacityOptions,
+    pub brightness: CapacityOptions,
+    pub cputemp: CpuTempOptions,
+    pub kernelmodules: KernelModulesOptions,
+    pub usb: UsbOptions,
+    pub custom: CustomOptions,
+    pub text: TextOptions,
+}
+
+/// Options of a module that reports how full it is
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CapacityOptions {
+    /// "off", "on" or "prefix"
+    pub bar: Option<String>,
+    /// Value styles keyed by the percentage they start at, e.g. `{ "80": "red" }`
+    pub thresholds: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CpuTempOptions {
+    /// "celsius" or "fahrenheit"
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KernelModulesOptions {
+    pub show_tainted: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UsbOptions {
+    pub hide_hubs: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomOptions {
+    pub command: Option<String>,
+    /// Deprecated in favor of `labels.custom`
+    pub label: Option<String>,
+    /// Milliseconds before the command is killed
+    pub timeout: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TextOptions {
+    pub text: Option<String>,
+    /// A color name like "cyan" or a "#rrggbb" code
+    pub color: Option<String>,
+}
+
+impl ConfigFile {
+    /// Directories searched for config files, in order of preference:
+    /// `$XDG_CONFIG_HOME/fastfetch-rs`, `~/.config/fastfetch-rs`, `/etc/fastfetch-rs`
+    pub fn search_dirs(ctx: &dyn SystemContext) -> Vec<PathBuf> {
+        let xdg_config_home = ctx
+            .get_env("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        let home_config = xdg::home_dir(ctx).map(|home| home.join(".config"));
+
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        for base in [xdg_config_home, home_config].into_iter().flatten() {
+            let dir = base.join(CONFIG_DIR);
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        if cfg!(unix) {
+            dirs.push(PathBuf::from(SYSTEM_CONFIG_DIR));
+        }
+        dirs
+    }
+
+    /// Default locations in order of preference: `config.jsonc`, then
+    /// `config.toml`, in each of the [search directories](Self::search_dirs)
+    pub fn default_paths(ctx: &dyn SystemContext) -> Vec<PathBuf> {
+        Self::search_dirs(ctx)
+            .into_iter()
+            .flat_map(|dir| [dir.join(JSONC_FILE), dir.join(TOML_FILE)])
+            .collect()
+    }
+
+    /// Load the first config that exists in a default location
+    pub fn load_default(ctx: &dyn SystemContext) -> Result<Option<Self>, ConfigError> {
+        Ok(load_first(ctx, &Self::default_paths(ctx))?.map(|(_, file)| file))
+    }
+
+    /// Path of the config [`load_default`](Self::load_default) would use
+    pub fn find_default(ctx: &dyn SystemContext) -> Option<PathBuf> {
+        first_existing(ctx, &Self::default_paths(ctx))
+    }
+
+    /// Path of the config [`load_named`](Self::load_named) would use
+    pub fn find_named(ctx: &dyn SystemContext, name: &str) -> Result<PathBuf, ConfigError> {
+        let tried = named_candidates(name, &Self::search_dirs(ctx));
+        first_existing(ctx, &tried).ok_or_else(|| ConfigError::NotFound {
+            name: name.to_string(),
+            tried,
+        })
+    }
+
+    /// Load the config passed to `--config`
+    ///
+    /// A path is used as given. A bare name such as `work` is also looked up
+    /// as `work`, `work.jsonc` and `work.toml` in each search directory.
+    pub fn load_named(ctx: &dyn SystemContext, name: &str) -> Result<Self, ConfigError> {
+        let tried = named_candidates(name, &Self::search_dirs(ctx));
+
+        match load_first(ctx, &tried)? {
+            Some((_, file)) => Ok(file),
+            None => Err(ConfigError::NotFound {
+                name: name.to_string(),
+                tried,
+            }),
+        }
+    }
+
+    /// Read and parse a config file along with everything it includes
+    pub fn load(ctx: &dyn SystemContext, path: &Path) -> Result<Self, ConfigError> {
+        let mut file = Self::read(ctx, path)?;
+        file.resolve_includes(Some(ctx), path)?;
+        Ok(file)
+    }
+
+    /// Read and parse a single config file, leaving its includes unresolved
+    pub(crate) fn read(ctx: &dyn SystemContext, path: &Path) -> Result<Self, ConfigError> {
+        let content = ctx.read_file(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let mut file = Self::parse(&content, ConfigFormat::from_path(path)).map_err(|message| {
+            ConfigError::Parse {
+                path: path.to_path_buf(),
+                message,
+            }
+        })?;
+        // A logo file next to the config is found wherever it's run from
+        if let Some(source) = &mut file.logo.source {
+            if let (Ok(rest), Some(home)) = (source.strip_prefix("~"), xdg::home_dir(ctx)) {
+                *source = home.join(rest);
+            } else if let Some(dir) = path.parent().filter(|_| source.is_relative()) {
+                *source = dir.join(&*source);
+            }
+        }
+        Ok(file)
+    }
+
+    /// Parse config text in the given format
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, String> {
+        match format {
+            ConfigFormat::Jsonc => Self::parse_jsonc(content),
+            ConfigFormat::Toml => Self::parse_toml(content),
+        }
+    }
+
+    /// Parse JSONC text
+    pub fn parse_jsonc(content: &str) -> Result<Self, String> {
+        serde_json::from_str(&strip_jsonc(content)).map_err(|err| err.to_string())
+    }
+
+    /// Parse JSONC text into an untyped document
+    pub(crate) fn jsonc_value(content: &str) -> Result<serde_json::Value, String> {
+        serde_json::from_str(&strip_jsonc(content)).map_err(|err| err.to_string())
+    }
+
+    /// Parse TOML text
+    pub fn parse_toml(content: &str) -> Result<Self, String> {
+        // toml's messages span several lines with a source excerpt; keep them intact
+        toml::from_str(content).map_err(|err| err.to_string().trim_end().to_string())
+    }
+
+    /// The file's own settings as config text in `format`, leaving out the
+    /// keys it doesn't set; includes are written as they were given
+    pub fn render(&self, format: ConfigFormat) -> Result<String, String> {
+        let mut value = serde_json::to_value(self).map_err(|err| err.to_string())?;
+        prune(&mut value);
+        match format {
+            ConfigFormat::Jsonc => pretty_json(&value).map(|json| json + "\n"),
+            ConfigFormat::Toml => toml::to_string(&value).map_err(|err| err.to_string()),
+        }
+    }
+
+    /// The module list in effect: the file's own, or else that of the last
+    /// include setting one
+    pub fn effective_modules(&self) -> Option<&[ModuleSpec]> {
+        self.modules.as_deref().or_else(|| {
+            self.layers
+                .iter()
+                .rev()
+                .find_map(ConfigFile::effective_modules)
+        })
+    }
+
+    /// Feed the file's settings into a builder, after those of its includes
+    pub fn apply(&self, mut builder: ConfigBuilder) -> Result<ConfigBuilder, ConfigError> {
+        for layer in &self.layers {
+            builder = layer.apply(builder)?;
+        }
+
+        for (alias, id) in &self.aliases {
+            let key = format!("aliases.{alias}");
+            let kind = parse_value::<ModuleKind>(&key, id)?;
+            builder = builder
+                .module_alias(alias, kind)
+                .map_err(|message| ConfigError::Value { key, message })?;
+        }
+
+        if let Some(modules) = &self.modules {
+            let entries = modules
+                .iter()
+                .enumerate()
+                .map(|(index, spec)| spec.resolve(index, &builder))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder = builder.with_module_entries(entries);
+        }
+
+        if self.logo.enabled == Some(false) {
+            builder = builder.without_logo();
+        } else if let Some(ascii) = &self.logo.ascii {
+            builder = builder.with_logo_ascii(ascii.clone());
+        } else if let Some(source) = &self.logo.source {
+            builder = builder.with_logo_source(source.clone());
+        } else if let Some(name) = &self.logo.name {
+            builder = builder
+                .with_logo_name(name)
+                .map_err(|message| ConfigError::Value {
+                    key: "logo.name".to_string(),
+                    message,
+                })?;
+        }
+        if let Some(position) = &self.logo.position {
+            builder = builder.logo_position(parse_value("logo.position", position)?);
+        }
+        for (index, color) in &self.logo.colors {
+            let key = format!("logo.colors.{index}");
+            let color = parse_value::<Color>(&key, color)?;
+            let index = parse_value::<usize>(&key, index)?;
+            builder = builder
+                .logo_color(index, color)
+                .map_err(|message| ConfigError::Value { key, message })?;
+        }
+
+        if let Some(values_only) = self.display.values_only {
+            builder = builder.values_only(values_only);
+        }
+        if let Some(hide) = self.display.hide_errors {
+            builder = builder.hide_errors(hide);
+        }
+        if let Some(hide) = self.display.hide_unavailable {
+            builder = builder.hide_unavailable(hide);
+        }
+        if let Some(stat) = self.display.stat {
+            builder = builder.stat(stat);
+        }
+        if let Some(colors) = self.display.colors {
+            builder = builder.color_blocks(colors);
+        }
+        if let Some(boxed) = self.display.boxed {
+            builder = builder.boxed(boxed);
+        }
+        if let Some(layout) = &self.display.layout {
+            builder = builder.layout(parse_value("display.layout", layout)?);
+        }
+        if let Some(separator) = &self.display.separator {
+            builder = builder.separator(separator.clone());
+        }
+        if let Some(oneline) = self.display.oneline {
+            builder = builder.oneline(oneline);
+        }
+        if let Some(delimiter) = &self.display.delimiter {
+            builder = builder.delimiter(delimiter.clone());
+        }
+        if let Some(columns) = self.display.max_length {
+            builder = builder.max_length(columns);
+        }
+        if let Some(align) = &self.display.key_align {
+            builder = builder.key_align(parse_value("display.key_align", align)?);
+        }
+        if let Some(width) = self.display.key_width {
+            builder = builder.key_width(width);
+        }
+        if let Some(icons) = &self.display.icons {
+            builder = builder.icons(parse_value("display.icons", icons)?);
+        }
+        if let Some(columns) = self.display.width {
+            let columns = check_width(columns).map_err(|message| ConfigError::Value {
+                key: "display.width".to_string(),
+                message,
+            })?;
+            builder = builder.width(columns);
+        }
+        if let Some(width) = self.display.bar_width {
+            builder = builder.bar_width(width);
+        }
+        if let Some(text) = &self.display.bar_filled {
+            builder = builder.bar_filled(text.clone());
+        }
+        if let Some(text) = &self.display.bar_empty {
+            builder = builder.bar_empty(text.clone());
+        }
+        if let Some(style) = &self.display.key_color {
+            builder = builder.key_style(parse_value("display.key_color", style)?);
+        }
+        if let Some(style) = &self.display.value_color {
+            builder = builder.value_style(parse_value("display.value_color", style)?);
+        }
+        if let Some(parallel) = self.general.parallel {
+            builder = builder.parallel(parallel);
+        }
+        if let Some(ms) = self.general.sample_interval {
+            builder = builder.sample_interval(Duration::from_millis(ms));
+        }
+
+        for (id, label) in &self.labels {
+            let kind = parse_kind(&builder, &format!("labels.{id}"), id)?;
+            builder = builder.module_label(kind, label.clone());
+        }
+        for (id, style) in &self.key_colors {
+            let key = format!("key_colors.{id}");
+            let kind = parse_kind(&builder, &key, id)?;
+            builder = builder.module_key_style(kind, parse_value::<TextStyle>(&key, style)?);
+        }
+        for (id, style) in &self.value_colors {
+            let key = format!("value_colors.{id}");
+            let kind = parse_kind(&builder, &key, id)?;
+            builder = builder.module_value_style(kind, parse_value::<TextStyle>(&key, style)?);
+        }
+        for (id, format) in &self.formats {
+            let key = format!("formats.{id}");
+            let kind = parse_kind(&builder, &key, id)?;
+            builder = builder.module_format(kind, parse_value::<Template>(&key, format)?);
+        }
+        for (id, &columns) in &self.max_lengths {
+            let kind = parse_kind(&builder, &format!("max_lengths.{id}"), id)?;
+            builder = builder.module_max_length(kind, columns);
+        }
+
+        let options = &self.options;
+        let capacity = [
+            (ModuleKind::Memory, &options.memory),
+            (ModuleKind::Swap, &options.swap),
+            (ModuleKind::Battery, &options.battery),
+            (ModuleKind::Brightness, &options.brightness),
+        ];
+        for (kind, section) in capacity {
+            if let Some(mode) = &section.bar {
+                let key = format!("options.{}.bar", kind.id());
+                builder = builder.module_bar(kind, parse_value(&key, mode)?);
+            }
+            let key = format!("options.{}.thresholds", kind.id());
+            if let Some(thresholds) = parse_thresholds(&key, &section.thresholds)? {
+                builder = builder.module_thresholds(kind, thresholds);
+            }
+        }
+        if let Some(unit) = &options.cputemp.unit {
+            builder = builder.temperature_unit(parse_value("options.cputemp.unit", unit)?);
+        }
+        if let Some(show) = options.kernelmodules.show_tainted {
+            builder = builder.show_tainted_modules(show);
+        }
+        if let Some(hide) = options.usb.hide_hubs {
+            builder = builder.usb_hide_hubs(hide);
+        }
+        if let Some(command) = &options.custom.command {
+            builder = builder.custom_command(command.clone(), options.custom.label.clone());
+        }
+        if let Some(ms) = options.custom.timeout {
+            builder = builder.custom_timeout(Duration::from_millis(ms));
+        }
+        if let Some(text) = &options.text.text {
+            let color = options
+                .text
+                .color
+                .as_deref()
+                .map(|color| parse_value::<Color>("options.text.color", color))
+                .transpose()?;
+            builder = builder.text(text.clone(), color);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Accept either a single string or a list of them
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(item) => vec![item],
+        OneOrMany::Many(items) => items,
+    })
+}
+
+/// Load the first candidate that exists, skipping missing files but not broken ones
+fn load_first(
+    ctx: &dyn SystemContext,
+    candidates: &[PathBuf],
+) -> Result<Option<(PathBuf, ConfigFile)>, ConfigError> {
+    for path in candidates {
+        match ConfigFile::load(ctx, path) {
+            Err(ConfigError::Read { source, .. })
+                if matches!(
+                    source.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::IsADirectory
+                ) => {}
+            result => return result.map(|file| Some((path.clone(), file))),
+        }
+    }
+    Ok(None)
+}
+
+/// The first candidate that exists and isn't a directory
+fn first_existing(ctx: &dyn SystemContext, candidates: &[PathBuf]) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .find(|path| match ctx.read_file(path) {
+            Err(err) => !matches!(
+                err.kind(),
+                io::ErrorKind::NotFound | io::ErrorKind::IsADirectory
+            ),
+            Ok(_) => true,
+        })
+        .cloned()
+}
+
+/// Paths a `--config` argument may refer to
+fn named_candidates(name: &str, dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let given = PathBuf::from(name);
+    let mut candidates = vec![given.clone()];
+
+    // Anything with a directory component is an explicit path
+    let is_bare = given.components().count() == 1 && !given.is_absolute();
+    if is_bare {
+        for dir in dirs {
+            candidates.push(dir.join(name));
+            if given.extension().is_none() {
+                candidates.push(dir.join(format!("{name}.jsonc")));
+                candidates.push(dir.join(format!("{name}.toml")));
+            }
+        }
+    }
+    candidates
+}
+
+/// One path per line for error messages
+fn list_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("\n  {}", path.display()))
+        .collect()
+}
+
+/// Thresholds written as `{ "80": "red" }`, where a bound may end in `%`;
+/// `None` when there are none
+fn parse_thresholds(
+    key: &str,
+    bands: &BTreeMap<String, String>,
+) -> Result<Option<Thresholds>, ConfigError> {
+    if bands.is_empty() {
+        return Ok(None);
+    }
+    let bands = bands
+        .iter()
+        .map(|(bound, style)| {
+            let key = format!("{key}.{bound}");
+            let bound = parse_value::<f64>(&key, bound.trim().trim_end_matches('%'))?;
+            Ok((bound, parse_value::<TextStyle>(&key, style)?))
+        })
+        .collect::<Result<_, ConfigError>>()?;
+    Ok(Some(Thresholds::new(bands)))
+}
+
+/// Drop unset keys, and sections left empty, from a serialized config
+fn prune(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.values_mut().for_each(prune);
+            map.retain(|_, value| {
+                !value.is_null() && value.as_object().is_none_or(|map| !map.is_empty())
+            });
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(prune),
+        _ => {}
+    }
+}
+
+/// JSON indented by four spaces like generated configs
+pub(super) fn pretty_json(value: &serde_json::Value) -> Result<String, String> {
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+    value
+        .serialize(&mut serializer)
+        .map_err(|err| err.to_string())?;
+    String::from_utf8(out).map_err(|err| err.to_string())
+}
+
+/// Parse a string value with its `FromStr` impl, naming the key on failure
+fn parse_value<T>(key: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value.parse().map_err(|err: T::Err| ConfigError::Value {
+        key: key.to_string(),
+        message: err.to_string(),
+    })
+}
+
+/// Parse a module name, honoring the builder's aliases
+fn parse_kind(builder: &ConfigBuilder, key: &str, id: &str) -> Result<ModuleKind, ConfigError> {
+    builder
+        .parse_module(id)
+        .map_err(|message| ConfigError::Value {
+            key: key.to_string(),
+            message,
+        })
+}
+
+fn parse_optional<T>(key: &str, value: &Option<String>) -> Result<Option<T>, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .as_deref()
+        .map(|value| parse_value(key, value))
+        .transpose()
+}
+
+/// Turn JSONC into plain JSON by blanking out comments and trailing commas
+///
+/// Removed characters are replaced with spaces (newlines are kept) so the
+/// line and column of parse errors still point into the original file.
+fn strip_jsonc(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let blank = |c: char| if c == '\n' { '\n' } else { ' ' };
+
+    let mut i = 0;
+    let mut in_string = false;
+    // Position of a comma that may turn out to be trailing
+    let mut pending_comma: Option<usize> = None;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(&escaped) = chars.get(i + 1) {
+                    out.push(escaped);
+                    i += 1;
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match (c, chars.get(i + 1)) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    out.push(' ');
+                    i += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                let end = (i + 2..chars.len().saturating_sub(1))
+                    .find(|&j| chars[j] == '*' && chars[j + 1] == '/')
+                    .map_or(chars.len(), |j| j + 2);
+                out.extend(chars[i..end].iter().map(|&c| blank(c)));
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+
+        if !c.is_whitespace() {
+            if let Some(comma) = pending_comma.take()
+                && (c == '}' || c == ']')
+            {
+                out[comma] = ' ';
+            }
+            if c == ',' {
+                pending_comma = Some(out.len());
+            }
+        }
+        in_string = c == '"';
+        out.push(c);
+        i += 1;
+    }
+
+    out.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModuleKind, RealSystemContext, TemperatureUnit};
+    use std::fs;
+
+    const SAMPLE: &str = r#"
+        // Comments are allowed
+        {
+            "modules": ["title", "os", "kernel", /* "cpu", */ "memory",],
+            "logo": { "enabled": false },
+            "display": { "values_only": true },
+            "formats": { "kernel": "{name} // {version}" },
+            "options": {
+                "cputemp": { "unit": "fahrenheit" },
+            },
+        }
+    "#;
+
+    #[test]
+    fn test_strip_jsonc() {
+        assert_eq!(strip_jsonc("[1, 2, ]"), "[1, 2  ]");
+        assert_eq!(strip_jsonc("{\"a\": \"/* x */\"}"), "{\"a\": \"/* x */\"}");
+        assert_eq!(strip_jsonc("{\"a\": \"\\\"//\"}"), "{\"a\": \"\\\"//\"}");
+        assert_eq!(strip_jsonc("1 /* a\nb */"), "1     \n    ");
+    }
+
+    #[test]
+    fn test_parse_and_apply() {
+        let file = ConfigFile::parse_jsonc(SAMPLE).unwrap();
+        assert_eq!(file.formats["kernel"], "{name} // {version}");
+
+        let outcome = file.apply(ConfigBuilder::default()).unwrap().build();
+        let config = outcome.config;
+        assert_eq!(
+            config.modules(),
+            [
+                ModuleKind::Title,
+                ModuleKind::Os,
+                ModuleKind::Kernel,
+                ModuleKind::Memory
+            ]
+            .map(ModuleEntry::new)
+        );
+        assert!(config.values_only());
+        assert!(config.logo().is_none());
+        assert_eq!(
+            config.module_options().temperature_unit,
+            TemperatureUnit::Fahrenheit
+        );
+        assert_eq!(
+            config.module_options().formats[&ModuleKind::Kernel].as_str(),
+            "{name} // {version}"
+        );
+    }
+
+    #[test]
+    fn test_module_entries() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": [
+                    "os",
+                    { "type": "custom", "command": "uptime -p", "label": "Up" },
+                    "nonsense",
+                    {
+                        "type": "custom",
+                        "command": "date",
+                        "format": "[{value}]",
+                        "max_length": 20,
+                    },
+                    "os",
+                ],
+                "options": { "custom": { "command": "true", "label": "Shared" } },
+            }"#,
+        )
+        .unwrap();
+        let outcome = file.apply(ConfigBuilder::default()).unwrap().build();
+        assert_eq!(outcome.unknown_modules, ["nonsense"]);
+
+        let config = outcome.config;
+        let kinds: Vec<_> = config.modules().iter().map(|entry| entry.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ModuleKind::Os,
+                ModuleKind::Custom,
+                ModuleKind::Custom,
+                ModuleKind::Os
+            ]
+        );
+
+        let [_, up, date, _] = config.modules() else {
+            unreachable!()
+        };
+        assert_eq!(config.label_for(up).as_deref(), Some("Up"));
+        assert_eq!(config.label_for(date).as_deref(), Some("Shared"));
+
+        let up = config.options_for(up);
+        assert_eq!(up.custom_command.as_deref(), Some("uptime -p"));
+        assert!(up.formats.is_empty());
+
+        let date = config.options_for(date);
+        assert_eq!(date.custom_command.as_deref(), Some("date"));
+        assert!(date.formats.contains_key(&ModuleKind::Custom));
+        assert_eq!(date.max_lengths.get(&ModuleKind::Custom), Some(&20));
+
+        let file = ConfigFile::parse_jsonc(
+            r#"{ "modules": [{ "type": "text", "text": "hi", "color": "plaid" }] }"#,
+        )
+        .unwrap();
+        let err = file.apply(ConfigBuilder::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value for 'modules[0].color': Unknown color: plaid"
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": [
+                    { "module": "battery", "if": "chassis == laptop" },
+                    { "type": "wm", "if_os": "plan9" },
+                    { "type": "os", "if_os": ["plan9", "linux", "macos", "windows", "freebsd"] },
+                ],
+            }"#,
+        )
+        .unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        let [battery, wm, os] = config.modules() else {
+            unreachable!()
+        };
+        assert_eq!(battery.kind, ModuleKind::Battery);
+        assert_eq!(battery.conditions, ["chassis == laptop".parse().unwrap()]);
+
+        let ctx = crate::RealSystemContext;
+        assert!(!wm.is_shown(&ctx));
+        assert!(os.is_shown(&ctx));
+
+        let file = ConfigFile::parse_jsonc(r#"{ "modules": [{ "type": "os", "if": "laptop" }] }"#)
+            .unwrap();
+        assert!(matches!(
+            file.apply(ConfigBuilder::default()),
+            Err(ConfigError::Value { .. })
+        ));
+    }
+
+    #[test]
+    fn test_aliases() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "aliases": { "gfx": "gpudriver", "Fans": "cputemp" },
+                "modules": ["ram", "gfx", { "type": "fans" }],
+                "labels": { "gfx": "Graphics" },
+            }"#,
+        )
+        .unwrap();
+        let builder = file.apply(ConfigBuilder::default()).unwrap();
+        assert_eq!(builder.parse_module("GFX"), Ok(ModuleKind::GpuDriver));
+
+        let config = builder.build().config;
+        let kinds: Vec<_> = config.modules().iter().map(|entry| entry.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ModuleKind::Memory,
+                ModuleKind::GpuDriver,
+                ModuleKind::CpuTemp
+            ]
+        );
+        assert_eq!(
+            config.label_for(&config.modules()[1]).as_deref(),
+            Some("Graphics")
+        );
+
+        let file = ConfigFile::parse_jsonc(r#"{ "aliases": { "gfx": "gpu-drivre" } }"#).unwrap();
+        assert!(file.apply(ConfigBuilder::default()).is_err());
+
+        for shadowing in [r#"{ "Kernel": "os" }"#, r#"{ "ram": "swap" }"#] {
+            let file =
+                ConfigFile::parse_jsonc(&format!(r#"{{ "aliases": {shadowing} }}"#)).unwrap();
+            assert!(matches!(
+                file.apply(ConfigBuilder::default()),
+                Err(ConfigError::Value { key, .. }) if key.starts_with("aliases.")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_labels() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": ["host", { "type": "host", "label": "Model" }, "memory"],
+                "labels": { "host": "Box", "memory": "\uf2db" },
+            }"#,
+        )
+        .unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        let labels: Vec<_> = config
+            .modules()
+            .iter()
+            .map(|entry| config.label_for(entry))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                Some("Box".to_string()),
+                Some("Model".to_string()),
+                Some("\u{f2db}".to_string())
+            ]
+        );
+
+        let file = ConfigFile::parse_jsonc(r#"{ "labels": { "hots": "Box" } }"#).unwrap();
+        assert!(file.apply(ConfigBuilder::default()).is_err());
+    }
+
+    #[test]
+    fn test_colors() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": ["os", "cpu", { "type": "cpu", "value_color": "red" }],
+                "display": { "key_color": "bold blue" },
+                "key_colors": { "cpu": "yellow" },
+                "value_colors": { "cpu": "dim" },
+            }"#,
+        )
+        .unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        assert_eq!(config.display().key_style, "bold blue".parse().unwrap());
+
+        let [os, cpu, red_cpu] = config.modules() else {
+            unreachable!()
+        };
+        assert_eq!(config.key_style_for(os), None);
+        assert_eq!(config.key_style_for(cpu), Some(Color::Yellow.into()));
+        assert_eq!(config.value_style_for(cpu), Some("dim".parse().unwrap()));
+        assert_eq!(config.value_style_for(red_cpu), Some(Color::Red.into()));
+
+        let file = ConfigFile::parse_jsonc(r#"{ "display": { "value_color": "blod" } }"#).unwrap();
+        assert!(file.apply(ConfigBuilder::default()).is_err());
+    }
+
+    #[test]
+    fn test_bars() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{
+                "modules": ["memory", { "type": "battery", "bar": "prefix" }],
+                "display": { "bar_width": 20, "bar_empty": " " },
+                "options": { "memory": { "bar": "on", "thresholds": { "80%": "red" } } },
+            }"#,
+        )
+        .unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        assert_eq!(config.display().bar.width, 20);
+        assert_eq!(config.display().bar.filled, "█");
+        assert_eq!(config.display().bar.empty, " ");
+
+        let [memory, battery] = config.modules() else {
+            unreachable!()
+        };
+        assert_eq!(
+            config.options_for(memory).bars[&ModuleKind::Memory],
+            BarMode::On
+        );
+        assert_eq!(
+            config.options_for(battery).bars[&ModuleKind::Battery],
+            BarMode::Prefix
+        );
+        let thresholds = &config.module_options().thresholds[&ModuleKind::Memory];
+        assert_eq!(thresholds.style_for(90.0), Some(Color::Red.into()));
+        assert_eq!(thresholds.style_for(79.0), None);
+
+        let file =
+            ConfigFile::parse_jsonc(r#"{ "options": { "swap": { "bar": "half" } } }"#).unwrap();
+        assert!(file.apply(ConfigBuilder::default()).is_err());
+        let file = ConfigFile::parse_jsonc(
+            r#"{ "options": { "swap": { "thresholds": { "high": "red" } } } }"#,
+        )
+        .unwrap();
+        assert!(file.apply(ConfigBuilder::default()).is_err());
+    }
+
+    #[test]
+    fn test_toml_matches_jsonc() {
+        const TOML: &str = r#"
+            # Same settings as SAMPLE
+            modules = ["title", "os", "kernel", "memory"]
+
+            [logo]
+            enabled = false
+
+            [display]
+            values_only = true
+
+            [formats]
+            kernel = "{name} // {version}"
+
+            [options.cputemp]
+            unit = "fahrenheit"
+        "#;
+
+        let path = Path::new("/home/user/.config/fastfetch-rs/config.toml");
+        assert_eq!(ConfigFormat::from_path(path), ConfigFormat::Toml);
+
+        let toml = ConfigFile::parse(TOML, ConfigFormat::Toml).unwrap();
+        let jsonc = ConfigFile::parse_jsonc(SAMPLE).unwrap();
+        assert_eq!(format!("{toml:?}"), format!("{jsonc:?}"));
+        assert!(ConfigFile::parse_toml("moduels = []").is_err());
+    }
+
+    #[test]
+    fn test_named_candidates() {
+        let dirs = [
+            PathBuf::from("/home/user/.config/fastfetch-rs"),
+            PathBuf::from("/etc/fastfetch-rs"),
+        ];
+
+        let bare = named_candidates("work", &dirs);
+        assert_eq!(bare.len(), 7);
+        assert_eq!(bare[0], Path::new("work"));
+        assert_eq!(
+            bare[2],
+            Path::new("/home/user/.config/fastfetch-rs/work.jsonc")
+        );
+        assert_eq!(bare[6], Path::new("/etc/fastfetch-rs/work.toml"));
+
+        let with_ext = named_candidates("work.toml", &dirs);
+        assert_eq!(with_ext.len(), 3);
+
+        let explicit = named_candidates("./configs/work.jsonc", &dirs);
+        assert_eq!(explicit, [PathBuf::from("./configs/work.jsonc")]);
+
+        let err = ConfigError::NotFound {
+            name: "work.toml".to_string(),
+            tried: with_ext,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Config 'work.toml' not found. Searched:\n  work.toml\n  \
+             /home/user/.config/fastfetch-rs/work.toml\n  /etc/fastfetch-rs/work.toml"
+        );
+    }
+
+    #[test]
+    fn test_logo_name() {
+        let apply = |config| {
+            ConfigFile::parse_jsonc(config)
+                .unwrap()
+                .apply(ConfigBuilder::default())
+        };
+
+        let config = apply(r#"{ "logo": { "name": "Arch Linux" } }"#)
+            .unwrap()
+            .build()
+            .config;
+        assert_eq!(config.logo().unwrap().name.as_deref(), Some("arch"));
+
+        assert!(matches!(
+            apply(r#"{ "logo": { "name": "plan9" } }"#),
+            Err(ConfigError::Value { key, .. }) if key == "logo.name"
+        ));
+    }
+
+    #[test]
+    fn test_logo_colors() {
+        let apply = |config: &str| {
+            ConfigFile::parse_jsonc(config)
+                .unwrap()
+                .apply(ConfigBuilder::default())
+        };
+
+        let config =
+            apply(r##"{ "logo": { "name": "arch", "colors": { "1": "214", "2": "#00ff00" } } }"##)
+                .unwrap()
+                .build()
+                .config;
+        let logo = config.logo().unwrap();
+        assert_eq!(logo.name.as_deref(), Some("arch"));
+        assert_eq!(
+            logo.colors,
+            [(1, Color::Indexed(214)), (2, Color::Rgb(0, 255, 0))].into()
+        );
+
+        for colors in [
+            r#"{ "0": "red" }"#,
+            r#"{ "x": "red" }"#,
+            r#"{ "1": "mauve" }"#,
+        ] {
+            let config = format!(r#"{{ "logo": {{ "colors": {colors} }} }}"#);
+            assert!(matches!(apply(&config), Err(ConfigError::Value { .. })));
+        }
+    }
+
+    #[test]
+    fn test_display_width() {
+        let apply = |config: &str| {
+            ConfigFile::parse_jsonc(config)
+                .unwrap()
+                .apply(ConfigBuilder::default())
+        };
+
+        assert!(apply(r#"{ "display": { "width": 0 } }"#).is_ok());
+        assert!(apply(r#"{ "display": { "width": 60 } }"#).is_ok());
+        assert!(matches!(
+            apply(r#"{ "display": { "width": 10 } }"#),
+            Err(ConfigError::Value { key, .. }) if key == "display.width"
+        ));
+    }
+
+    #[test]
+    fn test_logo_source_relative_to_config() {
+        let dir =
+            std::env::temp_dir().join(format!("fastfetch-rs-logo-source-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(&path, "[logo]\nsource = \"art/logo.txt\"\n").unwrap();
+
+        let file = ConfigFile::load(&RealSystemContext, &path).unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        assert_eq!(
+            config.logo().unwrap().source.as_deref(),
+            Some(dir.join("art/logo.txt").as_path())
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(ConfigFile::parse_jsonc(r#"{ "moduels": [] }"#).is_err());
+
+        let file = ConfigFile::parse_jsonc(
+            r#"{ "options": { "text": { "text": "hi", "color": "plaid" } } }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            file.apply(ConfigBuilder::default()),
+            Err(ConfigError::Value { .. })
+        ));
+
+        for formats in [r#"{ "kernal": "{name}" }"#, r#"{ "kernel": "{?arch}" }"#] {
+            let file = ConfigFile::parse_jsonc(&format!(r#"{{ "formats": {formats} }}"#)).unwrap();
+            assert!(matches!(
+                file.apply(ConfigBuilder::default()),
+                Err(ConfigError::Value { .. })
+            ));
+        }
+    }
+}
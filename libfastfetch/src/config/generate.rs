@@ -0,0 +1,647 @@
+//! Default config file generation
+//!
+//! Writes the built-in defaults out as a commented config file so users can
+//! discover settings by example. Both formats are rendered from the same
+//! list of sections, so JSONC and TOML output always agree.
+
+use super::ConfigFormat;
+use crate::{
+    modules::{ModuleKind, ModuleOptions},
+    output::DisplayOptions,
+};
+
+/// A setting's default value
+enum Value {
+    Bool(bool),
+    Int(u64),
+    Str(String),
+    /// No default; the entry is written commented out with an example value
+    Example(&'static str),
+    /// Like `Example`, for numbers
+    IntExample(u64),
+    /// Like `Example`, for a table of strings
+    MapExample(&'static [(&'static str, &'static str)]),
+}
+
+struct Entry {
+    key: &'static str,
+    comment: &'static str,
+    value: Value,
+}
+
+struct Section {
+    key: &'static str,
+    comment: Option<&'static str>,
+    entries: Vec<Entry>,
+}
+
+/// Render the default configuration in `format`
+///
+/// With `full` set, every per-module option is written out explicitly;
+/// otherwise the `options` section is left empty with a pointer to
+/// `--gen-config-full`.
+pub fn generate_config(format: ConfigFormat, full: bool) -> String {
+    let options = ModuleOptions::default();
+    let general = general_sections(&DisplayOptions::default(), &options);
+    let module_options = if full {
+        option_sections(&options)
+    } else {
+        Vec::new()
+    };
+
+    match format {
+        ConfigFormat::Jsonc => render_jsonc(&general, &module_options),
+        ConfigFormat::Toml => render_toml(&general, &module_options),
+    }
+}
+
+/// Dotted config keys with a one-line description, for documentation
+///
+/// Sections keyed by module id (`labels`, `formats`, ...) are listed once
+/// under their own name.
+pub fn documented_keys() -> Vec<(String, &'static str)> {
+    let options = ModuleOptions::default();
+    let mut keys = Vec::new();
+
+    for section in general_sections(&DisplayOptions::default(), &options) {
+        match section.comment {
+            Some(comment) => keys.push((section.key.to_string(), comment)),
+            None => keys.extend(
+                section
+                    .entries
+                    .iter()
+                    .map(|entry| (format!("{}.{}", section.key, entry.key), entry.comment)),
+            ),
+        }
+    }
+    for section in option_sections(&options) {
+        keys.extend(section.entries.iter().map(|entry| {
+            (
+                format!("options.{}.{}", section.key, entry.key),
+                entry.comment,
+            )
+        }));
+    }
+    keys
+}
+
+fn general_sections(display: &DisplayOptions, options: &ModuleOptions) -> Vec<Section> {
+    vec![
+        Section {
+            key: "logo",
+            comment: None,
+            entries: vec![
+                Entry {
+                    key: "enabled",
+                    comment: "Set to false to hide the logo",
+                    value: Value::Bool(true),
+                },
+                Entry {
+                    key: "name",
+                    comment: "Logo shown instead of the detected distro's, e.g. \"arch\"",
+                    value: Value::Example("arch"),
+                },
+                Entry {
+                    key: "source",
+                    comment: "File with ASCII art (ANSI colors allowed), relative to this config",
+                    value: Value::Example("logo.txt"),
+                },
+                Entry {
+                    key: "ascii",
+                    comment: "Custom ASCII art shown instead of the detected distro logo",
+                    value: Value::Example("  .--.\\n |o_o |"),
+                },
+                Entry {
+                    key: "position",
+                    comment: "\"left\", \"right\" or \"bottom\" of the modules",
+                    value: Value::Example("right"),
+                },
+            ],
+        },
+        Section {
+            key: "display",
+            comment: None,
+            entries: vec![
+                Entry {
+                    key: "values_only",
+                    comment: "Show only values, without labels",
+                    value: Value::Bool(false),
+                },
+                Entry {
+                    key: "hide_errors",
+                    comment: "Leave out modules whose detection failed",
+                    value: Value::Bool(display.hide_errors),
+                },
+                Entry {
+                    key: "hide_unavailable",
+                    comment: "Leave out modules with nothing to show",
+                    value: Value::Bool(display.hide_unavailable),
+                },
+                Entry {
+                    key: "stat",
+                    comment: "Show how long each module took to detect, and the total",
+                    value: Value::Bool(display.stat),
+                },
+                Entry {
+                    key: "colors",
+                    comment: "End with two rows of the terminal's colors",
+                    value: Value::Bool(display.color_blocks),
+                },
+                Entry {
+                    key: "layout",
+                    comment: "\"side\", \"top\" (logo above the modules) or \"grid\" (two columns)",
+                    value: Value::Str(display.layout.to_string()),
+                },
+                Entry {
+                    key: "boxed",
+                    comment: "Draw a box around the modules, with the title in its top border",
+                    value: Value::Bool(display.boxed),
+                },
+                Entry {
+                    key: "separator",
+                    comment: "Text between a label and its value",
+                    value: Value::Str(display.separator.clone()),
+                },
+                Entry {
+                    key: "oneline",
+                    comment: "Show every value on one line, e.g. for a status bar",
+                    value: Value::Bool(display.oneline),
+                },
+                Entry {
+                    key: "delimiter",
+                    comment: "Text between the values on one line",
+                    value: Value::Str(display.delimiter.clone()),
+                },
+                Entry {
+                    key: "key_align",
+                    comment: "\"left\", \"right\" or \"none\" (labels not padded)",
+                    value: Value::Str(display.key_align.to_string()),
+                },
+                Entry {
+                    key: "key_width",
+                    comment: "Pad labels to a fixed width instead of the widest one",
+                    value: Value::IntExample(10),
+                },
+                Entry {
+                    key: "icons",
+                    comment: "\"nerd\" or \"emoji\" to put a glyph in front of each label",
+                    value: Value::Str(display.icons.to_string()),
+                },
+                Entry {
+                    key: "width",
+                    comment: "Wrap values to this many columns (at least 20) instead of the terminal's",
+                    value: Value::IntExample(80),
+                },
+                Entry {
+                    key: "max_length",
+                    comment: "Cut values longer than this many columns short with \"…\"",
+                    value: Value::IntExample(60),
+                },
+                Entry {
+                    key: "bar_width",
+                    comment: "Cells in the percentage bars of memory, swap, battery, brightness",
+                    value: Value::Int(display.bar.width as u64),
+                },
+                Entry {
+                    key: "bar_filled",
+                    comment: "Text of a filled bar cell",
+                    value: Value::Str(display.bar.filled.clone()),
+                },
+                Entry {
+                    key: "bar_empty",
+                    comment: "Text of an empty bar cell",
+                    value: Value::Str(display.bar.empty.clone()),
+                },
+                Entry {
+                    key: "key_color",
+                    comment: "Label color and style, e.g. \"bold blue\" or \"#ff8800\"",
+                    value: Value::Example("bold blue"),
+                },
+                Entry {
+                    key: "value_color",
+                    comment: "Value color and style",
+                    value: Value::Example("white"),
+                },
+            ],
+        },
+        Section {
+            key: "general",
+            comment: None,
+            entries: vec![
+                Entry {
+                    key: "parallel",
+                    comment: "Detect modules in parallel",
+                    value: Value::Bool(true),
+                },
+                Entry {
+                    key: "sample_interval",
+                    comment: "Sampling window in milliseconds for diskio and netio",
+                    value: Value::Int(millis(options.sample_interval)),
+                },
+            ],
+        },
+        Section {
+            key: "aliases",
+            comment: Some("Extra module names, mapped to the module id they stand for"),
+            entries: vec![Entry {
+                key: "gfx",
+                comment: "Usable in the module list and in keys like labels.gfx",
+                value: Value::Example("gpudriver"),
+            }],
+        },
+        Section {
+            key: "labels",
+            comment: Some("Labels shown instead of module names, keyed by module id"),
+            entries: vec![Entry {
+                key: "host",
+                comment: "Any text, e.g. a Nerd Font icon",
+                value: Value::Example("Box"),
+            }],
+        },
+        Section {
+            key: "key_colors",
+            comment: Some("Label styles for single modules, keyed by module id"),
+            entries: vec![Entry {
+                key: "memory",
+                comment: "Same syntax as display.key_color",
+                value: Value::Example("yellow"),
+            }],
+        },
+        Section {
+            key: "value_colors",
+            comment: Some("Value styles for single modules, keyed by module id"),
+            entries: vec![Entry {
+                key: "memory",
+                comment: "Same syntax as display.value_color",
+                value: Value::Example("dim"),
+            }],
+        },
+        Section {
+            key: "formats",
+            comment: Some("Templates replacing a module's output, keyed by module id"),
+            entries: vec![
+                Entry {
+                    key: "kernel",
+                    comment: "Fields: {name}, {version}, {arch}, {flavor}, {build_date}, {compiler}",
+                    value: Value::Example("{name} {version}{?arch} ({arch}){?}"),
+                },
+                Entry {
+                    key: "title",
+                    comment: "Fields: {user}, {host}; {#color}..{#} adds styling",
+                    value: Value::Example("{#bold}{user}{#}@{host}"),
+                },
+            ],
+        },
+        Section {
+            key: "max_lengths",
+            comment: Some("Columns a module's values are cut short at, keyed by module id"),
+            entries: vec![Entry {
+                key: "cpu",
+                comment: "Overrides display.max_length for this module",
+                value: Value::IntExample(40),
+            }],
+        },
+    ]
+}
+
+fn option_sections(options: &ModuleOptions) -> Vec<Section> {
+    let mut sections = vec![
+        Section {
+            key: "cputemp",
+            comment: None,
+            entries: vec![Entry {
+                key: "unit",
+                comment: "\"celsius\" or \"fahrenheit\"",
+                value: Value::Str(options.temperature_unit.to_string()),
+            }],
+        },
+        Section {
+            key: "kernelmodules",
+            comment: None,
+            entries: vec![Entry {
+                key: "show_tainted",
+                comment: "List out-of-tree and other taint-causing modules by name",
+                value: Value::Bool(options.show_tainted_modules),
+            }],
+        },
+        Section {
+            key: "usb",
+            comment: None,
+            entries: vec![Entry {
+                key: "hide_hubs",
+                comment: "Leave hubs and root hubs out of the device list",
+                value: Value::Bool(options.usb_hide_hubs),
+            }],
+        },
+        Section {
+            key: "custom",
+            comment: Some("Add \"custom\" to the module list to show a command's output"),
+            entries: vec![
+                Entry {
+                    key: "command",
+                    comment: "Shell command whose output is shown",
+                    value: Value::Example("uptime -p"),
+                },
+                Entry {
+                    key: "label",
+                    comment: "Label shown instead of \"Custom\"",
+                    value: Value::Example("Up"),
+                },
+                Entry {
+                    key: "timeout",
+                    comment: "Milliseconds the command may run before it is killed",
+                    value: Value::Int(millis(options.custom_timeout)),
+                },
+            ],
+        },
+        Section {
+            key: "text",
+            comment: Some("Add \"text\" to the module list to show a fixed string"),
+            entries: vec![
+                Entry {
+                    key: "text",
+                    comment: "String to show",
+                    value: Value::Example("Hello!"),
+                },
+                Entry {
+                    key: "color",
+                    comment: "A color name like \"cyan\" or a \"#rrggbb\" code",
+                    value: Value::Example("cyan"),
+                },
+            ],
+        },
+    ];
+    sections.extend(
+        [
+            ModuleKind::Memory,
+            ModuleKind::Swap,
+            ModuleKind::Battery,
+            ModuleKind::Brightness,
+        ]
+        .map(|kind| Section {
+            key: kind.id(),
+            comment: None,
+            entries: vec![
+                Entry {
+                    key: "bar",
+                    comment: "\"on\" to show a percentage bar instead, \"prefix\" to add one",
+                    value: Value::Example("on"),
+                },
+                Entry {
+                    key: "thresholds",
+                    comment: "Value styles keyed by the percentage they start at",
+                    value: Value::MapExample(&[("80%", "yellow"), ("90%", "bold red")]),
+                },
+            ],
+        }),
+    );
+    sections
+}
+
+fn millis(duration: std::time::Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// JSON string literal; its escapes are also valid in TOML basic strings
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_default()
+}
+
+impl Value {
+    /// The literal in `format`, and whether the entry must be commented out
+    fn literal(&self, format: ConfigFormat) -> (String, bool) {
+        match self {
+            Self::Bool(b) => (b.to_string(), false),
+            Self::Int(n) => (n.to_string(), false),
+            Self::Str(s) => (quote(s), false),
+            // Example values are already escaped
+            Self::Example(s) => (format!("\"{s}\""), true),
+            Self::IntExample(n) => (n.to_string(), true),
+            Self::MapExample(pairs) => {
+                let assign = match format {
+                    ConfigFormat::Jsonc => ": ",
+                    ConfigFormat::Toml => " = ",
+                };
+                let pairs: Vec<_> = pairs
+                    .iter()
+                    .map(|(key, value)| format!("{}{assign}{}", quote(key), quote(value)))
+                    .collect();
+                (format!("{{ {} }}", pairs.join(", ")), true)
+            }
+        }
+    }
+}
+
+fn opt_in_ids() -> String {
+    ModuleKind::all()
+        .iter()
+        .filter(|kind| kind.is_opt_in())
+        .map(|kind| kind.id())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+const HEADER: &[&str] = &[
+    "fastfetch-rs configuration",
+    "",
+    "Generated by `fastfetch-rs --gen-config`. Every key is optional and",
+    "command line flags override anything set here.",
+];
+
+const INCLUDE_COMMENT: &str = "Configs and presets applied first; settings here override theirs";
+
+const MODULES_COMMENT: &str =
+    "Modules shown, in order. Run `fastfetch-rs --list-modules` for all of them.";
+
+const ENTRIES_COMMENT: &str =
+    "A module may also be given as a table with options of its own, listed as often as needed:";
+
+const OPTIONS_HINT: &str =
+    "Per-module options; run `fastfetch-rs --gen-config-full` to list them all";
+
+fn render_jsonc(general: &[Section], module_options: &[Section]) -> String {
+    let mut out = String::new();
+    for line in HEADER {
+        out.push_str(&format!(
+            "//{}{line}\n",
+            if line.is_empty() { "" } else { " " }
+        ));
+    }
+    out.push_str("// Comments and trailing commas are allowed.\n{\n");
+
+    out.push_str(&format!("    // {INCLUDE_COMMENT}\n"));
+    out.push_str("    // \"include\": [\"preset:minimal\", \"base.jsonc\"],\n\n");
+
+    out.push_str(&format!("    // {MODULES_COMMENT}\n"));
+    out.push_str(&format!("    // Opt-in modules: {}\n", opt_in_ids()));
+    out.push_str(&format!("    // {ENTRIES_COMMENT}\n"));
+    out.push_str(
+        "    //   { \"type\": \"custom\", \"command\": \"uptime -p\", \"label\": \"Up\" }\n",
+    );
+    out.push_str("    \"modules\": [\n");
+    for kind in ModuleKind::defaults() {
+        out.push_str(&format!("        \"{}\",\n", kind.id()));
+    }
+    out.push_str("    ],\n");
+
+    for section in general {
+        out.push('\n');
+        jsonc_section(&mut out, section, 1);
+    }
+
+    out.push('\n');
+    if module_options.is_empty() {
+        out.push_str(&format!("    // {OPTIONS_HINT}\n    \"options\": {{}},\n"));
+    } else {
+        out.push_str("    // Per-module options, keyed by module id\n    \"options\": {\n");
+        for (i, section) in module_options.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            jsonc_section(&mut out, section, 2);
+        }
+        out.push_str("    },\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn jsonc_section(out: &mut String, section: &Section, depth: usize) {
+    let indent = "    ".repeat(depth);
+    if let Some(comment) = section.comment {
+        out.push_str(&format!("{indent}// {comment}\n"));
+    }
+    out.push_str(&format!("{indent}\"{}\": {{\n", section.key));
+    for entry in &section.entries {
+        let (literal, commented) = entry.value.literal(ConfigFormat::Jsonc);
+        let prefix = if commented { "// " } else { "" };
+        out.push_str(&format!("{indent}    // {}\n", entry.comment));
+        out.push_str(&format!(
+            "{indent}    {prefix}\"{}\": {literal},\n",
+            entry.key
+        ));
+    }
+    out.push_str(&format!("{indent}}},\n"));
+}
+
+fn render_toml(general: &[Section], module_options: &[Section]) -> String {
+    let mut out = String::new();
+    for line in HEADER {
+        out.push_str(&format!(
+            "#{}{line}\n",
+            if line.is_empty() { "" } else { " " }
+        ));
+    }
+
+    out.push_str(&format!("\n# {INCLUDE_COMMENT}\n"));
+    out.push_str("# include = [\"preset:minimal\", \"base.toml\"]\n");
+
+    out.push_str(&format!("\n# {MODULES_COMMENT}\n"));
+    out.push_str(&format!("# Opt-in modules: {}\n", opt_in_ids()));
+    out.push_str(&format!("# {ENTRIES_COMMENT}\n"));
+    out.push_str("#   { type = \"custom\", command = \"uptime -p\", label = \"Up\" }\n");
+    out.push_str("modules = [\n");
+    for kind in ModuleKind::defaults() {
+        out.push_str(&format!("    \"{}\",\n", kind.id()));
+    }
+    out.push_str("]\n");
+
+    for section in general {
+        toml_section(&mut out, section, section.key);
+    }
+
+    if module_options.is_empty() {
+        out.push_str(&format!("\n# {OPTIONS_HINT}\n"));
+    } else {
+        out.push_str("\n# Per-module options, keyed by module id\n");
+        for section in module_options {
+            toml_section(&mut out, section, &format!("options.{}", section.key));
+        }
+    }
+    out
+}
+
+fn toml_section(out: &mut String, section: &Section, table: &str) {
+    out.push('\n');
+    if let Some(comment) = section.comment {
+        out.push_str(&format!("# {comment}\n"));
+    }
+    out.push_str(&format!("[{table}]\n"));
+    for entry in &section.entries {
+        let (literal, commented) = entry.value.literal(ConfigFormat::Toml);
+        let prefix = if commented { "# " } else { "" };
+        out.push_str(&format!("# {}\n", entry.comment));
+        out.push_str(&format!("{prefix}{} = {literal}\n", entry.key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConfigBuilder, ConfigFile};
+
+    /// Every generated file must load back into exactly the default configuration
+    #[test]
+    fn test_generated_config_round_trips() {
+        // Naming every field makes a new module option fail to compile until
+        // its key is listed here and written by the full config
+        let ModuleOptions {
+            temperature_unit: _,
+            formats: _,
+            bars: _,
+            thresholds: _,
+            max_lengths: _,
+            sample_interval: _,
+            show_tainted_modules: _,
+            usb_hide_hubs: _,
+            custom_command: _,
+            custom_label: _,
+            custom_timeout: _,
+            text: _,
+            text_color: _,
+        } = ModuleOptions::default();
+        let option_keys = [
+            "unit",
+            "formats",
+            "bar",
+            "thresholds",
+            "max_lengths",
+            "sample_interval",
+            "show_tainted",
+            "hide_hubs",
+            "command",
+            "label",
+            "timeout",
+            "text",
+            "color",
+        ];
+
+        let defaults = format!("{:?}", ConfigBuilder::default().build().config);
+
+        for format in [ConfigFormat::Jsonc, ConfigFormat::Toml] {
+            for full in [false, true] {
+                let text = generate_config(format, full);
+                let file = ConfigFile::parse(&text, format)
+                    .unwrap_or_else(|err| panic!("{format:?} (full: {full}): {err}\n{text}"));
+                let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+                assert_eq!(format!("{config:?}"), defaults, "{format:?} (full: {full})");
+                assert_eq!(
+                    text.contains("\"hide_hubs\"") || text.contains("hide_hubs ="),
+                    full
+                );
+                assert!(text.contains("include = [") || text.contains("\"include\": ["));
+                if full {
+                    for key in option_keys {
+                        assert!(
+                            text.contains(&format!("\"{key}\":"))
+                                || text.contains(&format!("{key} ="))
+                                || text.contains(&format!("[{key}]")),
+                            "{format:?} lacks {key}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
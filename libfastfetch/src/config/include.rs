@@ -0,0 +1,222 @@
+//! Config includes
+//!
+//! A config's `include` key lists other configs to apply before it, so a
+//! user config can extend a bundled preset or a shared base file:
+//!
+//! ```jsonc
+//! {
+//!     "include": ["preset:neofetch", "../shared/colors.jsonc"],
+//!     "modules": ["title", "os", "kernel"],
+//! }
+//! ```
+//!
+//! Includes are applied in order and the including file last, so later
+//! layers override earlier ones. Relative paths are resolved against the
+//! directory of the including file. Presets may include other presets but
+//! not files. A config that includes itself, directly or through other
+//! files, is rejected, as is nesting deeper than [`MAX_INCLUDE_DEPTH`].
+
+use super::{ConfigError, ConfigFile, Preset};
+use crate::context::SystemContext;
+use std::path::{Component, Path, PathBuf};
+
+/// Deepest chain of nested includes accepted
+pub const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Prefix of an include naming a bundled preset, e.g. `preset:minimal`
+pub const PRESET_PREFIX: &str = "preset:";
+
+impl ConfigFile {
+    /// Load every config this one includes, recursively, into its layers
+    ///
+    /// `path` identifies this config; a file's relative includes are
+    /// resolved against its directory. Without a context only presets can
+    /// be included.
+    pub(crate) fn resolve_includes(
+        &mut self,
+        ctx: Option<&dyn SystemContext>,
+        path: &Path,
+    ) -> Result<(), ConfigError> {
+        let mut chain = vec![normalize(path)];
+        self.resolve_nested(ctx, &mut chain)
+    }
+
+    fn resolve_nested(
+        &mut self,
+        ctx: Option<&dyn SystemContext>,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<(), ConfigError> {
+        let dir = chain
+            .last()
+            .and_then(|path| path.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        for include in &self.include {
+            let preset = include
+                .strip_prefix(PRESET_PREFIX)
+                .map(|name| {
+                    Preset::find(name).ok_or_else(|| ConfigError::UnknownPreset {
+                        name: name.to_string(),
+                        available: Preset::names().collect::<Vec<_>>().join(", "),
+                    })
+                })
+                .transpose()?;
+            let path = match preset {
+                Some(preset) => preset.path(),
+                None => normalize(&dir.join(include)),
+            };
+
+            if chain.contains(&path) {
+                let mut names: Vec<_> = chain.iter().map(|p| p.display().to_string()).collect();
+                names.push(path.display().to_string());
+                return Err(ConfigError::IncludeCycle { chain: names });
+            }
+            if chain.len() > MAX_INCLUDE_DEPTH {
+                return Err(ConfigError::IncludeDepth {
+                    include: include.clone(),
+                    max: MAX_INCLUDE_DEPTH,
+                });
+            }
+
+            let mut file = match (preset, ctx) {
+                (Some(preset), _) => preset.read()?,
+                (None, Some(ctx)) => ConfigFile::read(ctx, &path)?,
+                (None, None) => {
+                    return Err(ConfigError::Value {
+                        key: "include".to_string(),
+                        message: format!("presets can only include presets, not '{include}'"),
+                    });
+                }
+            };
+
+            chain.push(path);
+            file.resolve_nested(ctx, chain)?;
+            chain.pop();
+            self.layers.push(file);
+        }
+        Ok(())
+    }
+}
+
+/// Remove `.` and resolve `..` components without touching the filesystem,
+/// so the same file reached through different relative paths compares equal
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match out.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    out.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => out.push(".."),
+            },
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModuleKind, RealSystemContext, config::ConfigBuilder};
+    use std::fs;
+
+    /// A fresh directory under the system temp dir
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "fastfetch-rs-include-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_include_preset() {
+        let mut file = ConfigFile::parse_jsonc(
+            r#"{ "include": "preset:minimal", "display": { "separator": " -> " } }"#,
+        )
+        .unwrap();
+        file.resolve_includes(None, Path::new("config.jsonc"))
+            .unwrap();
+
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        // Modules and logo come from the preset, the separator from the file
+        assert_eq!(config.modules().len(), 5);
+        assert!(config.logo().is_none());
+        assert_eq!(config.display().separator, " -> ");
+    }
+
+    #[test]
+    fn test_include_files_in_order() {
+        let dir = temp_dir("order");
+        fs::create_dir_all(dir.join("shared")).unwrap();
+        fs::write(
+            dir.join("shared/base.jsonc"),
+            r#"{ "modules": ["os", "cpu"], "display": { "separator": " = " } }"#,
+        )
+        .unwrap();
+        fs::write(dir.join("shared/override.toml"), r#"modules = ["kernel"]"#).unwrap();
+        fs::write(
+            dir.join("config.jsonc"),
+            r#"{ "include": ["shared/base.jsonc", "./shared/../shared/override.toml"] }"#,
+        )
+        .unwrap();
+
+        let file = ConfigFile::load(&RealSystemContext, &dir.join("config.jsonc")).unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        let kinds: Vec<_> = config.modules().iter().map(|entry| entry.kind).collect();
+        assert_eq!(kinds, [ModuleKind::Kernel]);
+        assert_eq!(config.display().separator, " = ");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_include_cycle() {
+        let dir = temp_dir("cycle");
+        fs::write(dir.join("a.jsonc"), r#"{ "include": "b.jsonc" }"#).unwrap();
+        fs::write(dir.join("b.jsonc"), r#"{ "include": "./a.jsonc" }"#).unwrap();
+
+        let err = ConfigFile::load(&RealSystemContext, &dir.join("a.jsonc")).unwrap_err();
+        let ConfigError::IncludeCycle { chain } = err else {
+            panic!("expected a cycle, got {err}");
+        };
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain[0], chain[2]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_include_depth() {
+        let dir = temp_dir("depth");
+        for level in 0..=MAX_INCLUDE_DEPTH + 1 {
+            fs::write(
+                dir.join(format!("{level}.jsonc")),
+                format!(r#"{{ "include": "{}.jsonc" }}"#, level + 1),
+            )
+            .unwrap();
+        }
+        fs::write(dir.join(format!("{}.jsonc", MAX_INCLUDE_DEPTH + 2)), "{}").unwrap();
+
+        let err = ConfigFile::load(&RealSystemContext, &dir.join("0.jsonc")).unwrap_err();
+        assert!(matches!(err, ConfigError::IncludeDepth { .. }), "{err}");
+        // Exactly the maximum is fine
+        assert!(ConfigFile::load(&RealSystemContext, &dir.join("2.jsonc")).is_ok());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(Path::new("a/./b/../c")), Path::new("a/c"));
+        assert_eq!(normalize(Path::new("../../a")), Path::new("../../a"));
+        assert_eq!(normalize(Path::new("/../a")), Path::new("/a"));
+    }
+}
@@ -0,0 +1,463 @@
+//! Converting configs of the original C fastfetch
+//!
+//! `--migrate-config` reads an upstream `config.jsonc` and maps what has an
+//! equivalent here: module names, per-module `key`, `keyColor` and
+//! `outputColor`, the `command` and `custom` modules, and the common
+//! `logo`, `display` and `general` settings, including built-in logo names
+//! we have a logo for. Everything else is listed in
+//! [`Migration::unsupported`] so it can be reported instead of silently
+//! dropped. Upstream format strings use a different placeholder syntax and
+//! are not carried over.
+
+use super::{ConfigFile, ConfigFormat, file::pretty_json};
+use crate::{ModuleKind, logo::database, output::Color};
+use serde_json::{Map, Value, json};
+
+/// Upstream module names that differ from ours, or have no counterpart (`None`)
+///
+/// Names not listed here are looked up as our own module ids.
+const MODULE_MAP: &[(&str, Option<&str>)] = &[
+    ("bootmgr", Some("bootloader")),
+    ("command", Some("custom")),
+    ("custom", Some("text")),
+    ("display", Some("monitor")),
+    ("gpu", Some("gpudriver")),
+    ("media", Some("player")),
+    ("physicalmemory", Some("memorydevices")),
+    ("chassis", None),
+    ("colors", None),
+    ("cpuusage", None),
+    ("cursor", None),
+    ("datetime", None),
+    ("de", None),
+    ("disk", None),
+    ("editor", None),
+    ("gamepad", None),
+    ("lm", None),
+    ("localip", None),
+    ("opencl", None),
+    ("opengl", None),
+    ("poweradapter", None),
+    ("publicip", None),
+    ("terminaltheme", None),
+    ("vulkan", None),
+    ("wallpaper", None),
+    ("weather", None),
+    ("wifi", None),
+];
+
+/// Result of converting an upstream config
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    /// Top-level sections of the converted config, in output order with
+    /// the module list first
+    pub sections: Vec<(&'static str, Value)>,
+    /// Upstream keys and modules that couldn't be converted
+    pub unsupported: Vec<String>,
+}
+
+impl Migration {
+    /// Convert the text of an upstream JSONC config
+    pub fn from_upstream(content: &str) -> Result<Self, String> {
+        let upstream = ConfigFile::jsonc_value(content)?;
+        let Value::Object(upstream) = upstream else {
+            return Err("expected a JSON object at the top level".to_string());
+        };
+
+        let mut migration = Self {
+            sections: Vec::new(),
+            unsupported: Vec::new(),
+        };
+        let mut display = Map::new();
+        let mut general = Map::new();
+        let mut logo = Map::new();
+        let mut options = Map::new();
+
+        for (key, value) in &upstream {
+            match key.as_str() {
+                "$schema" => {}
+                "modules" => migration.modules(value),
+                "logo" => migration.logo(value, &mut logo),
+                "display" => migration.display(value, &mut display, &mut options),
+                "general" => {
+                    for (key, value) in value.as_object().into_iter().flatten() {
+                        match key.as_str() {
+                            "multithreading" => {
+                                general.insert("parallel".into(), value.clone());
+                            }
+                            _ => migration.unsupported.push(format!("general.{key}")),
+                        }
+                    }
+                }
+                _ => migration.unsupported.push(key.clone()),
+            }
+        }
+
+        for (name, section) in [
+            ("logo", logo),
+            ("display", display),
+            ("general", general),
+            ("options", options),
+        ] {
+            if !section.is_empty() {
+                migration.sections.push((name, Value::Object(section)));
+            }
+        }
+        Ok(migration)
+    }
+
+    fn modules(&mut self, value: &Value) {
+        let mut modules = Vec::new();
+        for (index, item) in value.as_array().into_iter().flatten().enumerate() {
+            let (name, table) = match item {
+                Value::String(name) => (name.as_str(), None),
+                Value::Object(table) => match table.get("type").and_then(Value::as_str) {
+                    Some(name) => (name, Some(table)),
+                    None => {
+                        self.unsupported
+                            .push(format!("modules[{index}] without a type"));
+                        continue;
+                    }
+                },
+                _ => {
+                    self.unsupported.push(format!("modules[{index}]"));
+                    continue;
+                }
+            };
+
+            let Some(id) = map_module(name) else {
+                self.unsupported.push(format!("module '{name}'"));
+                continue;
+            };
+            let Some(table) = table else {
+                modules.push(Value::String(id.to_string()));
+                continue;
+            };
+
+            let mut entry = Map::new();
+            entry.insert("type".into(), json!(id));
+            for (key, value) in table {
+                let ours = match (key.as_str(), id) {
+                    ("type", _) => continue,
+                    ("key", _) => "label",
+                    ("keyColor", _) => "key_color",
+                    ("outputColor", _) => "value_color",
+                    // The command module's shell command
+                    ("text", "custom") => "command",
+                    // The custom module's fixed text
+                    ("format", "text") => "text",
+                    _ => {
+                        self.unsupported.push(format!("modules[{index}].{key}"));
+                        continue;
+                    }
+                };
+                entry.insert(ours.into(), value.clone());
+            }
+            modules.push(Value::Object(entry));
+        }
+
+        // Ours shows nothing unless given something to run or print
+        for (id, key) in [("custom", "command"), ("text", "text")] {
+            let bare = Value::String(id.to_string());
+            if modules.contains(&bare) {
+                self.unsupported
+                    .push(format!("module '{id}' without a {key}"));
+                modules.retain(|module| module != &bare);
+            }
+        }
+        self.sections.push(("modules", Value::Array(modules)));
+    }
+
+    fn logo(&mut self, value: &Value, logo: &mut Map<String, Value>) {
+        match value {
+            Value::Null => {
+                logo.insert("enabled".into(), json!(false));
+            }
+            // A built-in logo name
+            Value::String(name) => match database::find(name) {
+                Some(known) => {
+                    logo.insert("name".into(), json!(known.name));
+                }
+                None => self.unsupported.push(format!("logo '{name}'")),
+            },
+            Value::Object(table) => {
+                for (key, value) in table {
+                    match (key.as_str(), value.as_str()) {
+                        ("type", Some("none")) => {
+                            logo.insert("enabled".into(), json!(false));
+                        }
+                        ("type", Some("auto" | "builtin" | "data" | "file" | "file-raw")) => {}
+                        ("source", _) if table.get("type") == Some(&json!("data")) => {
+                            logo.insert("ascii".into(), value.clone());
+                        }
+                        ("color", _) => self.logo_colors(value, logo),
+                        ("source", _)
+                            if matches!(
+                                table.get("type").and_then(Value::as_str),
+                                Some("file" | "file-raw")
+                            ) =>
+                        {
+                            logo.insert("source".into(), value.clone());
+                        }
+                        ("source", Some(name)) if database::find(name).is_some() => {
+                            let known = database::find(name).map(|known| known.name);
+                            logo.insert("name".into(), json!(known));
+                        }
+                        _ => self.unsupported.push(format!("logo.{key}")),
+                    }
+                }
+            }
+            _ => self.unsupported.push("logo".to_string()),
+        }
+    }
+
+    /// `logo.color`, which numbers colors like ours
+    fn logo_colors(&mut self, value: &Value, logo: &mut Map<String, Value>) {
+        let mut colors = Map::new();
+        for (index, color) in value.as_object().into_iter().flatten() {
+            match color
+                .as_str()
+                .filter(|color| color.parse::<Color>().is_ok())
+            {
+                Some(color) => {
+                    colors.insert(index.clone(), json!(color));
+                }
+                None => self.unsupported.push(format!("logo.color.{index}")),
+            }
+        }
+        if !colors.is_empty() {
+            logo.insert("colors".into(), Value::Object(colors));
+        }
+    }
+
+    fn display(
+        &mut self,
+        value: &Value,
+        display: &mut Map<String, Value>,
+        options: &mut Map<String, Value>,
+    ) {
+        for (key, value) in value.as_object().into_iter().flatten() {
+            match key.as_str() {
+                "separator" => {
+                    display.insert("separator".into(), value.clone());
+                }
+                "showErrors" => {
+                    let show = value.as_bool().unwrap_or(false);
+                    display.insert("hide_errors".into(), json!(!show));
+                }
+                "key" => {
+                    for (key, value) in value.as_object().into_iter().flatten() {
+                        match key.as_str() {
+                            "width" => {
+                                display.insert("key_width".into(), value.clone());
+                            }
+                            _ => self.unsupported.push(format!("display.key.{key}")),
+                        }
+                    }
+                }
+                "color" => {
+                    // A plain string colors the keys and the title
+                    let colors = match value {
+                        Value::String(_) => json!({ "keys": value }),
+                        _ => value.clone(),
+                    };
+                    for (key, value) in colors.as_object().into_iter().flatten() {
+                        match key.as_str() {
+                            "keys" => {
+                                display.insert("key_color".into(), value.clone());
+                            }
+                            "output" => {
+                                display.insert("value_color".into(), value.clone());
+                            }
+                            _ => self.unsupported.push(format!("display.color.{key}")),
+                        }
+                    }
+                }
+                "temp" => {
+                    let unit = value
+                        .get("unit")
+                        .and_then(Value::as_str)
+                        .map(str::to_ascii_uppercase);
+                    match unit.as_deref() {
+                        Some("C" | "CELSIUS") => {
+                            options.insert("cputemp".into(), json!({ "unit": "celsius" }));
+                        }
+                        Some("F" | "FAHRENHEIT") => {
+                            options.insert("cputemp".into(), json!({ "unit": "fahrenheit" }));
+                        }
+                        _ => self.unsupported.push("display.temp".to_string()),
+                    }
+                }
+                _ => self.unsupported.push(format!("display.{key}")),
+            }
+        }
+    }
+
+    /// The converted config as text in `format`, headed by a note on its origin
+    pub fn render(&self, format: ConfigFormat, source: &str) -> Result<String, String> {
+        let mut header = vec![format!(
+            "Converted from {source} by fastfetch-rs --migrate-config"
+        )];
+        if !self.unsupported.is_empty() {
+            header.push("Not converted:".to_string());
+            header.extend(self.unsupported.iter().map(|item| format!("  {item}")));
+        }
+
+        match format {
+            ConfigFormat::Jsonc => {
+                let mut out: String = header.iter().map(|line| format!("// {line}\n")).collect();
+                out.push_str("{\n");
+                for (name, value) in &self.sections {
+                    let value = pretty_json(value)?.replace('\n', "\n    ");
+                    out.push_str(&format!("    \"{name}\": {value},\n"));
+                }
+                out.push_str("}\n");
+                Ok(out)
+            }
+            ConfigFormat::Toml => {
+                let table: Map<String, Value> = self
+                    .sections
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect();
+                let body = toml::to_string(&table).map_err(|err| err.to_string())?;
+                let mut out: String = header.iter().map(|line| format!("# {line}\n")).collect();
+                out.push('\n');
+                out.push_str(&body);
+                Ok(out)
+            }
+        }
+    }
+}
+
+/// Our module id for an upstream module name
+fn map_module(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+    match MODULE_MAP.iter().find(|(upstream, _)| *upstream == name) {
+        Some((_, ours)) => *ours,
+        None => name.parse::<ModuleKind>().ok().map(ModuleKind::id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+
+    const UPSTREAM: &str = r#"{
+        "$schema": "https://github.com/fastfetch-cli/fastfetch/raw/dev/doc/json_schema.json",
+        "logo": { "type": "none" },
+        "display": {
+            "separator": " -> ",
+            "color": { "keys": "blue", "title": "red" },
+            "key": { "width": 10 },
+            "temp": { "unit": "F" },
+        },
+        "general": { "multithreading": false },
+        "modules": [
+            "title",
+            "separator",
+            "os",
+            { "type": "cpu", "key": "Processor", "keyColor": "yellow", "temp": true },
+            "gpu",
+            "bootmgr",
+            "localip",
+            { "type": "command", "key": "Up", "text": "uptime -p" },
+            { "type": "custom", "format": "Hello!" },
+            "break",
+            "colors",
+        ],
+    }"#;
+
+    #[test]
+    fn test_migrate_upstream() {
+        let migration = Migration::from_upstream(UPSTREAM).unwrap();
+        assert_eq!(
+            migration.unsupported,
+            [
+                "display.color.title",
+                "modules[3].temp",
+                "module 'localip'",
+                "module 'colors'"
+            ]
+        );
+        assert_eq!(
+            migration.sections[0],
+            (
+                "modules",
+                json!([
+                    "title",
+                    "separator",
+                    "os",
+                    { "type": "cpu", "label": "Processor", "key_color": "yellow" },
+                    "gpudriver",
+                    "bootloader",
+                    { "type": "custom", "label": "Up", "command": "uptime -p" },
+                    { "type": "text", "text": "Hello!" },
+                    "break",
+                ])
+            )
+        );
+    }
+
+    #[test]
+    fn test_migrated_config_loads() {
+        let migration = Migration::from_upstream(UPSTREAM).unwrap();
+        for format in [ConfigFormat::Jsonc, ConfigFormat::Toml] {
+            let text = migration.render(format, "config.jsonc").unwrap();
+            let file = ConfigFile::parse(&text, format)
+                .unwrap_or_else(|err| panic!("{format:?}: {err}\n{text}"));
+            let outcome = file.apply(ConfigBuilder::default()).unwrap().build();
+            assert!(outcome.unknown_modules.is_empty());
+
+            let config = outcome.config;
+            assert_eq!(config.modules().len(), 9);
+            assert!(config.logo().is_none());
+            assert!(!config.parallel());
+            assert_eq!(config.display().separator, " -> ");
+            assert_eq!(config.display().key_width, Some(10));
+            assert_eq!(
+                config.module_options().temperature_unit,
+                crate::TemperatureUnit::Fahrenheit
+            );
+        }
+    }
+
+    #[test]
+    fn test_bare_command_module_is_dropped() {
+        let migration = Migration::from_upstream(r#"{ "modules": ["os", "command"] }"#).unwrap();
+        assert_eq!(migration.sections[0], ("modules", json!(["os"])));
+        assert_eq!(migration.unsupported, ["module 'custom' without a command"]);
+    }
+
+    #[test]
+    fn test_migrate_logo() {
+        let logo = |config| {
+            let migration = Migration::from_upstream(config).unwrap();
+            (migration.sections, migration.unsupported)
+        };
+        assert_eq!(
+            logo(r#"{ "logo": "Arch" }"#),
+            (vec![("logo", json!({ "name": "arch" }))], vec![])
+        );
+        assert_eq!(
+            logo(r#"{ "logo": { "type": "builtin", "source": "ubuntu" } }"#),
+            (vec![("logo", json!({ "name": "ubuntu" }))], vec![])
+        );
+        assert_eq!(
+            logo(r#"{ "logo": { "type": "file-raw", "source": "~/logo.txt" } }"#),
+            (vec![("logo", json!({ "source": "~/logo.txt" }))], vec![])
+        );
+        assert_eq!(
+            logo(r#"{ "logo": { "color": { "1": "blue", "2": "38;5;214" } } }"#),
+            (
+                vec![("logo", json!({ "colors": { "1": "blue" } }))],
+                vec!["logo.color.2".to_string()]
+            )
+        );
+        assert_eq!(
+            logo(r#"{ "logo": "nixos" }"#),
+            (vec![], vec!["logo 'nixos'".to_string()])
+        );
+    }
+}
@@ -0,0 +1,660 @@
+//! Configuration and options management for fastfetch-rs
+//!
+//! A minimal vertical slice that mirrors the upstream architecture by
+//! separating configuration from execution. Settings can come from CLI
+//! flags or a JSONC/TOML config file (see [`file`]), both feeding `ConfigBuilder`.
+//! [`generate`] writes the defaults out as a commented config file, and
+//! [`preset`] bundles ready-made configs. [`include`] layers configs on top
+//! of each other. [`condition`] limits entries to some machines, and
+//! [`check`] validates a config for `--check-config`. [`migrate`] converts
+//! configs of the original fastfetch.
+
+pub mod check;
+pub mod condition;
+pub mod file;
+pub mod generate;
+pub mod include;
+pub mod migrate;
+pub mod preset;
+pub mod set;
+
+pub use condition::{Condition, Fact};
+pub use file::{ConfigError, ConfigFile, ConfigFormat};
+pub use generate::{documented_keys, generate_config};
+pub use preset::{PRESETS, Preset};
+
+use crate::context::SystemContext;
+use crate::logo::{LogoPosition, PALETTE_SIZE};
+use crate::modules::{IconSet, ModuleKind, ModuleOptions, ModuleOverrides, TemperatureUnit};
+use crate::output::{
+    BarMode, Color, DisplayOptions, KeyAlign, Layout, OutputFormat, Template, TextStyle, Thresholds,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    time::Duration,
+};
+
+/// Logo configuration placeholder.
+#[derive(Debug, Clone, Default)]
+pub struct LogoConfig {
+    /// Optional ASCII logo to render alongside module output.
+    /// If None, logo will be auto-detected from system.
+    pub ascii_art: Option<String>,
+    /// Logo from the database to show instead of the detected one
+    pub name: Option<String>,
+    /// File to read ASCII art from, which may contain ANSI escapes
+    pub source: Option<PathBuf>,
+    /// Palette overrides by color number, 1 to 9
+    pub colors: BTreeMap<usize, Color>,
+    /// Side of the module lines the logo goes on, or under them
+    pub position: LogoPosition,
+}
+
+/// One module in the display list.
+///
+/// The same kind may appear several times, each entry with its own options,
+/// e.g. two Custom entries running different commands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleEntry {
+    pub kind: ModuleKind,
+    /// Label shown instead of the kind's name, for this entry only.
+    pub label: Option<String>,
+    /// Label style for this entry only.
+    pub key_style: Option<TextStyle>,
+    /// Value style for this entry only.
+    pub value_style: Option<TextStyle>,
+    /// Options for this entry only, over the shared module options.
+    pub overrides: ModuleOverrides,
+    /// The entry is shown only where all of these hold.
+    pub conditions: Vec<Condition>,
+}
+
+impl ModuleEntry {
+    /// An entry using the shared module options.
+    pub fn new(kind: ModuleKind) -> Self {
+        Self::with_overrides(kind, ModuleOverrides::default())
+    }
+
+    /// An entry with options of its own.
+    pub fn with_overrides(kind: ModuleKind, overrides: ModuleOverrides) -> Self {
+        Self {
+            kind,
+            label: None,
+            key_style: None,
+            value_style: None,
+            overrides,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Show `label` instead of the kind's name.
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Draw the label and value in their own styles.
+    pub fn with_styles(mut self, key: Option<TextStyle>, value: Option<TextStyle>) -> Self {
+        self.key_style = key;
+        self.value_style = value;
+        self
+    }
+
+    /// Show the entry only where all `conditions` hold.
+    pub fn with_conditions(mut self, conditions: Vec<Condition>) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    /// Whether the entry's conditions hold on this machine.
+    pub fn is_shown(&self, ctx: &dyn SystemContext) -> bool {
+        self.conditions.iter().all(|condition| condition.holds(ctx))
+    }
+}
+
+impl From<ModuleKind> for ModuleEntry {
+    fn from(kind: ModuleKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+/// Resolved configuration used by the application orchestrator.
+#[derive(Debug, Clone)]
+pub struct Config {
+    modules: Vec<ModuleEntry>,
+    parallel: bool,
+    values_only: bool,
+    output_format: OutputFormat,
+    logo: Option<LogoConfig>,
+    display: DisplayOptions,
+    labels: HashMap<ModuleKind, String>,
+    key_styles: HashMap<ModuleKind, TextStyle>,
+    value_styles: HashMap<ModuleKind, TextStyle>,
+    module_options: ModuleOptions,
+}
+
+impl Config {
+    /// Builder entrypoint with sensible defaults (all modules, parallel on, labels on).
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// Modules to execute, in display order.
+    pub fn modules(&self) -> &[ModuleEntry] {
+        &self.modules
+    }
+
+    /// Whether to execute modules in parallel.
+    pub const fn parallel(&self) -> bool {
+        self.parallel
+    }
+
+    /// Whether to suppress labels and show only values.
+    pub const fn values_only(&self) -> bool {
+        self.values_only
+    }
+
+    /// What the results are written as.
+    pub const fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+
+    /// Optional logo configuration.
+    pub fn logo(&self) -> Option<&LogoConfig> {
+        self.logo.as_ref()
+    }
+
+    /// Layout of "label: value" lines.
+    pub fn display(&self) -> &DisplayOptions {
+        &self.display
+    }
+
+    /// Options shared by every module.
+    pub fn module_options(&self) -> &ModuleOptions {
+        &self.module_options
+    }
+
+    /// Label for one entry, if it shouldn't use the kind's name: its own
+    /// label, else one set for its kind.
+    pub fn label_for(&self, entry: &ModuleEntry) -> Option<String> {
+        entry
+            .label
+            .clone()
+            .or_else(|| self.labels.get(&entry.kind).cloned())
+            .or_else(|| match entry.kind {
+                ModuleKind::Custom => self.module_options.custom_label.clone(),
+                _ => None,
+            })
+    }
+
+    /// Label style for one entry, if it has one apart from the shared key style.
+    pub fn key_style_for(&self, entry: &ModuleEntry) -> Option<TextStyle> {
+        entry
+            .key_style
+            .as_ref()
+            .or_else(|| self.key_styles.get(&entry.kind))
+            .cloned()
+    }
+
+    /// Value style for one entry, if it has one apart from the shared value style.
+    pub fn value_style_for(&self, entry: &ModuleEntry) -> Option<TextStyle> {
+        entry
+            .value_style
+            .as_ref()
+            .or_else(|| self.value_styles.get(&entry.kind))
+            .cloned()
+    }
+
+    /// Options for one entry: the shared options with its overrides applied.
+    pub fn options_for(&self, entry: &ModuleEntry) -> ModuleOptions {
+        self.module_options
+            .with_overrides(entry.kind, &entry.overrides)
+    }
+}
+
+/// Result of building configuration, including any unknown modules that were skipped.
+#[derive(Debug, Clone)]
+pub struct BuildOutcome {
+    pub config: Config,
+    pub unknown_modules: Vec<String>,
+}
+
+/// Builder for `Config` that can be fed by CLI flags or future file-based settings.
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder {
+    modules: Vec<ModuleEntry>,
+    explicit_modules: bool,
+    parallel: bool,
+    values_only: bool,
+    output_format: OutputFormat,
+    logo: Option<LogoConfig>,
+    display: DisplayOptions,
+    labels: HashMap<ModuleKind, String>,
+    key_styles: HashMap<ModuleKind, TextStyle>,
+    value_styles: HashMap<ModuleKind, TextStyle>,
+    module_options: ModuleOptions,
+    aliases: HashMap<String, ModuleKind>,
+    unknown_modules: Vec<String>,
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self {
+            modules: ModuleKind::defaults()
+                .into_iter()
+                .map(ModuleEntry::new)
+                .collect(),
+            explicit_modules: false,
+            parallel: true,
+            values_only: false,
+            output_format: OutputFormat::default(),
+            logo: Some(LogoConfig::default()), // Auto-detect
+            display: DisplayOptions::default(),
+            labels: HashMap::new(),
+            key_styles: HashMap::new(),
+            value_styles: HashMap::new(),
+            module_options: ModuleOptions::default(),
+            aliases: HashMap::new(),
+            unknown_modules: Vec::new(),
+        }
+    }
+}
+
+impl ConfigBuilder {
+    /// Replace module list with an explicit ordered set.
+    pub fn with_modules(self, modules: Vec<ModuleKind>) -> Self {
+        self.with_module_entries(modules.into_iter().map(Ok))
+    }
+
+    /// Parse module names, retaining valid ones and tracking unknown entries.
+    pub fn with_module_names<I, S>(self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let entries: Vec<_> = names
+            .into_iter()
+            .map(|name| {
+                let name = name.into();
+                self.parse_module(&name).map_err(|_| name)
+            })
+            .collect();
+        self.with_module_entries(entries)
+    }
+
+    /// Accept `alias` wherever a module name is expected, e.g. "gfx" for GPU Driver.
+    ///
+    /// An alias may not be a module id or built-in alias, which it would hide.
+    pub fn module_alias(mut self, alias: &str, kind: ModuleKind) -> Result<Self, String> {
+        if let Ok(builtin) = alias.parse::<ModuleKind>() {
+            return Err(format!(
+                "Alias {alias} is already a name for the {} module",
+                builtin.id()
+            ));
+        }
+        self.aliases.insert(alias.to_lowercase(), kind);
+        Ok(self)
+    }
+
+    /// Parse a module id, built-in alias or one added with [`module_alias`](Self::module_alias).
+    pub fn parse_module(&self, name: &str) -> Result<ModuleKind, String> {
+        match self.aliases.get(&name.to_lowercase()) {
+            Some(kind) => Ok(*kind),
+            None => name.parse(),
+        }
+    }
+
+    /// Replace the module list, in order; `Err` items name unknown modules to skip.
+    pub fn with_module_entries<I, E>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = Result<E, String>>,
+        E: Into<ModuleEntry>,
+    {
+        let mut parsed = Vec::new();
+        let mut unknown = Vec::new();
+        for entry in entries {
+            match entry {
+                Ok(entry) => parsed.push(entry.into()),
+                Err(name) => unknown.push(name),
+            }
+        }
+
+        self.modules = parsed;
+        self.explicit_modules = true;
+        // A later list (e.g. from the CLI) replaces an earlier one entirely
+        self.unknown_modules = unknown;
+
+        self
+    }
+
+    /// Enable or disable parallel execution.
+    pub const fn parallel(mut self, enabled: bool) -> Self {
+        self.parallel = enabled;
+        self
+    }
+
+    /// Toggle values-only output.
+    pub const fn values_only(mut self, enabled: bool) -> Self {
+        self.values_only = enabled;
+        self
+    }
+
+    /// Write the results as `format` instead of text lines.
+    pub const fn output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    /// Leave modules whose detection failed out of the output.
+    pub const fn hide_errors(mut self, enabled: bool) -> Self {
+        self.display.hide_errors = enabled;
+        self
+    }
+
+    /// Leave modules with nothing to show out of the output.
+    pub const fn hide_unavailable(mut self, enabled: bool) -> Self {
+        self.display.hide_unavailable = enabled;
+        self
+    }
+
+    /// Show each module's detection time and the total.
+    pub const fn stat(mut self, enabled: bool) -> Self {
+        self.display.stat = enabled;
+        self
+    }
+
+    /// Set the text between a label and its value, e.g. `" -> "`.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.display.separator = separator.into();
+        self
+    }
+
+    /// Cut value lines longer than `columns` short with `…`; 0 leaves them whole.
+    pub const fn max_length(mut self, columns: usize) -> Self {
+        self.display.max_length = match columns {
+            0 => None,
+            columns => Some(columns),
+        };
+        self
+    }
+
+    /// Show every value on one line, without labels or logo.
+    pub const fn oneline(mut self, enabled: bool) -> Self {
+        self.display.oneline = enabled;
+        self
+    }
+
+    /// Set the text between values on one line, e.g. `" · "`.
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.display.delimiter = delimiter.into();
+        self
+    }
+
+    /// Choose how labels are padded.
+    pub const fn key_align(mut self, align: KeyAlign) -> Self {
+        self.display.key_align = align;
+        self
+    }
+
+    /// End the output with two rows of swatches of the terminal's colors.
+    pub const fn color_blocks(mut self, enabled: bool) -> Self {
+        self.display.color_blocks = enabled;
+        self
+    }
+
+    /// Choose where the logo goes and how the modules are arranged.
+    pub const fn layout(mut self, layout: Layout) -> Self {
+        self.display.layout = layout;
+        self
+    }
+
+    /// Draw a box around the module lines, with the title in its top border.
+    pub const fn boxed(mut self, enabled: bool) -> Self {
+        self.display.boxed = enabled;
+        self
+    }
+
+    /// Put a Nerd Font or emoji glyph in front of each label.
+    pub const fn icons(mut self, icons: IconSet) -> Self {
+        self.display.icons = icons;
+        self
+    }
+
+    /// Pad labels to a fixed width instead of the widest label.
+    pub const fn key_width(mut self, width: usize) -> Self {
+        self.display.key_width = Some(width);
+        self
+    }
+
+    /// Wrap values so no line is wider than `columns`; 0 leaves lines as they are.
+    pub const fn width(mut self, columns: usize) -> Self {
+        self.display.width = match columns {
+            0 => None,
+            columns => Some(columns),
+        };
+        self
+    }
+
+    /// Draw every label in `style`, e.g. `"bold blue"`.
+    pub fn key_style(mut self, style: TextStyle) -> Self {
+        self.display.key_style = style;
+        self
+    }
+
+    /// Draw every value in `style`.
+    pub fn value_style(mut self, style: TextStyle) -> Self {
+        self.display.value_style = style;
+        self
+    }
+
+    /// Draw the labels of every `kind` module in `style`.
+    pub fn module_key_style(mut self, kind: ModuleKind, style: TextStyle) -> Self {
+        self.key_styles.insert(kind, style);
+        self
+    }
+
+    /// Draw the values of every `kind` module in `style`.
+    pub fn module_value_style(mut self, kind: ModuleKind, style: TextStyle) -> Self {
+        self.value_styles.insert(kind, style);
+        self
+    }
+
+    /// Set the unit used for temperature readings.
+    pub const fn temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.module_options.temperature_unit = unit;
+        self
+    }
+
+    /// Set the sampling window used by rate-based modules.
+    pub const fn sample_interval(mut self, interval: Duration) -> Self {
+        self.module_options.sample_interval = interval;
+        self
+    }
+
+    /// List taint-causing modules in the Kernel Modules output.
+    pub const fn show_tainted_modules(mut self, enabled: bool) -> Self {
+        self.module_options.show_tainted_modules = enabled;
+        self
+    }
+
+    /// Show `label` instead of the name of every `kind` module, e.g. "Box" for Host.
+    pub fn module_label(mut self, kind: ModuleKind, label: impl Into<String>) -> Self {
+        self.labels.insert(kind, label.into());
+        self
+    }
+
+    /// Draw a capacity-style module's percentage as a bar, as `mode` asks.
+    pub fn module_bar(mut self, kind: ModuleKind, mode: BarMode) -> Self {
+        self.module_options.bars.insert(kind, mode);
+        self
+    }
+
+    /// Draw a capacity-style module's value in the style of the band its
+    /// percentage falls in.
+    pub fn module_thresholds(mut self, kind: ModuleKind, thresholds: Thresholds) -> Self {
+        self.module_options.thresholds.insert(kind, thresholds);
+        self
+    }
+
+    /// Cut a module's value lines short with `…` at `columns`, over the
+    /// shared limit.
+    pub fn module_max_length(mut self, kind: ModuleKind, columns: usize) -> Self {
+        self.module_options.max_lengths.insert(kind, columns);
+        self
+    }
+
+    /// Set the number of cells in percentage bars.
+    pub const fn bar_width(mut self, width: usize) -> Self {
+        self.display.bar.width = width;
+        self
+    }
+
+    /// Draw the filled cells of percentage bars as `text`, e.g. `"█"`.
+    pub fn bar_filled(mut self, text: impl Into<String>) -> Self {
+        self.display.bar.filled = text.into();
+        self
+    }
+
+    /// Draw the empty cells of percentage bars as `text`, e.g. `"─"`.
+    pub fn bar_empty(mut self, text: impl Into<String>) -> Self {
+        self.display.bar.empty = text.into();
+        self
+    }
+
+    /// Render a module through a template instead of its default output.
+    pub fn module_format(mut self, kind: ModuleKind, template: Template) -> Self {
+        self.module_options.formats.insert(kind, template);
+        self
+    }
+
+    /// Hide hubs and root hubs from the USB device list.
+    pub const fn usb_hide_hubs(mut self, enabled: bool) -> Self {
+        self.module_options.usb_hide_hubs = enabled;
+        self
+    }
+
+    /// Set the shell command run by the Custom module, shown under `label`.
+    pub fn custom_command(mut self, command: impl Into<String>, label: Option<String>) -> Self {
+        self.module_options.custom_command = Some(command.into());
+        self.module_options.custom_label = label;
+        self
+    }
+
+    /// Set the string shown by the Text module, optionally in a color.
+    pub fn text(mut self, text: impl Into<String>, color: Option<Color>) -> Self {
+        self.module_options.text = Some(text.into());
+        self.module_options.text_color = color;
+        self
+    }
+
+    /// Limit how long the Custom module's command may run.
+    pub const fn custom_timeout(mut self, timeout: Duration) -> Self {
+        self.module_options.custom_timeout = timeout;
+        self
+    }
+
+    /// Attach a simple ASCII logo to render.
+    pub fn with_logo_ascii<T: Into<String>>(self, logo: T) -> Self {
+        self.replace_logo(LogoConfig {
+            ascii_art: Some(logo.into()),
+            ..LogoConfig::default()
+        })
+    }
+
+    /// Show ASCII art read from a file when the output is rendered.
+    pub fn with_logo_source<P: Into<PathBuf>>(self, path: P) -> Self {
+        self.replace_logo(LogoConfig {
+            source: Some(path.into()),
+            ..LogoConfig::default()
+        })
+    }
+
+    /// Show a logo from the database, whatever the detected distribution.
+    ///
+    /// Fails with the known names if `name` matches no logo.
+    pub fn with_logo_name(self, name: &str) -> Result<Self, String> {
+        let logo = crate::logo::database::find(name).ok_or_else(|| {
+            let names: Vec<_> = crate::logo::database::LOGOS
+                .iter()
+                .map(|logo| logo.name)
+                .collect();
+            format!("Unknown logo '{name}' (known: {})", names.join(", "))
+        })?;
+        Ok(self.replace_logo(LogoConfig {
+            name: Some(logo.name.to_string()),
+            ..LogoConfig::default()
+        }))
+    }
+
+    /// Draw the logo's color `index` (1 to 9) in `color` instead.
+    pub fn logo_color(mut self, index: usize, color: Color) -> Result<Self, String> {
+        if !(1..=PALETTE_SIZE).contains(&index) {
+            return Err(format!(
+                "Logo color {index} is out of range (1 to {PALETTE_SIZE})"
+            ));
+        }
+        if let Some(logo) = &mut self.logo {
+            logo.colors.insert(index, color);
+        }
+        Ok(self)
+    }
+
+    /// Put the logo on the right of the module lines or under them.
+    pub fn logo_position(mut self, position: LogoPosition) -> Self {
+        if let Some(logo) = &mut self.logo {
+            logo.position = position;
+        }
+        self
+    }
+
+    /// Use another logo, keeping the colors and position set so far
+    fn replace_logo(mut self, mut logo: LogoConfig) -> Self {
+        if let Some(previous) = self.logo.take() {
+            logo.colors = previous.colors;
+            logo.position = previous.position;
+        }
+        self.logo = Some(logo);
+        self
+    }
+
+    /// Show the detected distro's logo, dropping a custom or named one.
+    pub fn with_detected_logo(self) -> Self {
+        self.replace_logo(LogoConfig::default())
+    }
+
+    /// Disable logo rendering entirely.
+    pub fn without_logo(mut self) -> Self {
+        self.logo = None;
+        self
+    }
+
+    /// Finalize the configuration and surface any unknown module names.
+    pub fn build(mut self) -> BuildOutcome {
+        // Configured user-defined modules are shown even without an explicit module list
+        if !self.explicit_modules {
+            if self.module_options.custom_command.is_some() {
+                self.modules.push(ModuleEntry::new(ModuleKind::Custom));
+            }
+            if self.module_options.text.is_some() {
+                self.modules.push(ModuleEntry::new(ModuleKind::Text));
+            }
+        }
+
+        BuildOutcome {
+            config: Config {
+                modules: self.modules,
+                parallel: self.parallel,
+                values_only: self.values_only,
+                output_format: self.output_format,
+                logo: self.logo,
+                display: self.display,
+                labels: self.labels,
+                key_styles: self.key_styles,
+                value_styles: self.value_styles,
+                module_options: self.module_options,
+            },
+            unknown_modules: self.unknown_modules,
+        }
+    }
+}
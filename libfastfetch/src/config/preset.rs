@@ -0,0 +1,134 @@
+//! Bundled configuration presets
+//!
+//! Presets are ordinary JSONC configs compiled into the binary, so
+//! `--preset minimal` goes through the same parsing and
+//! [`ConfigFile::apply`] as a file on disk.
+
+use super::{ConfigError, ConfigFile};
+use std::path::PathBuf;
+
+/// A named config shipped with fastfetch-rs
+#[derive(Debug, Clone, Copy)]
+pub struct Preset {
+    pub name: &'static str,
+    /// One-line summary shown in `--help`
+    pub description: &'static str,
+    /// JSONC source
+    pub source: &'static str,
+}
+
+/// Every bundled preset
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        name: "neofetch",
+        description: "The modules and order of neofetch's default output",
+        source: include_str!("presets/neofetch.jsonc"),
+    },
+    Preset {
+        name: "minimal",
+        description: "A few essentials without the logo",
+        source: include_str!("presets/minimal.jsonc"),
+    },
+    Preset {
+        name: "all",
+        description: "Every module, including opt-in ones",
+        source: include_str!("presets/all.jsonc"),
+    },
+    Preset {
+        name: "hardware",
+        description: "Machine, firmware and device details",
+        source: include_str!("presets/hardware.jsonc"),
+    },
+    Preset {
+        name: "ci",
+        description: "Build environment details for CI logs, without the logo",
+        source: include_str!("presets/ci.jsonc"),
+    },
+];
+
+impl Preset {
+    /// Look up a preset by name
+    pub fn find(name: &str) -> Option<&'static Self> {
+        PRESETS
+            .iter()
+            .find(|preset| preset.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Names of all presets
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        PRESETS.iter().map(|preset| preset.name)
+    }
+
+    /// Parse the preset into a config, along with the presets it includes
+    pub fn load(&self) -> Result<ConfigFile, ConfigError> {
+        let mut file = self.read()?;
+        file.resolve_includes(None, &self.path())?;
+        Ok(file)
+    }
+
+    /// Parse the preset alone, leaving its includes unresolved
+    pub(crate) fn read(&self) -> Result<ConfigFile, ConfigError> {
+        ConfigFile::parse_jsonc(self.source).map_err(|message| ConfigError::Parse {
+            path: self.path(),
+            message,
+        })
+    }
+
+    /// Pseudo-path naming the preset in errors and include chains
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(format!("{}{}", super::include::PRESET_PREFIX, self.name))
+    }
+}
+
+impl ConfigFile {
+    /// Load a bundled preset by name
+    pub fn load_preset(name: &str) -> Result<Self, ConfigError> {
+        match Preset::find(name) {
+            Some(preset) => preset.load(),
+            None => Err(ConfigError::UnknownPreset {
+                name: name.to_string(),
+                available: Preset::names().collect::<Vec<_>>().join(", "),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModuleKind, config::ConfigBuilder};
+
+    #[test]
+    fn test_presets_load() {
+        for preset in PRESETS {
+            let file = preset
+                .load()
+                .unwrap_or_else(|err| panic!("{}: {err}", preset.name));
+            let outcome = file.apply(ConfigBuilder::default()).unwrap().build();
+            assert!(outcome.unknown_modules.is_empty(), "{}", preset.name);
+            assert!(!outcome.config.modules().is_empty(), "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn test_all_preset_is_complete() {
+        let file = ConfigFile::load_preset("ALL").unwrap();
+        let config = file.apply(ConfigBuilder::default()).unwrap().build().config;
+        let kinds: Vec<_> = config.modules().iter().map(|entry| entry.kind).collect();
+        let expected: Vec<_> = ModuleKind::all()
+            .iter()
+            .copied()
+            .filter(|kind| !matches!(kind, ModuleKind::Custom | ModuleKind::Text))
+            .collect();
+        assert_eq!(kinds, expected);
+    }
+
+    #[test]
+    fn test_unknown_preset() {
+        let err = ConfigFile::load_preset("fancy").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown preset 'fancy'. Available: neofetch, minimal, all, hardware, ci"
+        );
+    }
+}
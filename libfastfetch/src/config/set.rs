@@ -0,0 +1,123 @@
+//! Module options set on the command line
+//!
+//! `--set MODULE.OPTION=VALUE` overrides one key of the config's
+//! `"options"` section, so `--set cputemp.unit=fahrenheit` is the same as
+//!
+//! ```jsonc
+//! "options": { "cputemp": { "unit": "fahrenheit" } }
+//! ```
+//!
+//! Values are taken as JSON when the option accepts that, like `true` or
+//! `500`, and as plain strings otherwise, so they rarely need quoting.
+//! Module names may be aliases; the option names are those of the config.
+
+use super::{ConfigError, ConfigFile, file::OptionsSection};
+use crate::ModuleKind;
+use serde_json::{Map, Value, json};
+
+impl ConfigFile {
+    /// A config holding only the given `MODULE.OPTION=VALUE` settings, to be
+    /// applied after the config file; later settings of an option win
+    pub fn from_settings<S: AsRef<str>>(settings: &[S]) -> Result<Self, ConfigError> {
+        let mut options = Map::new();
+        for setting in settings {
+            let (module, option, value) = split_setting(setting.as_ref())?;
+            if let Value::Object(module) = options.entry(module).or_insert_with(|| json!({})) {
+                module.insert(option.to_string(), value);
+            }
+        }
+
+        let options = serde_json::from_value(Value::Object(options))
+            .expect("each setting was checked against the options");
+        Ok(Self {
+            options,
+            ..Self::default()
+        })
+    }
+}
+
+/// Split `cputemp.unit=fahrenheit` into the module id, option and value
+fn split_setting(setting: &str) -> Result<(String, &str, Value), ConfigError> {
+    let invalid = |key: &str, message: String| ConfigError::Value {
+        key: key.to_string(),
+        message,
+    };
+    let Some((key, value)) = setting.split_once('=') else {
+        return Err(invalid(
+            setting,
+            "expected MODULE.OPTION=VALUE, e.g. cputemp.unit=fahrenheit".to_string(),
+        ));
+    };
+    let key = key.trim();
+    let Some((module, option)) = key.split_once('.') else {
+        return Err(invalid(key, "expected MODULE.OPTION".to_string()));
+    };
+
+    let id = module
+        .trim()
+        .parse::<ModuleKind>()
+        .map_err(|message| invalid(key, message))?
+        .id();
+    let option = option.trim();
+    let value = typed_value(id, option, value).map_err(|message| invalid(key, message))?;
+    Ok((id.to_string(), option, value))
+}
+
+/// `value` as JSON if the option accepts it that way, else as a string
+fn typed_value(module: &str, option: &str, value: &str) -> Result<Value, String> {
+    let accepts = |value: &Value| {
+        serde_json::from_value::<OptionsSection>(json!({ module: { option: value } }))
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    };
+    if let Ok(typed) = serde_json::from_str::<Value>(value)
+        && accepts(&typed).is_ok()
+    {
+        return Ok(typed);
+    }
+    let text = Value::String(value.to_string());
+    accepts(&text).map(|()| text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TemperatureUnit, config::ConfigBuilder};
+    use std::time::Duration;
+
+    #[test]
+    fn test_settings_override_options() {
+        let file = ConfigFile::parse_jsonc(
+            r#"{ "options": { "cputemp": { "unit": "celsius" }, "custom": { "timeout": 100 } } }"#,
+        )
+        .unwrap();
+        let settings = ConfigFile::from_settings(&[
+            "cpu_temp.unit=fahrenheit",
+            "kernelmodules.show_tainted=true",
+            "text.text=42",
+        ])
+        .unwrap();
+
+        let builder = file.apply(ConfigBuilder::default()).unwrap();
+        let config = settings.apply(builder).unwrap().build().config;
+        let options = config.module_options();
+        assert_eq!(options.temperature_unit, TemperatureUnit::Fahrenheit);
+        assert!(options.show_tainted_modules);
+        assert_eq!(options.custom_timeout, Duration::from_millis(100));
+        assert_eq!(options.text.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let error = |setting: &str| {
+            ConfigFile::from_settings(&[setting])
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("cputemp.unit").contains("expected MODULE.OPTION=VALUE"));
+        assert!(error("cputemp=celsius").contains("expected MODULE.OPTION"));
+        assert!(error("cpux.unit=celsius").starts_with("Invalid value for 'cpux.unit'"));
+        assert!(error("cputemp.colour=red").contains("unknown field `colour`"));
+        assert!(error("usb.hide_hubs=maybe").contains("expected a boolean"));
+    }
+}
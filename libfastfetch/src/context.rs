@@ -5,6 +5,10 @@
 
 use std::io;
 use std::path::Path;
+use std::time::Duration;
+
+#[cfg(target_os = "linux")]
+use crate::platform::linux::dbus::{DbusBus, DbusValue};
 
 /// Trait abstracting system operations for testability
 ///
@@ -14,12 +18,43 @@ pub trait SystemContext: Send + Sync {
     /// Read a file to string
     fn read_file(&self, path: &Path) -> io::Result<String>;
 
+    /// Read a file as raw bytes
+    fn read_bytes(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// List the entry names of a directory
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<String>>;
+
     /// Execute a command and return stdout
     fn execute_command(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput>;
 
+    /// Execute a command, killing it if it runs longer than `timeout`
+    ///
+    /// Fails with `io::ErrorKind::TimedOut` when the deadline passes.
+    fn execute_command_with_timeout(
+        &self,
+        program: &str,
+        args: &[&str],
+        timeout: Duration,
+    ) -> io::Result<CommandOutput> {
+        let _ = timeout;
+        self.execute_command(program, args)
+    }
+
     /// Get an environment variable
     fn get_env(&self, key: &str) -> Option<String>;
 
+    /// Call a D-Bus method with string arguments and return the reply (Linux-specific)
+    #[cfg(target_os = "linux")]
+    fn dbus_call(
+        &self,
+        bus: DbusBus,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[&str],
+    ) -> io::Result<DbusValue>;
+
     /// Get hostname (Unix-specific)
     #[cfg(unix)]
     fn get_hostname(&self) -> io::Result<String>;
@@ -57,6 +92,16 @@ impl SystemContext for RealSystemContext {
         std::fs::read_to_string(path)
     }
 
+    fn read_bytes(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<String>> {
+        std::fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.file_name().to_string_lossy().into_owned()))
+            .collect()
+    }
+
     fn execute_command(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
         use std::process::Command;
 
@@ -69,10 +114,126 @@ impl SystemContext for RealSystemContext {
         })
     }
 
+    fn execute_command_with_timeout(
+        &self,
+        program: &str,
+        args: &[&str],
+        timeout: Duration,
+    ) -> io::Result<CommandOutput> {
+        use std::io::Read;
+        use std::process::{Command, Stdio};
+        use std::sync::mpsc::{self, RecvTimeoutError};
+        use std::time::Instant;
+
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain the pipes on threads so a chatty child cannot block on a full
+        // pipe. The output comes back over a channel: a process the child left
+        // in the background can hold the pipes open long after it exits.
+        let drain = |pipe: Option<Box<dyn Read + Send>>| {
+            let (sender, receiver) = mpsc::channel();
+            std::thread::spawn(move || {
+                let mut buf = Vec::new();
+                if let Some(mut pipe) = pipe {
+                    let _ = pipe.read_to_end(&mut buf);
+                }
+                let _ = sender.send(buf);
+            });
+            receiver
+        };
+        let stdout = drain(
+            child
+                .stdout
+                .take()
+                .map(|p| Box::new(p) as Box<dyn Read + Send>),
+        );
+        let stderr = drain(
+            child
+                .stderr
+                .take()
+                .map(|p| Box::new(p) as Box<dyn Read + Send>),
+        );
+
+        let deadline = Instant::now() + timeout;
+        let timed_out = || {
+            io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("{program} timed out after {}ms", timeout.as_millis()),
+            )
+        };
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(timed_out());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+
+        // Threads still reading at the deadline are left to finish on their own
+        let collect = |output: mpsc::Receiver<Vec<u8>>| match output
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        {
+            Ok(buf) => Ok(buf),
+            Err(RecvTimeoutError::Disconnected) => Ok(Vec::new()),
+            Err(RecvTimeoutError::Timeout) => Err(timed_out()),
+        };
+        Ok(CommandOutput {
+            stdout: collect(stdout)?,
+            stderr: collect(stderr)?,
+            success: status.success(),
+        })
+    }
+
     fn get_env(&self, key: &str) -> Option<String> {
         std::env::var(key).ok()
     }
 
+    #[cfg(target_os = "linux")]
+    fn dbus_call(
+        &self,
+        bus: DbusBus,
+        destination: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[&str],
+    ) -> io::Result<DbusValue> {
+        use crate::platform::linux::dbus::parse_busctl_output;
+
+        let signature = "s".repeat(args.len());
+        let mut command = vec![
+            bus.flag(),
+            "--timeout=2",
+            "call",
+            destination,
+            path,
+            interface,
+            method,
+        ];
+        if !args.is_empty() {
+            command.push(&signature);
+            command.extend_from_slice(args);
+        }
+
+        let output = self.execute_command("busctl", &command)?;
+        if !output.success {
+            return Err(io::Error::other(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        parse_busctl_output(&String::from_utf8_lossy(&output.stdout))
+    }
+
     #[cfg(unix)]
     fn get_hostname(&self) -> io::Result<String> {
         use std::ffi::CStr;
@@ -130,8 +291,13 @@ mod tests {
     #[derive(Debug, Clone, Default)]
     pub struct MockSystemContext {
         pub files: std::collections::HashMap<String, String>,
+        pub binary_files: std::collections::HashMap<String, Vec<u8>>,
+        pub dirs: std::collections::HashMap<String, Vec<String>>,
         pub commands: std::collections::HashMap<String, CommandOutput>,
         pub env_vars: std::collections::HashMap<String, String>,
+        /// D-Bus replies keyed by "destination path interface.method"
+        #[cfg(target_os = "linux")]
+        pub dbus: std::collections::HashMap<String, DbusValue>,
         #[cfg(unix)]
         pub hostname: Option<String>,
         #[cfg(unix)]
@@ -146,6 +312,20 @@ mod tests {
                 .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))
         }
 
+        fn read_bytes(&self, path: &Path) -> io::Result<Vec<u8>> {
+            self.binary_files
+                .get(path.to_str().unwrap())
+                .cloned()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))
+        }
+
+        fn read_dir(&self, path: &Path) -> io::Result<Vec<String>> {
+            self.dirs
+                .get(path.to_str().unwrap())
+                .cloned()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Directory not found"))
+        }
+
         fn execute_command(&self, program: &str, _args: &[&str]) -> io::Result<CommandOutput> {
             self.commands
                 .get(program)
@@ -157,6 +337,22 @@ mod tests {
             self.env_vars.get(key).cloned()
         }
 
+        #[cfg(target_os = "linux")]
+        fn dbus_call(
+            &self,
+            _bus: DbusBus,
+            destination: &str,
+            path: &str,
+            interface: &str,
+            method: &str,
+            _args: &[&str],
+        ) -> io::Result<DbusValue> {
+            self.dbus
+                .get(&format!("{destination} {path} {interface}.{method}"))
+                .cloned()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No D-Bus reply"))
+        }
+
         #[cfg(unix)]
         fn get_hostname(&self) -> io::Result<String> {
             self.hostname
@@ -185,6 +381,18 @@ mod tests {
         assert_eq!(result.unwrap(), "test content");
     }
 
+    #[test]
+    fn test_mock_context_read_dir() {
+        let mut ctx = MockSystemContext::default();
+        ctx.dirs.insert(
+            "/proc".to_string(),
+            vec!["1".to_string(), "self".to_string()],
+        );
+
+        assert_eq!(ctx.read_dir(Path::new("/proc")).unwrap(), vec!["1", "self"]);
+        assert!(ctx.read_dir(Path::new("/missing")).is_err());
+    }
+
     #[test]
     fn test_mock_context_env() {
         let mut ctx = MockSystemContext::default();
@@ -193,4 +401,30 @@ mod tests {
         assert_eq!(ctx.get_env("TEST_VAR"), Some("test_value".to_string()));
         assert_eq!(ctx.get_env("MISSING"), None);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_execute_command_with_timeout() {
+        let ctx = RealSystemContext;
+        let timeout = Duration::from_secs(5);
+
+        let output = ctx
+            .execute_command_with_timeout("sh", &["-c", "echo hello"], timeout)
+            .unwrap();
+        assert!(output.success);
+        assert_eq!(output.stdout, b"hello\n");
+
+        let err = ctx
+            .execute_command_with_timeout("sleep", &["5"], Duration::from_millis(50))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+
+        // The backgrounded sleep keeps stdout open after sh exits
+        let started = std::time::Instant::now();
+        let err = ctx
+            .execute_command_with_timeout("sh", &["-c", "sleep 5 &"], Duration::from_millis(200))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
 }
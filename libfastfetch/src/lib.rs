@@ -11,10 +11,19 @@ pub mod logo;
 pub mod modules;
 pub mod output;
 pub mod platform;
+pub mod util;
 
-pub use app::Application;
-pub use config::{Config, ConfigBuilder, LogoConfig};
+pub use app::{Application, ModuleStream, RunReport};
+pub use config::{
+    Config, ConfigBuilder, ConfigError, ConfigFile, ConfigFormat, LogoConfig, ModuleEntry,
+};
 pub use context::{RealSystemContext, SystemContext};
 pub use error::{DetectionResult, Error};
-pub use modules::{Module, ModuleInfo, ModuleKind};
-pub use output::{OutputFormatter, RenderedModule};
+pub use modules::{
+    IconSet, Module, ModuleInfo, ModuleKind, ModuleMetadata, ModuleOptions, ModuleOverrides,
+    TemperatureUnit,
+};
+pub use output::{
+    Color, DisplayOptions, JsonFormatter, KeyAlign, Layout, OutputFormat, OutputFormatter,
+    OutputRenderer, RenderedModule, Template, TextStyle,
+};
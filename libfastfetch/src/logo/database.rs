@@ -203,6 +203,116 @@ pub fn generic_linux() -> LogoDefinition {
     }
 }
 
+/// A logo in the database with the distro IDs it is known by
+#[derive(Debug, Clone, Copy)]
+pub struct NamedLogo {
+    pub name: &'static str,
+    /// Other names, such as `ID=` values from os-release
+    pub aliases: &'static [&'static str],
+    pub logo: fn() -> LogoDefinition,
+}
+
+impl NamedLogo {
+    /// The name and the aliases
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.name).chain(self.aliases.iter().copied())
+    }
+}
+
+/// Every logo that can be selected by name
+pub const LOGOS: &[NamedLogo] = &[
+    NamedLogo {
+        name: "arch",
+        aliases: &["archlinux"],
+        logo: arch_linux,
+    },
+    NamedLogo {
+        name: "cachyos",
+        aliases: &[],
+        logo: cachyos,
+    },
+    NamedLogo {
+        name: "debian",
+        aliases: &[],
+        logo: debian,
+    },
+    NamedLogo {
+        name: "fedora",
+        aliases: &[],
+        logo: fedora,
+    },
+    NamedLogo {
+        name: "gentoo",
+        aliases: &[],
+        logo: gentoo,
+    },
+    NamedLogo {
+        name: "manjaro",
+        aliases: &[],
+        logo: manjaro,
+    },
+    NamedLogo {
+        name: "opensuse",
+        aliases: &["opensuse-leap", "opensuse-tumbleweed", "suse"],
+        logo: opensuse,
+    },
+    NamedLogo {
+        name: "ubuntu",
+        aliases: &[],
+        logo: ubuntu,
+    },
+    NamedLogo {
+        name: "linux",
+        aliases: &["generic", "tux"],
+        logo: generic_linux,
+    },
+];
+
+/// Logo named exactly `name` or one of its aliases, ignoring case
+fn lookup(name: &str) -> Option<&'static NamedLogo> {
+    LOGOS
+        .iter()
+        .find(|logo| logo.names().any(|known| known.eq_ignore_ascii_case(name)))
+}
+
+/// Find a logo by name, leniently
+///
+/// Case, spaces, `-` and `_` are ignored, and a name that starts with a
+/// known name or is the start of exactly one matches too, so `Arch Linux`,
+/// `opensuse_slowroll` and `manj` all find a logo.
+pub fn find(name: &str) -> Option<&'static NamedLogo> {
+    if let Some(logo) = lookup(name) {
+        return Some(logo);
+    }
+
+    let squash = |name: &str| -> String {
+        name.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+    let query = squash(name);
+    if query.is_empty() {
+        return None;
+    }
+
+    let candidates = || {
+        LOGOS
+            .iter()
+            .flat_map(|logo| logo.names().map(move |known| (logo, squash(known))))
+    };
+    if let Some((logo, _)) = candidates().find(|(_, known)| query.starts_with(known.as_str())) {
+        return Some(logo);
+    }
+
+    // An abbreviation must not be ambiguous
+    let mut matches = candidates()
+        .filter(|(_, known)| known.starts_with(&query))
+        .map(|(logo, _)| logo);
+    let first = matches.next()?;
+    matches.all(|logo| logo.name == first.name).then_some(first)
+}
+
 /// Detect distribution from /etc/os-release and return appropriate logo
 pub fn detect_logo() -> LogoDefinition {
     #[cfg(target_os = "linux")]
@@ -212,18 +322,8 @@ pub fn detect_logo() -> LogoDefinition {
         {
             for line in content.lines() {
                 if let Some(id) = line.strip_prefix("ID=") {
-                    let id = id.trim_matches('"').to_lowercase();
-                    return match id.as_str() {
-                        "arch" | "archlinux" => arch_linux(),
-                        "cachyos" => cachyos(),
-                        "manjaro" => manjaro(),
-                        "ubuntu" => ubuntu(),
-                        "debian" => debian(),
-                        "fedora" => fedora(),
-                        "gentoo" => gentoo(),
-                        "opensuse" | "opensuse-leap" | "opensuse-tumbleweed" => opensuse(),
-                        _ => generic_linux(),
-                    };
+                    return lookup(id.trim_matches('"'))
+                        .map_or_else(generic_linux, |logo| (logo.logo)());
                 }
             }
         }
@@ -235,3 +335,28 @@ pub fn detect_logo() -> LogoDefinition {
         generic_linux()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_logo() {
+        let name = |query| find(query).map(|logo| logo.name);
+        assert_eq!(name("Arch"), Some("arch"));
+        assert_eq!(name("Arch Linux"), Some("arch"));
+        assert_eq!(name("opensuse_tumbleweed"), Some("opensuse"));
+        assert_eq!(name("opensuse-slowroll"), Some("opensuse"));
+        assert_eq!(name("manj"), Some("manjaro"));
+        assert_eq!(name("tux"), Some("linux"));
+        assert_eq!(name("windows"), None);
+        assert_eq!(name(""), None);
+    }
+
+    #[test]
+    fn test_ambiguous_abbreviation() {
+        // "gen" could be gentoo or the generic logo
+        assert_eq!(find("gen").map(|logo| logo.name), None);
+        assert_eq!(find("gent").map(|logo| logo.name), Some("gentoo"));
+    }
+}
@@ -5,52 +5,132 @@
 pub mod database;
 
 use crate::config::LogoConfig;
-use crate::output::{Color, StyledString};
+use crate::output::{Color, Style, colors_enabled, strip_ansi, visible_width};
+use std::{fmt, str::FromStr};
+
+/// Number of colors in a logo's palette
+pub const PALETTE_SIZE: usize = 9;
+
+/// Where the logo goes relative to the module lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogoPosition {
+    #[default]
+    Left,
+    Right,
+    /// Centered under the module lines
+    Bottom,
+}
+
+impl FromStr for LogoPosition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "bottom" => Ok(Self::Bottom),
+            _ => Err(format!(
+                "Unknown logo position: {s} (expected left, right or bottom)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogoPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "left"),
+            Self::Right => write!(f, "right"),
+            Self::Bottom => write!(f, "bottom"),
+        }
+    }
+}
 
 /// Renderable logo representation.
 #[derive(Debug, Clone)]
 pub struct Logo {
     lines: Vec<String>,
     width: usize,
-    color: Option<Color>,
+    /// Colors 1 to 9; database logos only use the first
+    palette: [Option<Color>; PALETTE_SIZE],
+    /// Custom art may switch colors with `$1` to `$9`
+    placeholders: bool,
+    position: LogoPosition,
 }
 
 impl Logo {
     /// Build a logo from configuration, splitting on newlines and measuring width.
+    ///
+    /// Inline ASCII art comes first, then art read from `source`; a file
+    /// that can't be read falls back to the named or detected logo. Colors
+    /// set in the config replace the logo's own.
     pub fn from_config(config: &LogoConfig) -> Option<Self> {
+        let custom = config.ascii_art.clone().or_else(|| {
+            config
+                .source
+                .as_ref()
+                .and_then(|path| std::fs::read_to_string(path).ok())
+        });
+
         // If custom ASCII art is provided, use it
-        if let Some(ref ascii) = config.ascii_art {
+        let mut logo = if let Some(ascii) = custom {
             let lines: Vec<String> = ascii.lines().map(|line| line.to_string()).collect();
             let width = lines
                 .iter()
-                .map(|line| line.chars().count())
+                .map(|line| visible_width(&strip_placeholders(line)))
                 .max()
                 .unwrap_or(0);
 
             if lines.is_empty() {
-                None
-            } else {
-                Some(Self {
-                    lines,
-                    width,
-                    color: None,
-                })
+                return None;
+            }
+            Self {
+                lines,
+                width,
+                palette: [None; PALETTE_SIZE],
+                placeholders: true,
+                position: LogoPosition::default(),
             }
         } else {
-            // Auto-detect distribution logo
-            let logo_def = database::detect_logo();
-            let width = logo_def
-                .lines
-                .iter()
-                .map(|line| line.chars().count())
-                .max()
-                .unwrap_or(0);
+            // A logo picked by name, else the detected distribution's
+            let logo_def = config
+                .name
+                .as_deref()
+                .and_then(database::find)
+                .map_or_else(database::detect_logo, |logo| (logo.logo)());
+            Self::from_definition(logo_def)
+        };
 
-            Some(Self {
-                lines: logo_def.lines.iter().map(|s| s.to_string()).collect(),
-                width,
-                color: logo_def.color,
-            })
+        logo.position = config.position;
+        for (&index, &color) in &config.colors {
+            if let Some(slot) = index.checked_sub(1).and_then(|i| logo.palette.get_mut(i)) {
+                *slot = Some(color);
+            }
+        }
+        Some(logo)
+    }
+
+    /// The database logo `name` finds, see [`database::find`].
+    pub fn named(name: &str) -> Option<Self> {
+        database::find(name).map(|logo| Self::from_definition((logo.logo)()))
+    }
+
+    fn from_definition(logo_def: database::LogoDefinition) -> Self {
+        let width = logo_def
+            .lines
+            .iter()
+            .map(|line| visible_width(line))
+            .max()
+            .unwrap_or(0);
+
+        let mut palette = [None; PALETTE_SIZE];
+        palette[0] = logo_def.color;
+        Self {
+            lines: logo_def.lines.iter().map(|s| s.to_string()).collect(),
+            width,
+            palette,
+            placeholders: false,
+            position: LogoPosition::default(),
         }
     }
 
@@ -59,15 +139,156 @@ impl Logo {
         self.width
     }
 
+    /// Where the logo goes relative to the module lines.
+    pub const fn position(&self) -> LogoPosition {
+        self.position
+    }
+
+    /// Accent color of the logo, if it has one.
+    pub const fn color(&self) -> Option<Color> {
+        self.palette[0]
+    }
+
     /// Lines to render top-to-bottom, with color applied if available.
+    ///
+    /// Lines start in the first color; in custom art `$N` switches to color
+    /// N and `$$` is a plain `$`. Lines carrying ANSI escapes are reset at
+    /// the end so the colors don't run into the module output. With colors
+    /// turned off, escapes in the art itself are dropped too.
     pub fn lines(&self) -> Vec<String> {
-        if let Some(color) = self.color {
-            self.lines
-                .iter()
-                .map(|line| StyledString::new(line).fg(color).format())
-                .collect()
-        } else {
-            self.lines.clone()
+        self.lines
+            .iter()
+            .map(|line| {
+                let mut out = self.color().map(color_code).unwrap_or_default();
+                if self.placeholders {
+                    self.expand_placeholders(line, &mut out);
+                } else {
+                    out.push_str(line);
+                }
+                if !colors_enabled() {
+                    return strip_ansi(&out);
+                }
+                if out.contains('\x1b') {
+                    out.push_str(Style::Reset.code());
+                }
+                out
+            })
+            .collect()
+    }
+
+    /// Append `line` to `out` with `$N` replaced by color N's escape code
+    fn expand_placeholders(&self, line: &str, out: &mut String) {
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            let next = chars.peek().copied();
+            match (c, next) {
+                ('$', Some('$')) => {
+                    chars.next();
+                    out.push('$');
+                }
+                ('$', Some(digit @ '1'..='9')) => {
+                    chars.next();
+                    let index = digit as usize - '1' as usize;
+                    // A color that isn't set falls back to the terminal's default
+                    match self.palette[index] {
+                        Some(color) => out.push_str(&color_code(color)),
+                        None => out.push_str("\x1b[39m"),
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+    }
+}
+
+/// Escape code switching the foreground to `color`
+fn color_code(color: Color) -> String {
+    color
+        .fg_rgb_code()
+        .unwrap_or_else(|| color.fg_code().to_string())
+}
+
+/// `line` without its `$N` color placeholders, with `$$` as `$`
+fn strip_placeholders(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('$', Some('$')) => {
+                chars.next();
+                out.push('$');
+            }
+            ('$', Some('1'..='9')) => {
+                chars.next();
+            }
+            _ => out.push(c),
         }
     }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logo_from_file() {
+        let path = std::env::temp_dir().join(format!("fastfetch-rs-logo-{}", std::process::id()));
+        std::fs::write(&path, "\x1b[34m/\\\\\x1b[0m\n\x1b[1;34m|__|\n").unwrap();
+
+        let config = LogoConfig {
+            source: Some(path.clone()),
+            ..LogoConfig::default()
+        };
+        let logo = Logo::from_config(&config).unwrap();
+        assert_eq!(logo.width(), 4);
+        // The unterminated color is reset before the module text
+        assert_eq!(logo.lines()[1], "\x1b[1;34m|__|\x1b[0m");
+
+        std::fs::remove_file(&path).unwrap();
+        // An unreadable file falls back to the named logo
+        let config = LogoConfig {
+            name: Some("arch".to_string()),
+            ..config
+        };
+        assert_eq!(
+            Logo::from_config(&config).unwrap().width(),
+            Logo::from_config(&LogoConfig {
+                name: Some("arch".to_string()),
+                ..LogoConfig::default()
+            })
+            .unwrap()
+            .width()
+        );
+    }
+
+    #[test]
+    fn test_logo_colors() {
+        let mut config = LogoConfig {
+            ascii_art: Some("$1/$2\\$$\n".to_string()),
+            ..LogoConfig::default()
+        };
+        let logo = Logo::from_config(&config).unwrap();
+        assert_eq!(logo.width(), 3);
+        // Unset colors fall back to the terminal's default color
+        assert_eq!(logo.lines(), ["\x1b[39m/\x1b[39m\\$\x1b[0m"]);
+
+        config.colors.insert(1, Color::Red);
+        config.colors.insert(2, Color::Rgb(0, 128, 255));
+        let logo = Logo::from_config(&config).unwrap();
+        assert_eq!(
+            logo.lines(),
+            ["\x1b[31m\x1b[31m/\x1b[38;2;0;128;255m\\$\x1b[0m"]
+        );
+
+        // Database logos are drawn in their first color
+        let config = LogoConfig {
+            name: Some("arch".to_string()),
+            colors: [(1, Color::Green)].into(),
+            ..LogoConfig::default()
+        };
+        let logo = Logo::from_config(&config).unwrap();
+        assert_eq!(logo.color(), Some(Color::Green));
+        assert!(logo.lines()[0].starts_with("\x1b[32m "));
+    }
 }
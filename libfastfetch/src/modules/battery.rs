@@ -0,0 +1,240 @@
+//! Battery detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::{fmt, time::Duration};
+
+/// Battery detection module
+#[derive(Debug)]
+pub struct BatteryModule;
+
+/// Charging state reported by the battery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatteryStatus {
+    Charging,
+    Discharging,
+    Full,
+    NotCharging,
+    Unknown,
+}
+
+impl BatteryStatus {
+    fn from_sysfs(status: &str) -> Self {
+        match status {
+            "Charging" => Self::Charging,
+            "Discharging" => Self::Discharging,
+            "Full" => Self::Full,
+            "Not charging" => Self::NotCharging,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for BatteryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Charging => write!(f, "Charging"),
+            Self::Discharging => write!(f, "Discharging"),
+            Self::Full => write!(f, "Full"),
+            Self::NotCharging => write!(f, "Not charging"),
+            Self::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Battery information
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatteryInfo {
+    /// Power supply name, e.g. "BAT0"
+    pub name: String,
+    pub model: Option<String>,
+    /// Current charge in percent
+    pub percentage: u8,
+    pub status: BatteryStatus,
+    /// Capacity the battery was designed for, in µWh or µAh
+    pub design_capacity: Option<u64>,
+    /// Capacity the battery holds when fully charged today, same unit as `design_capacity`
+    pub full_capacity: Option<u64>,
+    pub cycle_count: Option<u32>,
+    /// Estimated time until empty while discharging
+    pub time_to_empty: Option<Duration>,
+    /// Estimated time until full while charging
+    pub time_to_full: Option<Duration>,
+}
+
+impl BatteryInfo {
+    /// Full-charge capacity as a percentage of the design capacity
+    pub fn health(&self) -> Option<f64> {
+        let design = self.design_capacity.filter(|&design| design > 0)?;
+        Some(self.full_capacity? as f64 / design as f64 * 100.0)
+    }
+}
+
+impl fmt::Display for BatteryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}% [{}]", self.percentage, self.status)?;
+        if let Some(time) = self.time_to_empty {
+            write!(f, ", {} remaining", format_duration(time))?;
+        } else if let Some(time) = self.time_to_full {
+            write!(f, ", {} until full", format_duration(time))?;
+        }
+        if let Some(health) = self.health() {
+            write!(f, ", health {health:.0}%")?;
+        }
+        if let Some(cycles) = self.cycle_count {
+            write!(f, ", {cycles} cycles")?;
+        }
+        Ok(())
+    }
+}
+
+/// Format as "2h 15m" or "45m"
+fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+impl Module for BatteryModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_battery(ctx).map(ModuleInfo::Battery)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Battery
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Charge level and state of each battery",
+            ModuleMetadata::LINUX,
+        )
+        .with_options(ModuleMetadata::CAPACITY_OPTIONS)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_battery(ctx: &dyn SystemContext) -> DetectionResult<BatteryInfo> {
+    use std::path::Path;
+
+    let base = Path::new("/sys/class/power_supply");
+    let mut supplies = ctx.read_dir(base).unwrap_or_default();
+    supplies.sort();
+
+    // System batteries only; peripherals report scope "Device"
+    let battery = supplies.into_iter().find_map(|name| {
+        let dir = base.join(&name);
+        let read = |attr: &str| {
+            ctx.read_file(&dir.join(attr))
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
+        if read("type").as_deref() != Some("Battery") || read("scope").as_deref() == Some("Device")
+        {
+            return None;
+        }
+        battery_from_attrs(name, |attr| read(attr))
+    });
+
+    match battery {
+        Some(battery) => DetectionResult::Detected(battery),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Build battery info from power_supply attributes
+///
+/// Drivers report either energy (µWh, with power in µW) or charge (µAh,
+/// with current in µA); the ratios work out the same for either.
+#[cfg(target_os = "linux")]
+fn battery_from_attrs(name: String, read: impl Fn(&str) -> Option<String>) -> Option<BatteryInfo> {
+    let number = |attr: &str| read(attr)?.parse::<u64>().ok();
+    let either = |energy: &str, charge: &str| number(energy).or_else(|| number(charge));
+
+    let now = either("energy_now", "charge_now");
+    let full = either("energy_full", "charge_full");
+    let rate = either("power_now", "current_now").filter(|&rate| rate > 0);
+
+    let percentage = number("capacity")
+        .or_else(|| Some(now? * 100 / full.filter(|&full| full > 0)?))?
+        .min(100) as u8;
+    let status = BatteryStatus::from_sysfs(read("status").as_deref().unwrap_or_default());
+
+    // Time to move `amount` of energy or charge at the current rate
+    let hours = |amount: u64| {
+        let rate = rate?;
+        Some(Duration::from_secs_f64(
+            amount as f64 / rate as f64 * 3600.0,
+        ))
+    };
+    let (time_to_empty, time_to_full) = match status {
+        BatteryStatus::Discharging => (now.and_then(hours), None),
+        BatteryStatus::Charging => (
+            None,
+            full.zip(now)
+                .and_then(|(full, now)| hours(full.saturating_sub(now))),
+        ),
+        _ => (None, None),
+    };
+
+    Some(BatteryInfo {
+        name,
+        model: read("model_name").filter(|model| !model.is_empty()),
+        percentage,
+        status,
+        design_capacity: either("energy_full_design", "charge_full_design"),
+        full_capacity: full,
+        // Drivers without cycle tracking report 0
+        cycle_count: number("cycle_count")
+            .filter(|&cycles| cycles > 0)
+            .map(|cycles| cycles as u32),
+        time_to_empty,
+        time_to_full,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_battery(_ctx: &dyn SystemContext) -> DetectionResult<BatteryInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_battery_from_attrs() {
+        let attrs: HashMap<&str, &str> = HashMap::from([
+            ("status", "Discharging"),
+            ("capacity", "80"),
+            ("energy_now", "40000000"),
+            ("energy_full", "50000000"),
+            ("energy_full_design", "57000000"),
+            ("power_now", "10000000"),
+            ("cycle_count", "312"),
+            ("model_name", "5B10W13930"),
+        ]);
+        let battery = battery_from_attrs("BAT0".to_string(), |attr| {
+            attrs.get(attr).map(|v| v.to_string())
+        })
+        .unwrap();
+
+        assert_eq!(battery.percentage, 80);
+        assert_eq!(battery.status, BatteryStatus::Discharging);
+        assert_eq!(battery.cycle_count, Some(312));
+        assert_eq!(battery.time_to_empty, Some(Duration::from_secs(4 * 3600)));
+        assert_eq!(battery.time_to_full, None);
+        assert_eq!(battery.health().map(f64::round), Some(88.0));
+        assert_eq!(
+            battery.to_string(),
+            "80% [Discharging], 4h 0m remaining, health 88%, 312 cycles"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! BIOS/UEFI firmware detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// BIOS detection module
+#[derive(Debug)]
+pub struct BiosModule;
+
+/// Firmware information
+#[derive(Debug, Clone, Serialize)]
+pub struct BiosInfo {
+    pub vendor: Option<String>,
+    pub version: String,
+    pub date: Option<String>,
+    /// Whether the system booted through UEFI rather than legacy BIOS
+    pub uefi: bool,
+}
+
+impl fmt::Display for BiosInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(vendor) = &self.vendor {
+            write!(f, "{vendor} ")?;
+        }
+        write!(f, "{}", self.version)?;
+        if let Some(date) = &self.date {
+            write!(f, " ({date})")?;
+        }
+        write!(f, " [{}]", if self.uefi { "UEFI" } else { "Legacy" })
+    }
+}
+
+impl Module for BiosModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_bios(ctx).map(ModuleInfo::Bios)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Bios
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Firmware vendor, version and release date",
+            &["linux", "freebsd"],
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_bios(ctx: &dyn SystemContext) -> DetectionResult<BiosInfo> {
+    use crate::platform::linux::sys::dmi;
+    use std::path::Path;
+
+    match dmi::meaningful(dmi::bios_version) {
+        Some(version) => DetectionResult::Detected(BiosInfo {
+            vendor: dmi::meaningful(dmi::bios_vendor),
+            version,
+            date: dmi::meaningful(dmi::bios_date),
+            // The kernel only exposes efivars when booted through UEFI
+            uefi: ctx.read_dir(Path::new("/sys/firmware/efi")).is_ok(),
+        }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn detect_bios(ctx: &dyn SystemContext) -> DetectionResult<BiosInfo> {
+    use crate::platform::{freebsd::kenv, is_smbios_placeholder};
+
+    let smbios = |name: &str| kenv(ctx, name).filter(|value| !is_smbios_placeholder(value));
+
+    let uefi = ctx
+        .execute_command("sysctl", &["-n", "machdep.bootmethod"])
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "UEFI");
+
+    match smbios("smbios.bios.version") {
+        Some(version) => DetectionResult::Detected(BiosInfo {
+            vendor: smbios("smbios.bios.vendor"),
+            version,
+            date: smbios("smbios.bios.reldate"),
+            uefi,
+        }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "freebsd")))]
+fn detect_bios(_ctx: &dyn SystemContext) -> DetectionResult<BiosInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,247 @@
+//! Bluetooth adapter and connected devices detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Bluetooth detection module
+#[derive(Debug)]
+pub struct BluetoothModule;
+
+/// A connected Bluetooth device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BluetoothDevice {
+    pub name: String,
+    /// Battery level in percent, when the device reports one
+    pub battery: Option<u8>,
+}
+
+/// Bluetooth adapter information
+#[derive(Debug, Clone, Serialize)]
+pub struct BluetoothInfo {
+    pub adapter: String,
+    pub powered: bool,
+    pub devices: Vec<BluetoothDevice>,
+}
+
+impl fmt::Display for BluetoothInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.adapter)?;
+
+        if !self.powered {
+            return write!(f, " (Off)");
+        }
+
+        for (i, device) in self.devices.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { ": " } else { ", " }, device.name)?;
+            if let Some(battery) = device.battery {
+                write!(f, " ({battery}%)")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Module for BluetoothModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_bluetooth(ctx).map(ModuleInfo::Bluetooth)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Bluetooth
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Bluetooth adapters and connected devices",
+            &["linux", "macos"],
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_bluetooth(ctx: &dyn SystemContext) -> DetectionResult<BluetoothInfo> {
+    use crate::platform::linux::dbus::DbusBus;
+
+    // bluetoothd exports adapters and devices through the ObjectManager at "/"
+    let objects = match ctx.dbus_call(
+        DbusBus::System,
+        "org.bluez",
+        "/",
+        "org.freedesktop.DBus.ObjectManager",
+        "GetManagedObjects",
+        &[],
+    ) {
+        Ok(objects) => objects,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+
+    match bluetooth_from_objects(&objects) {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Build adapter info from a BlueZ `GetManagedObjects` reply
+#[cfg(target_os = "linux")]
+fn bluetooth_from_objects(
+    objects: &crate::platform::linux::dbus::DbusValue,
+) -> Option<BluetoothInfo> {
+    let mut info: Option<BluetoothInfo> = None;
+    let mut devices = Vec::new();
+
+    for (_, interfaces) in objects.as_dict()? {
+        if let Some(adapter) = interfaces.get("org.bluez.Adapter1") {
+            if info.is_none() {
+                info = Some(BluetoothInfo {
+                    adapter: adapter
+                        .get("Alias")
+                        .or_else(|| adapter.get("Name"))
+                        .and_then(|name| name.as_str())
+                        .unwrap_or("Bluetooth")
+                        .to_string(),
+                    powered: adapter
+                        .get("Powered")
+                        .and_then(|powered| powered.as_bool())
+                        .unwrap_or(false),
+                    devices: Vec::new(),
+                });
+            }
+        } else if let Some(device) = interfaces.get("org.bluez.Device1") {
+            let connected = device
+                .get("Connected")
+                .and_then(|connected| connected.as_bool())
+                .unwrap_or(false);
+            if !connected {
+                continue;
+            }
+
+            let name = device
+                .get("Alias")
+                .or_else(|| device.get("Name"))
+                .and_then(|name| name.as_str())
+                .unwrap_or("Unknown device");
+            let battery = interfaces
+                .get("org.bluez.Battery1")
+                .and_then(|battery| battery.get("Percentage"))
+                .and_then(|percentage| percentage.as_u64())
+                .and_then(|percentage| u8::try_from(percentage).ok());
+
+            devices.push(BluetoothDevice {
+                name: name.to_string(),
+                battery,
+            });
+        }
+    }
+
+    let mut info = info?;
+    info.devices = devices;
+    Some(info)
+}
+
+#[cfg(target_os = "macos")]
+fn detect_bluetooth(ctx: &dyn SystemContext) -> DetectionResult<BluetoothInfo> {
+    let output = match ctx.execute_command("system_profiler", &["SPBluetoothDataType"]) {
+        Ok(output) if output.success => output,
+        _ => return DetectionResult::Unavailable,
+    };
+
+    match parse_system_profiler(&String::from_utf8_lossy(&output.stdout)) {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Parse `system_profiler SPBluetoothDataType` output
+///
+/// Devices are listed as indented "Name:" headings under a "Connected:"
+/// section, each followed by more deeply indented properties.
+#[cfg(target_os = "macos")]
+fn parse_system_profiler(output: &str) -> Option<BluetoothInfo> {
+    let mut adapter = None;
+    let mut powered = false;
+    let mut devices: Vec<BluetoothDevice> = Vec::new();
+    let mut connected_indent: Option<usize> = None;
+    let mut device_indent: Option<usize> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        // Leaving the "Connected:" section
+        if connected_indent.is_some_and(|section| indent <= section) {
+            connected_indent = None;
+        }
+
+        match trimmed.split_once(':') {
+            Some((key, "")) if connected_indent.is_some() => {
+                let device = *device_indent.get_or_insert(indent);
+                if indent == device {
+                    devices.push(BluetoothDevice {
+                        name: key.to_string(),
+                        battery: None,
+                    });
+                }
+            }
+            Some(("Connected", "")) => {
+                connected_indent = Some(indent);
+                device_indent = None;
+            }
+            Some((key, value)) if connected_indent.is_some() => {
+                let battery = key
+                    .starts_with("Battery Level")
+                    .then(|| value.trim().trim_end_matches('%').parse::<u8>().ok())
+                    .flatten();
+                if let (Some(level), Some(device)) = (battery, devices.last_mut()) {
+                    device.battery = Some(device.battery.map_or(level, |b| b.min(level)));
+                }
+            }
+            Some(("State", value)) => powered = value.trim() == "On",
+            Some(("Chipset", value)) => adapter = Some(value.trim().to_string()),
+            Some(("Bluetooth Controller", "")) => {
+                adapter.get_or_insert_with(|| "Bluetooth Controller".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    Some(BluetoothInfo {
+        adapter: adapter?,
+        powered,
+        devices,
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_bluetooth(_ctx: &dyn SystemContext) -> DetectionResult<BluetoothInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::platform::linux::dbus::parse_busctl_output;
+
+    #[test]
+    fn test_bluetooth_from_objects() {
+        let objects = parse_busctl_output(concat!(
+            "a{oa{sa{sv}}} 3 ",
+            "\"/org/bluez/hci0\" 1 \"org.bluez.Adapter1\" 2 \"Alias\" s \"laptop\" \"Powered\" b true ",
+            "\"/org/bluez/hci0/dev_AA\" 2 \"org.bluez.Device1\" 2 \"Alias\" s \"WH-1000XM4\" ",
+            "\"Connected\" b true \"org.bluez.Battery1\" 1 \"Percentage\" y 70 ",
+            "\"/org/bluez/hci0/dev_BB\" 1 \"org.bluez.Device1\" 2 \"Alias\" s \"Keyboard\" ",
+            "\"Connected\" b false",
+        ))
+        .unwrap();
+
+        let info = bluetooth_from_objects(&objects).unwrap();
+        assert_eq!(info.devices.len(), 1);
+        assert_eq!(info.to_string(), "laptop: WH-1000XM4 (70%)");
+    }
+}
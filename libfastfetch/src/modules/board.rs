@@ -0,0 +1,100 @@
+//! Motherboard detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Board detection module
+#[derive(Debug)]
+pub struct BoardModule;
+
+/// Motherboard information
+#[derive(Debug, Clone, Serialize)]
+pub struct BoardInfo {
+    pub vendor: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl fmt::Display for BoardInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(vendor) = &self.vendor {
+            write!(f, "{vendor} ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, " ({version})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for BoardModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_board(ctx).map(ModuleInfo::Board)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Board
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Motherboard vendor and model", ModuleMetadata::UNIX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_board(_ctx: &dyn SystemContext) -> DetectionResult<BoardInfo> {
+    use crate::platform::linux::sys::dmi;
+
+    match dmi::meaningful(dmi::board_name) {
+        Some(name) => DetectionResult::Detected(BoardInfo {
+            vendor: dmi::meaningful(dmi::board_vendor),
+            name,
+            version: dmi::meaningful(dmi::board_version),
+        }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_board(ctx: &dyn SystemContext) -> DetectionResult<BoardInfo> {
+    use crate::platform::macos::ioreg_platform_property;
+
+    // Intel Macs carry a "Mac-XXXXXXXX" board-id; Apple Silicon only has the model
+    let name =
+        ioreg_platform_property(ctx, "board-id").or_else(|| ioreg_platform_property(ctx, "model"));
+
+    match name {
+        Some(name) => DetectionResult::Detected(BoardInfo {
+            vendor: ioreg_platform_property(ctx, "manufacturer"),
+            name,
+            version: None,
+        }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn detect_board(ctx: &dyn SystemContext) -> DetectionResult<BoardInfo> {
+    use crate::platform::{freebsd::kenv, is_smbios_placeholder};
+
+    let smbios = |name: &str| kenv(ctx, name).filter(|value| !is_smbios_placeholder(value));
+
+    match smbios("smbios.planar.product") {
+        Some(name) => DetectionResult::Detected(BoardInfo {
+            vendor: smbios("smbios.planar.maker"),
+            name,
+            version: smbios("smbios.planar.version"),
+        }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
+fn detect_board(_ctx: &dyn SystemContext) -> DetectionResult<BoardInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,184 @@
+//! Bootloader detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Bootloader detection module
+#[derive(Debug)]
+pub struct BootloaderModule;
+
+/// Bootloader information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BootloaderInfo {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl fmt::Display for BootloaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, " {version}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for BootloaderModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_bootloader(ctx).map(ModuleInfo::Bootloader)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Bootloader
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Bootloader that started the system", ModuleMetadata::LINUX)
+    }
+}
+
+/// `LoaderInfo` variable of the Boot Loader Interface, set by systemd-boot and Limine
+#[cfg(target_os = "linux")]
+const LOADER_INFO: &str =
+    "/sys/firmware/efi/efivars/LoaderInfo-4a67b082-0a4c-41cf-b6c7-440b29bb8c4f";
+
+/// ESP locations rEFInd may be installed under
+#[cfg(target_os = "linux")]
+const REFIND_DIRS: &[&str] = &[
+    "/boot/efi/EFI/refind",
+    "/boot/EFI/refind",
+    "/efi/EFI/refind",
+];
+
+#[cfg(target_os = "linux")]
+fn detect_bootloader(ctx: &dyn SystemContext) -> DetectionResult<BootloaderInfo> {
+    use std::path::Path;
+
+    // The loader that actually booted us, if it reports itself
+    let reported = ctx
+        .read_bytes(Path::new(LOADER_INFO))
+        .ok()
+        .and_then(|data| parse_efi_string(&data))
+        .or_else(|| bootctl_product(ctx))
+        .map(|product| split_product(&product));
+    if let Some(info) = reported {
+        return DetectionResult::Detected(info);
+    }
+
+    // Otherwise infer from what is installed
+    let exists = |path: &str| ctx.read_dir(Path::new(path)).is_ok();
+
+    let info = if REFIND_DIRS.iter().any(|dir| exists(dir)) {
+        BootloaderInfo {
+            name: "rEFInd".to_string(),
+            version: None,
+        }
+    } else if exists("/boot/limine") || ctx.read_file(Path::new("/boot/limine.conf")).is_ok() {
+        BootloaderInfo {
+            name: "Limine".to_string(),
+            version: command_version(ctx, "limine", &["--version"]),
+        }
+    } else if exists("/boot/grub") || exists("/boot/grub2") {
+        BootloaderInfo {
+            name: "GRUB".to_string(),
+            version: command_version(ctx, "grub-install", &["--version"])
+                .or_else(|| command_version(ctx, "grub2-install", &["--version"])),
+        }
+    } else {
+        return DetectionResult::Unavailable;
+    };
+
+    DetectionResult::Detected(info)
+}
+
+/// Read "Product: systemd-boot 255.4" from the current boot loader section of `bootctl status`
+#[cfg(target_os = "linux")]
+fn bootctl_product(ctx: &dyn SystemContext) -> Option<String> {
+    let output = ctx.execute_command("bootctl", &["status"]).ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Current Boot Loader:"))
+        .find_map(|line| line.trim().strip_prefix("Product:"))
+        .map(|product| product.trim().to_string())
+}
+
+/// Last whitespace-separated token of a version command, e.g. "grub-install (GRUB) 2.12"
+#[cfg(target_os = "linux")]
+fn command_version(ctx: &dyn SystemContext, program: &str, args: &[&str]) -> Option<String> {
+    let output = ctx.execute_command(program, args).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .next()?
+        .split_whitespace()
+        .last()
+        .map(str::to_string)
+}
+
+/// Decode an efivarfs file: 4 attribute bytes followed by a NUL-terminated UTF-16LE string
+#[cfg(target_os = "linux")]
+fn parse_efi_string(data: &[u8]) -> Option<String> {
+    let units: Vec<u16> = data
+        .get(4..)?
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+
+    let value = String::from_utf16_lossy(&units);
+    (!value.is_empty()).then_some(value)
+}
+
+/// Split "systemd-boot 255.4-1" into name and version
+#[cfg(target_os = "linux")]
+fn split_product(product: &str) -> BootloaderInfo {
+    match product.split_once(' ') {
+        Some((name, version)) => BootloaderInfo {
+            name: name.to_string(),
+            version: Some(version.trim().to_string()),
+        },
+        None => BootloaderInfo {
+            name: product.to_string(),
+            version: None,
+        },
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_bootloader(_ctx: &dyn SystemContext) -> DetectionResult<BootloaderInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loader_info() {
+        let mut data = vec![0x06, 0, 0, 0];
+        for unit in "systemd-boot 255.4-1".encode_utf16().chain([0]) {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        let product = parse_efi_string(&data).unwrap();
+        assert_eq!(
+            split_product(&product),
+            BootloaderInfo {
+                name: "systemd-boot".to_string(),
+                version: Some("255.4-1".to_string()),
+            }
+        );
+        assert_eq!(parse_efi_string(&[0x06, 0, 0, 0]), None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Display brightness detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Brightness detection module
+#[derive(Debug)]
+pub struct BrightnessModule;
+
+/// A backlight device and its current level
+#[derive(Debug, Clone, Serialize)]
+pub struct Backlight {
+    pub name: String,
+    pub percent: u32,
+}
+
+/// Backlight information
+#[derive(Debug, Clone, Serialize)]
+pub struct BrightnessInfo {
+    pub backlights: Vec<Backlight>,
+}
+
+impl fmt::Display for BrightnessInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // One line per backlight device
+        for (i, backlight) in self.backlights.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}% ({})", backlight.percent, backlight.name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for BrightnessModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_brightness(ctx).map(ModuleInfo::Brightness)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Brightness
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Backlight level of each display", &["linux", "macos"])
+            .with_options(ModuleMetadata::CAPACITY_OPTIONS)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_brightness(ctx: &dyn SystemContext) -> DetectionResult<BrightnessInfo> {
+    use std::path::Path;
+
+    let backlight_dir = Path::new("/sys/class/backlight");
+    let mut devices = match ctx.read_dir(backlight_dir) {
+        Ok(devices) => devices,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+    devices.sort();
+
+    let read_value = |device: &str, file: &str| -> Option<u64> {
+        ctx.read_file(&backlight_dir.join(device).join(file))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+
+    let backlights: Vec<Backlight> = devices
+        .iter()
+        .filter_map(|device| {
+            let current = read_value(device, "actual_brightness")
+                .or_else(|| read_value(device, "brightness"))?;
+            let max = read_value(device, "max_brightness").filter(|&max| max > 0)?;
+            Some(Backlight {
+                name: device.clone(),
+                percent: (current * 100 / max) as u32,
+            })
+        })
+        .collect();
+
+    if backlights.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(BrightnessInfo { backlights })
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_brightness(ctx: &dyn SystemContext) -> DetectionResult<BrightnessInfo> {
+    // The `brightness` CLI prints "display 0: brightness 0.750000" per display
+    let output = match ctx.execute_command("brightness", &["-l"]) {
+        Ok(output) if output.success => output,
+        _ => return DetectionResult::Unavailable,
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let backlights: Vec<Backlight> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (display, level) = line.split_once(": brightness ")?;
+            let level: f64 = level.trim().parse().ok()?;
+            Some(Backlight {
+                name: display.trim().to_string(),
+                percent: (level * 100.0).round() as u32,
+            })
+        })
+        .collect();
+
+    if backlights.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(BrightnessInfo { backlights })
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_brightness(_ctx: &dyn SystemContext) -> DetectionResult<BrightnessInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,104 @@
+//! Camera detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Camera detection module
+#[derive(Debug)]
+pub struct CameraModule;
+
+/// Video capture devices
+#[derive(Debug, Clone, Serialize)]
+pub struct CameraInfo {
+    pub cameras: Vec<String>,
+}
+
+impl fmt::Display for CameraInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cameras.join(", "))
+    }
+}
+
+impl Module for CameraModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_camera(ctx).map(ModuleInfo::Camera)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Camera
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Attached cameras", &["linux", "macos"])
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_camera(ctx: &dyn SystemContext) -> DetectionResult<CameraInfo> {
+    use std::path::Path;
+
+    let mut nodes: Vec<(u32, String)> = match ctx.read_dir(Path::new("/dev")) {
+        Ok(entries) => entries
+            .into_iter()
+            .filter_map(|entry| Some((entry.strip_prefix("video")?.parse().ok()?, entry)))
+            .collect(),
+        Err(_) => return DetectionResult::Unavailable,
+    };
+    nodes.sort();
+
+    let sysfs = Path::new("/sys/class/video4linux");
+    let mut cameras: Vec<String> = Vec::new();
+    for (_, node) in nodes {
+        // UVC devices expose a second node (index 1) that only carries metadata
+        let index = ctx.read_file(&sysfs.join(&node).join("index"));
+        if index.is_ok_and(|index| index.trim() != "0") {
+            continue;
+        }
+
+        let Ok(name) = ctx.read_file(&sysfs.join(&node).join("name")) else {
+            continue;
+        };
+        let name = name.trim().to_string();
+        if !name.is_empty() && !cameras.contains(&name) {
+            cameras.push(name);
+        }
+    }
+
+    if cameras.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(CameraInfo { cameras })
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_camera(ctx: &dyn SystemContext) -> DetectionResult<CameraInfo> {
+    let output = match ctx.execute_command("system_profiler", &["SPCameraDataType"]) {
+        Ok(output) if output.success => output,
+        _ => return DetectionResult::Unavailable,
+    };
+
+    // Cameras are the headings one level below "Camera:"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let cameras: Vec<String> = stdout
+        .lines()
+        .filter(|line| line.starts_with("    ") && !line.starts_with("     "))
+        .filter_map(|line| line.trim().strip_suffix(':'))
+        .map(str::to_string)
+        .collect();
+
+    if cameras.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(CameraInfo { cameras })
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_camera(_ctx: &dyn SystemContext) -> DetectionResult<CameraInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
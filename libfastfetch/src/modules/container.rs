@@ -0,0 +1,157 @@
+//! Container and sandbox detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Container detection module
+#[derive(Debug)]
+pub struct ContainerModule;
+
+/// Container runtime fastfetch-rs is running under
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContainerInfo {
+    pub runtime: String,
+    /// Container name or sandboxed application ID, when the runtime exposes it
+    pub name: Option<String>,
+}
+
+impl fmt::Display for ContainerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.runtime)?;
+        if let Some(name) = &self.name {
+            write!(f, " ({name})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for ContainerModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_container(ctx).map(ModuleInfo::Container)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Container
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Container or sandbox the system runs in",
+            ModuleMetadata::LINUX,
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_container(ctx: &dyn SystemContext) -> DetectionResult<ContainerInfo> {
+    use std::path::Path;
+
+    let read = |path: &str| ctx.read_file(Path::new(path)).ok();
+
+    // Runtime-specific marker files are the most precise signal
+    let info = if read("/.flatpak-info").is_some() {
+        ContainerInfo {
+            runtime: "Flatpak".to_string(),
+            name: ctx.get_env("FLATPAK_ID"),
+        }
+    } else if let Some(containerenv) = read("/run/.containerenv") {
+        ContainerInfo {
+            runtime: "Podman".to_string(),
+            name: containerenv_value(&containerenv, "name"),
+        }
+    } else if read("/.dockerenv").is_some() {
+        ContainerInfo {
+            runtime: "Docker".to_string(),
+            name: None,
+        }
+    } else if let Some(runtime) = read("/run/systemd/container")
+        .or_else(|| ctx.get_env("container"))
+        .and_then(|value| runtime_name(value.trim()))
+        .or_else(|| read("/proc/1/cgroup").and_then(|cgroup| runtime_from_cgroup(&cgroup)))
+    {
+        ContainerInfo {
+            runtime: runtime.to_string(),
+            name: None,
+        }
+    } else {
+        return DetectionResult::Unavailable;
+    };
+
+    DetectionResult::Detected(info)
+}
+
+/// Map the systemd `container=` convention to a display name
+#[cfg(target_os = "linux")]
+fn runtime_name(id: &str) -> Option<&'static str> {
+    let name = match id {
+        "docker" => "Docker",
+        "podman" => "Podman",
+        "lxc" | "lxc-libvirt" => "LXC",
+        "systemd-nspawn" => "systemd-nspawn",
+        "flatpak" => "Flatpak",
+        "oci" => "OCI",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Infer the runtime from the cgroup path of PID 1
+#[cfg(target_os = "linux")]
+fn runtime_from_cgroup(cgroup: &str) -> Option<&'static str> {
+    cgroup.lines().find_map(|line| {
+        let path = line.splitn(3, ':').nth(2)?;
+        if path.contains("/docker/") || path.contains("/docker-") {
+            Some("Docker")
+        } else if path.contains("/libpod-") {
+            Some("Podman")
+        } else if path.contains("/lxc/") || path.contains("/lxc.payload") {
+            Some("LXC")
+        } else if path.contains("/machine.slice/machine-") {
+            Some("systemd-nspawn")
+        } else {
+            None
+        }
+    })
+}
+
+/// Read a `key="value"` line of /run/.containerenv
+#[cfg(target_os = "linux")]
+fn containerenv_value(content: &str, key: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix('=')?.trim_matches('"');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_container(_ctx: &dyn SystemContext) -> DetectionResult<ContainerInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_from_cgroup() {
+        let docker = "0::/system.slice/docker-4f1c2a.scope\n";
+        assert_eq!(runtime_from_cgroup(docker), Some("Docker"));
+        let lxc = "12:pids:/lxc/web01\n0::/lxc/web01\n";
+        assert_eq!(runtime_from_cgroup(lxc), Some("LXC"));
+        assert_eq!(runtime_from_cgroup("0::/init.scope\n"), None);
+    }
+
+    #[test]
+    fn test_containerenv_value() {
+        let content = "engine=\"podman-4.9.3\"\nname=\"toolbox\"\nrootless=1\n";
+        assert_eq!(
+            containerenv_value(content, "name").as_deref(),
+            Some("toolbox")
+        );
+        assert_eq!(containerenv_value("", "name"), None);
+    }
+}
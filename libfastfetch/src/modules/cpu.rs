@@ -1,6 +1,9 @@
 //! CPU information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata,
+};
+use serde::Serialize;
 use std::fmt;
 use std::path::Path;
 
@@ -9,7 +12,7 @@ use std::path::Path;
 pub struct CpuModule;
 
 /// CPU information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CpuInfo {
     pub model: String,
     pub cores: Option<usize>,
@@ -33,6 +36,10 @@ impl Module for CpuModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Cpu
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Processor model, core count and frequency", ModuleMetadata::ALL)
+    }
 }
 
 #[cfg(target_os = "linux")]
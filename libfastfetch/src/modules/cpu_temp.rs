@@ -0,0 +1,100 @@
+//! CPU temperature detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+    modules::options::TemperatureUnit,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// CPU temperature detection module
+#[derive(Debug, Default)]
+pub struct CpuTempModule {
+    unit: TemperatureUnit,
+}
+
+impl CpuTempModule {
+    /// Create a module that renders readings in the given unit
+    pub const fn new(unit: TemperatureUnit) -> Self {
+        Self { unit }
+    }
+}
+
+/// CPU temperature information
+#[derive(Debug, Clone, Serialize)]
+pub struct CpuTempInfo {
+    pub celsius: f64,
+    pub unit: TemperatureUnit,
+}
+
+impl fmt::Display for CpuTempInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1}{}",
+            self.unit.convert(self.celsius),
+            self.unit.symbol()
+        )
+    }
+}
+
+impl Module for CpuTempModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_cpu_temp(ctx).map(|celsius| {
+            ModuleInfo::CpuTemp(CpuTempInfo {
+                celsius,
+                unit: self.unit,
+            })
+        })
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::CpuTemp
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Processor temperature", &["linux", "macos"])
+            .with_options(&[("unit", "\"celsius\" (default) or \"fahrenheit\"")])
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_cpu_temp(_ctx: &dyn SystemContext) -> DetectionResult<f64> {
+    use crate::platform::linux::sys::thermal;
+
+    // hwmon exposes labelled package sensors; thermal zones are the generic fallback
+    match thermal::cpu_package_temp().or_else(|_| thermal::cpu_zone_temp()) {
+        Ok(celsius) => DetectionResult::Detected(celsius),
+        Err(_) => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_cpu_temp(ctx: &dyn SystemContext) -> DetectionResult<f64> {
+    // powermetrics needs root; without it the SMC sampler is simply unavailable
+    let output = match ctx.execute_command("powermetrics", &["--samplers", "smc", "-i1", "-n1"]) {
+        Ok(output) if output.success => output,
+        _ => return DetectionResult::Unavailable,
+    };
+
+    // Format: "CPU die temperature: 45.67 C"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let celsius = stdout.lines().find_map(|line| {
+        line.strip_prefix("CPU die temperature:")?
+            .split_whitespace()
+            .next()?
+            .parse::<f64>()
+            .ok()
+    });
+
+    match celsius {
+        Some(celsius) => DetectionResult::Detected(celsius),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_cpu_temp(_ctx: &dyn SystemContext) -> DetectionResult<f64> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
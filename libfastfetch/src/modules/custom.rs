@@ -0,0 +1,97 @@
+//! Custom command module
+//!
+//! Runs a user-supplied shell command and shows its trimmed output, so new
+//! lines can be added to the output without writing a module.
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::{fmt, time::Duration};
+
+/// Default limit on how long the command may run
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Custom command module
+#[derive(Debug, Default)]
+pub struct CustomModule {
+    command: Option<String>,
+    timeout: Duration,
+}
+
+impl CustomModule {
+    /// Create a module running `command` through the platform shell
+    pub fn new(command: Option<String>, timeout: Duration) -> Self {
+        Self { command, timeout }
+    }
+}
+
+/// Output of the custom command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomInfo {
+    pub output: String,
+}
+
+impl fmt::Display for CustomInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.output)
+    }
+}
+
+impl Module for CustomModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        let Some(command) = &self.command else {
+            return DetectionResult::Unavailable;
+        };
+        run_command(ctx, command, self.timeout).map(ModuleInfo::Custom)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Custom
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Output of a shell command", ModuleMetadata::ALL).with_options(&[
+            ("command", "Command to run through the shell"),
+            (
+                "timeout",
+                "Milliseconds before the command is killed, 1000 by default",
+            ),
+        ])
+    }
+}
+
+fn run_command(
+    ctx: &dyn SystemContext,
+    command: &str,
+    timeout: Duration,
+) -> DetectionResult<CustomInfo> {
+    use crate::error::Error;
+
+    #[cfg(windows)]
+    let (shell, flag) = ("cmd", "/C");
+    #[cfg(not(windows))]
+    let (shell, flag) = ("sh", "-c");
+
+    let output = match ctx.execute_command_with_timeout(shell, &[flag, command], timeout) {
+        Ok(output) => output,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    if !output.success {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let message = if stderr.is_empty() {
+            format!("Command failed: {command}")
+        } else {
+            stderr
+        };
+        return DetectionResult::Error(Error::DetectionFailed(message));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if stdout.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(CustomInfo { output: stdout })
+    }
+}
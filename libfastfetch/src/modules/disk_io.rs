@@ -0,0 +1,116 @@
+//! Disk I/O throughput detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::{fmt, time::Duration};
+
+/// Disk I/O detection module
+#[derive(Debug)]
+pub struct DiskIoModule {
+    interval: Duration,
+}
+
+impl DiskIoModule {
+    /// Create a module that samples counters over the given window
+    pub const fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+}
+
+impl Default for DiskIoModule {
+    fn default() -> Self {
+        Self::new(super::sampler::DEFAULT_INTERVAL)
+    }
+}
+
+/// Current disk throughput across all physical disks
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskIoInfo {
+    /// Bytes read per second
+    pub read_rate: f64,
+    /// Bytes written per second
+    pub write_rate: f64,
+}
+
+impl fmt::Display for DiskIoInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::util::format_bytes;
+
+        write!(
+            f,
+            "{}/s (R) - {}/s (W)",
+            format_bytes(self.read_rate as u64),
+            format_bytes(self.write_rate as u64)
+        )
+    }
+}
+
+impl Module for DiskIoModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_disk_io(ctx, self.interval).map(ModuleInfo::DiskIo)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::DiskIo
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Disk read and write rates", ModuleMetadata::LINUX).with_options(&[(
+            "sample_interval",
+            "Milliseconds between the two samples, 200 by default",
+        )])
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_disk_io(ctx: &dyn SystemContext, interval: Duration) -> DetectionResult<DiskIoInfo> {
+    use super::sampler::sample;
+    use crate::platform::linux::proc::parse_diskstats_content;
+    use std::path::Path;
+
+    // Only whole physical disks, so partitions and device-mapper layers
+    // are not counted twice
+    let disks: Vec<String> = match ctx.read_dir(Path::new("/sys/block")) {
+        Ok(devices) => devices
+            .into_iter()
+            .filter(|dev| {
+                ctx.read_dir(&Path::new("/sys/block").join(dev).join("device"))
+                    .is_ok()
+            })
+            .collect(),
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    // Total (read, written) sectors
+    let read_totals = |ctx: &dyn SystemContext| {
+        let content = ctx.read_file(Path::new("/proc/diskstats"))?;
+        Ok(parse_diskstats_content(&content)
+            .into_iter()
+            .filter(|stat| disks.contains(&stat.name))
+            .fold((0u64, 0u64), |(read, written), stat| {
+                (read + stat.sectors_read, written + stat.sectors_written)
+            }))
+    };
+
+    let sample = match sample(ctx, interval, read_totals) {
+        Ok(sample) => sample,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    const SECTOR_SIZE: u64 = 512;
+    let read = sample.after.0.saturating_sub(sample.before.0) * SECTOR_SIZE;
+    let written = sample.after.1.saturating_sub(sample.before.1) * SECTOR_SIZE;
+
+    DetectionResult::Detected(DiskIoInfo {
+        read_rate: sample.per_second(read),
+        write_rate: sample.per_second(written),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_disk_io(_ctx: &dyn SystemContext, _interval: Duration) -> DetectionResult<DiskIoInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,195 @@
+//! DNS resolver configuration module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// DNS detection module
+#[derive(Debug)]
+pub struct DnsModule;
+
+/// Active DNS resolvers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DnsInfo {
+    pub servers: Vec<String>,
+    pub search_domains: Vec<String>,
+}
+
+impl fmt::Display for DnsInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.servers.join(", "))?;
+        if !self.search_domains.is_empty() {
+            write!(f, " (search: {})", self.search_domains.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for DnsModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_dns(ctx).map(ModuleInfo::Dns)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Dns
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Configured DNS servers", ModuleMetadata::UNIX)
+    }
+}
+
+/// Local stub listeners that forward to the real upstream servers
+#[cfg(target_os = "linux")]
+const STUB_RESOLVERS: &[&str] = &["127.0.0.53", "127.0.0.54"];
+
+#[cfg(unix)]
+fn detect_dns(ctx: &dyn SystemContext) -> DetectionResult<DnsInfo> {
+    use std::path::Path;
+
+    let mut info = match ctx.read_file(Path::new("/etc/resolv.conf")) {
+        Ok(content) => parse_resolv_conf(&content),
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    // With systemd-resolved, resolv.conf only names the stub; ask resolved
+    // for the servers it forwards to
+    #[cfg(target_os = "linux")]
+    if info
+        .servers
+        .iter()
+        .all(|server| STUB_RESOLVERS.contains(&server.as_str()))
+        && let Ok(output) = ctx.execute_command("resolvectl", &["status"])
+        && output.success
+    {
+        let resolved = parse_resolvectl_status(&String::from_utf8_lossy(&output.stdout));
+        if !resolved.servers.is_empty() {
+            info.servers = resolved.servers;
+        }
+        for domain in resolved.search_domains {
+            if !info.search_domains.contains(&domain) {
+                info.search_domains.push(domain);
+            }
+        }
+    }
+
+    if info.servers.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(info)
+    }
+}
+
+/// Parse `nameserver`, `search` and `domain` lines of resolv.conf
+#[cfg(unix)]
+fn parse_resolv_conf(content: &str) -> DnsInfo {
+    let mut info = DnsInfo::default();
+
+    for line in content.lines() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("nameserver") => info.servers.extend(words.next().map(str::to_string)),
+            // The last search/domain line overrides earlier ones
+            Some("search" | "domain") => {
+                info.search_domains = words.map(str::to_string).collect();
+            }
+            _ => {}
+        }
+    }
+
+    info
+}
+
+/// Collect servers and domains across the global and per-link sections of `resolvectl status`
+///
+/// Multi-valued fields continue on following lines indented under the value.
+#[cfg(target_os = "linux")]
+fn parse_resolvectl_status(output: &str) -> DnsInfo {
+    let mut info = DnsInfo::default();
+    let mut current: Option<&mut Vec<String>> = None;
+
+    for line in output.lines() {
+        // Keys are followed by ": ", which never occurs inside an IPv6 address
+        let values = match line.split_once(": ") {
+            Some((key, values)) => {
+                current = match key.trim() {
+                    "DNS Servers" => Some(&mut info.servers),
+                    "DNS Domain" => Some(&mut info.search_domains),
+                    _ => None,
+                };
+                values
+            }
+            None if line.starts_with(' ') => line,
+            _ => {
+                current = None;
+                continue;
+            }
+        };
+
+        if let Some(list) = current.as_deref_mut() {
+            for value in values.split_whitespace() {
+                // "1.1.1.1#cloudflare-dns.com" carries a DoT server name
+                let value = value.split('#').next().unwrap_or(value);
+                if value != "~." && !list.iter().any(|v| v == value) {
+                    list.push(value.to_string());
+                }
+            }
+        }
+    }
+
+    info
+}
+
+#[cfg(not(unix))]
+fn detect_dns(_ctx: &dyn SystemContext) -> DetectionResult<DnsInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resolv_conf() {
+        let content = "\
+# Generated by NetworkManager
+search home.arpa
+nameserver 192.168.1.1
+nameserver fd00::1
+options edns0
+";
+        let info = parse_resolv_conf(content);
+        assert_eq!(info.servers, ["192.168.1.1", "fd00::1"]);
+        assert_eq!(info.search_domains, ["home.arpa"]);
+    }
+
+    #[test]
+    fn test_parse_resolvectl_status() {
+        let output = "\
+Global
+           Protocols: +LLMNR +mDNS -DNSOverTLS DNSSEC=no/unsupported
+    resolv.conf mode: stub
+
+Link 2 (wlan0)
+    Current Scopes: DNS LLMNR/IPv4 LLMNR/IPv6
+         Protocols: +DefaultRoute +LLMNR -mDNS -DNSOverTLS DNSSEC=no/unsupported
+Current DNS Server: 192.168.1.1
+       DNS Servers: 192.168.1.1
+                    2001:db8::1
+        DNS Domain: home.arpa ~.
+
+Link 3 (tailscale0)
+       DNS Servers: 100.100.100.100#ts.net
+        DNS Domain: tail1234.ts.net
+";
+        let info = parse_resolvectl_status(output);
+        assert_eq!(
+            info.servers,
+            ["192.168.1.1", "2001:db8::1", "100.100.100.100"]
+        );
+        assert_eq!(info.search_domains, ["home.arpa", "tail1234.ts.net"]);
+    }
+}
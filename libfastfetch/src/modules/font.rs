@@ -0,0 +1,123 @@
+//! System font detection module
+//!
+//! Reports the desktop's UI and monospace fonts, as opposed to the font of
+//! the current terminal (see the terminal font module).
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Font detection module
+#[derive(Debug)]
+pub struct FontModule;
+
+/// A configured font
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Font {
+    pub name: String,
+    pub size: Option<f64>,
+}
+
+impl From<(String, Option<f64>)> for Font {
+    fn from((name, size): (String, Option<f64>)) -> Self {
+        Self { name, size }
+    }
+}
+
+impl fmt::Display for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(size) = self.size {
+            write!(f, " ({size}pt)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Desktop font information
+#[derive(Debug, Clone, Serialize)]
+pub struct FontInfo {
+    pub ui: Option<Font>,
+    pub monospace: Option<Font>,
+}
+
+impl fmt::Display for FontInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.ui, &self.monospace) {
+            (Some(ui), Some(mono)) => write!(f, "{ui}, {mono} [Mono]"),
+            (Some(ui), None) => write!(f, "{ui}"),
+            (None, Some(mono)) => write!(f, "{mono} [Mono]"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Module for FontModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_font(ctx).map(ModuleInfo::Font)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Font
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Desktop interface font", ModuleMetadata::UNIX)
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_font(ctx: &dyn SystemContext) -> DetectionResult<FontInfo> {
+    use crate::platform::desktop;
+
+    const INTERFACE: &str = "org.gnome.desktop.interface";
+
+    // KDE serializes QFont ("Noto Sans,10,-1,..."); GNOME/GTK use Pango ("Cantarell 11")
+    let ui = desktop::kde_setting(ctx, "General", "font")
+        .map(|font| desktop::parse_qt_font(&font))
+        .or_else(|| {
+            desktop::gsettings_get(ctx, INTERFACE, "font-name")
+                .or_else(|| desktop::gtk_setting(ctx, "gtk-font-name"))
+                .map(|font| desktop::parse_pango_font(&font))
+        })
+        .map(Font::from);
+    let monospace = desktop::kde_setting(ctx, "General", "fixed")
+        .map(|font| desktop::parse_qt_font(&font))
+        .or_else(|| {
+            desktop::gsettings_get(ctx, INTERFACE, "monospace-font-name")
+                .map(|font| desktop::parse_pango_font(&font))
+        })
+        .map(Font::from);
+
+    if ui.is_none() && monospace.is_none() {
+        return DetectionResult::Unavailable;
+    }
+    DetectionResult::Detected(FontInfo { ui, monospace })
+}
+
+#[cfg(target_os = "macos")]
+fn detect_font(ctx: &dyn SystemContext) -> DetectionResult<FontInfo> {
+    // User overrides live in the global domain; otherwise the system fonts apply
+    let defaults = |key: &str| {
+        let output = ctx.execute_command("defaults", &["read", "-g", key]).ok()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.success && !value.is_empty()).then_some(value)
+    };
+    let font = |name_key: &str, size_key: &str, fallback: &str| Font {
+        name: defaults(name_key).unwrap_or_else(|| fallback.to_string()),
+        size: defaults(size_key).and_then(|size| size.parse().ok()),
+    };
+
+    DetectionResult::Detected(FontInfo {
+        ui: Some(font("NSFont", "NSFontSize", "SF Pro")),
+        monospace: Some(font("NSFixedPitchFont", "NSFixedPitchFontSize", "SF Mono")),
+    })
+}
+
+#[cfg(not(unix))]
+fn detect_font(_ctx: &dyn SystemContext) -> DetectionResult<FontInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
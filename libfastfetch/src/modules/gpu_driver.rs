@@ -0,0 +1,142 @@
+//! GPU kernel driver detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// GPU driver detection module
+#[derive(Debug)]
+pub struct GpuDriverModule;
+
+/// A loaded GPU kernel driver
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GpuDriver {
+    pub name: String,
+    /// Out-of-tree drivers such as nvidia carry a version; in-tree ones usually don't
+    pub version: Option<String>,
+}
+
+/// GPU driver information
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuDriverInfo {
+    pub drivers: Vec<GpuDriver>,
+}
+
+impl fmt::Display for GpuDriverInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, driver) in self.drivers.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", driver.name)?;
+            if let Some(version) = &driver.version {
+                write!(f, " {version}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Module for GpuDriverModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_gpu_driver(ctx).map(ModuleInfo::GpuDriver)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::GpuDriver
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Kernel driver of each graphics card", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_gpu_driver(ctx: &dyn SystemContext) -> DetectionResult<GpuDriverInfo> {
+    use std::path::Path;
+
+    let entries = match ctx.read_dir(Path::new("/sys/class/drm")) {
+        Ok(entries) => entries,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+
+    let mut cards: Vec<&String> = entries
+        .iter()
+        .filter(|name| {
+            // "card0" is a GPU; "card0-HDMI-A-1" is one of its connectors
+            name.strip_prefix("card")
+                .is_some_and(|index| index.chars().all(|c| c.is_ascii_digit()))
+        })
+        .collect();
+    cards.sort();
+
+    let mut drivers: Vec<GpuDriver> = Vec::new();
+    for card in cards {
+        let uevent = Path::new("/sys/class/drm").join(card).join("device/uevent");
+        let Some(name) = ctx.read_file(&uevent).ok().and_then(|u| uevent_driver(&u)) else {
+            continue;
+        };
+        if drivers.iter().any(|driver| driver.name == name) {
+            continue;
+        }
+
+        let version = driver_version(ctx, &name);
+        drivers.push(GpuDriver { name, version });
+    }
+
+    if drivers.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(GpuDriverInfo { drivers })
+    }
+}
+
+/// Extract the `DRIVER=` line of a device uevent file
+#[cfg(target_os = "linux")]
+fn uevent_driver(uevent: &str) -> Option<String> {
+    uevent
+        .lines()
+        .find_map(|line| line.strip_prefix("DRIVER="))
+        .map(str::to_string)
+}
+
+#[cfg(target_os = "linux")]
+fn driver_version(ctx: &dyn SystemContext, driver: &str) -> Option<String> {
+    use std::path::Path;
+
+    let from_sysfs = ctx
+        .read_file(&Path::new("/sys/module").join(driver).join("version"))
+        .ok();
+
+    from_sysfs
+        .or_else(|| {
+            let output = ctx
+                .execute_command("modinfo", &["-F", "version", driver])
+                .ok()?;
+            output
+                .success
+                .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+        })
+        .map(|version| version.trim().to_string())
+        .filter(|version| !version.is_empty())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_gpu_driver(_ctx: &dyn SystemContext) -> DetectionResult<GpuDriverInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uevent_driver() {
+        let uevent = "DRIVER=amdgpu\nPCI_CLASS=30000\nPCI_ID=1002:73BF\n";
+        assert_eq!(uevent_driver(uevent).as_deref(), Some("amdgpu"));
+        assert_eq!(uevent_driver("PCI_CLASS=30000\n"), None);
+    }
+}
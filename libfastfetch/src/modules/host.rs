@@ -1,21 +1,57 @@
-//! Host information detection module
+//! Host (machine model) detection module
+//!
+//! Reports the product the system identifies as through SMBIOS, e.g.
+//! "LENOVO ThinkPad X1 Carbon Gen 9". The hostname is shown by the Title
+//! module instead.
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
 use std::fmt;
 
 /// Host detection module
 #[derive(Debug)]
 pub struct HostModule;
 
-/// Host information
-#[derive(Debug, Clone)]
+/// Machine model information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct HostInfo {
-    pub hostname: String,
+    pub vendor: Option<String>,
+    /// Product name, e.g. "ThinkPad X1 Carbon Gen 9" or "MacBookPro18,3"
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl HostInfo {
+    /// Template field: `vendor`, `name` or `version`
+    pub fn field(&self, name: &str) -> Option<String> {
+        match name {
+            "vendor" => self.vendor.clone(),
+            "name" => Some(self.name.clone()),
+            "version" => self.version.clone(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for HostInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.hostname)
+        // Many vendors already put their name in the product, e.g. "Dell XPS 13"
+        if let Some(vendor) = &self.vendor {
+            let redundant = self
+                .name
+                .to_lowercase()
+                .starts_with(&vendor.to_lowercase());
+            if !redundant {
+                write!(f, "{vendor} ")?;
+            }
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, " ({version})")?;
+        }
+        Ok(())
     }
 }
 
@@ -27,28 +63,182 @@ impl Module for HostModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Host
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Machine model", ModuleMetadata::ALL).with_fields(&[
+            ("vendor", "Manufacturer"),
+            ("name", "Product name"),
+            ("version", "Product version"),
+        ])
+    }
+}
+
+/// Assemble SMBIOS system fields into a `HostInfo`
+///
+/// Lenovo stores the machine type ("20XW0055US") as the product name and the
+/// marketing name ("ThinkPad X1 Carbon Gen 9") as the version, so those are
+/// swapped to lead with the recognizable name.
+#[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "windows"))]
+fn host_from_smbios(
+    vendor: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+) -> Option<HostInfo> {
+    let lenovo = vendor
+        .as_deref()
+        .is_some_and(|vendor| vendor.eq_ignore_ascii_case("lenovo"));
+
+    let (name, version) = match (name, version) {
+        (Some(name), Some(version)) if lenovo => (version, Some(name)),
+        (Some(name), version) => (name, version),
+        (None, version) => (version?, None),
+    };
+
+    Some(HostInfo {
+        vendor,
+        name,
+        version,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn detect_host(_ctx: &dyn SystemContext) -> DetectionResult<HostInfo> {
+    use crate::platform::linux::sys::dmi;
+
+    let info = host_from_smbios(
+        dmi::meaningful(dmi::sys_vendor),
+        dmi::meaningful(dmi::product_name),
+        dmi::meaningful(dmi::product_version),
+    );
+
+    match info {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_host(ctx: &dyn SystemContext) -> DetectionResult<HostInfo> {
+    use crate::platform::macos::ioreg_platform_property;
+
+    let model = ioreg_platform_property(ctx, "model").or_else(|| {
+        let output = ctx.execute_command("sysctl", &["-n", "hw.model"]).ok()?;
+        let model = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.success && !model.is_empty()).then_some(model)
+    });
+
+    match model {
+        Some(name) => DetectionResult::Detected(HostInfo {
+            vendor: Some("Apple".to_string()),
+            name,
+            version: None,
+        }),
+        None => DetectionResult::Unavailable,
+    }
 }
 
-#[cfg(unix)]
+#[cfg(target_os = "freebsd")]
 fn detect_host(ctx: &dyn SystemContext) -> DetectionResult<HostInfo> {
-    match ctx.get_hostname() {
-        Ok(hostname) => DetectionResult::Detected(HostInfo { hostname }),
-        Err(_) => DetectionResult::Unavailable,
+    use crate::platform::{freebsd::kenv, is_smbios_placeholder};
+
+    let smbios = |name: &str| kenv(ctx, name).filter(|value| !is_smbios_placeholder(value));
+
+    let info = host_from_smbios(
+        smbios("smbios.system.maker"),
+        smbios("smbios.system.product"),
+        smbios("smbios.system.version"),
+    );
+
+    match info {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
     }
 }
 
 #[cfg(target_os = "windows")]
 fn detect_host(ctx: &dyn SystemContext) -> DetectionResult<HostInfo> {
-    let hostname = ctx
-        .get_env("COMPUTERNAME")
-        .or_else(|| ctx.get_env("HOSTNAME"))
-        .unwrap_or_else(|| "Unknown".to_string());
+    use crate::platform::is_smbios_placeholder;
+
+    // Windows mirrors the SMBIOS system fields into the registry
+    let query = |value: &str| {
+        let output = ctx
+            .execute_command(
+                "reg",
+                &[
+                    "query",
+                    r"HKLM\HARDWARE\DESCRIPTION\System\BIOS",
+                    "/v",
+                    value,
+                ],
+            )
+            .ok()?;
+        // "    SystemProductName    REG_SZ    XPS 13 9310"
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.split_once("REG_SZ"))
+            .map(|(_, data)| data.trim().to_string())
+            .filter(|data| !is_smbios_placeholder(data))
+    };
 
-    DetectionResult::Detected(HostInfo { hostname })
+    let info = host_from_smbios(
+        query("SystemManufacturer"),
+        query("SystemProductName"),
+        query("SystemVersion"),
+    );
+
+    match info {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
 }
 
-#[cfg(not(any(unix, target_os = "windows")))]
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "windows"
+)))]
 fn detect_host(_ctx: &dyn SystemContext) -> DetectionResult<HostInfo> {
     use crate::error::Error;
     DetectionResult::Error(Error::UnsupportedPlatform)
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn test_host_from_smbios() {
+        let thinkpad = host_from_smbios(
+            some("LENOVO"),
+            some("20XW0055US"),
+            some("ThinkPad X1 Carbon Gen 9"),
+        )
+        .unwrap();
+        assert_eq!(thinkpad.name, "ThinkPad X1 Carbon Gen 9");
+        assert_eq!(
+            thinkpad.to_string(),
+            "LENOVO ThinkPad X1 Carbon Gen 9 (20XW0055US)"
+        );
+
+        let xps = host_from_smbios(some("Dell Inc."), some("XPS 13 9310"), None).unwrap();
+        assert_eq!(xps.to_string(), "Dell Inc. XPS 13 9310");
+
+        let framework = host_from_smbios(
+            some("Framework"),
+            some("Framework Laptop 13 (AMD Ryzen 7040Series)"),
+            some("A7"),
+        )
+        .unwrap();
+        assert_eq!(
+            framework.to_string(),
+            "Framework Laptop 13 (AMD Ryzen 7040Series) (A7)"
+        );
+
+        assert_eq!(host_from_smbios(some("QEMU"), None, None), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Icon theme detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Icon theme detection module
+#[derive(Debug)]
+pub struct IconsModule;
+
+/// Icon themes configured for each toolkit
+#[derive(Debug, Clone, Serialize)]
+pub struct IconsInfo {
+    pub gtk: Option<String>,
+    pub qt: Option<String>,
+}
+
+impl fmt::Display for IconsInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.gtk, &self.qt) {
+            (Some(gtk), Some(qt)) if gtk == qt => write!(f, "{gtk} [GTK/Qt]"),
+            (Some(gtk), Some(qt)) => write!(f, "{gtk} [GTK], {qt} [Qt]"),
+            (Some(gtk), None) => write!(f, "{gtk} [GTK]"),
+            (None, Some(qt)) => write!(f, "{qt} [Qt]"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Module for IconsModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_icons(ctx).map(ModuleInfo::Icons)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Icons
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Desktop icon theme", &["linux", "freebsd"])
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_icons(ctx: &dyn SystemContext) -> DetectionResult<IconsInfo> {
+    use crate::platform::desktop;
+
+    let gtk = desktop::gtk_setting(ctx, "gtk-icon-theme-name")
+        .or_else(|| desktop::gsettings_get(ctx, "org.gnome.desktop.interface", "icon-theme"));
+    let qt = desktop::kde_setting(ctx, "Icons", "Theme")
+        .or_else(|| desktop::qtct_setting(ctx, "Appearance", "icon_theme"));
+
+    if gtk.is_none() && qt.is_none() {
+        return DetectionResult::Unavailable;
+    }
+    DetectionResult::Detected(IconsInfo { gtk, qt })
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn detect_icons(_ctx: &dyn SystemContext) -> DetectionResult<IconsInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,139 @@
+//! Init system detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Init system detection module
+#[derive(Debug)]
+pub struct InitSystemModule;
+
+/// Init system information
+#[derive(Debug, Clone, Serialize)]
+pub struct InitSystemInfo {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl fmt::Display for InitSystemInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(version) = &self.version {
+            write!(f, " {version}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for InitSystemModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_init_system(ctx).map(ModuleInfo::InitSystem)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::InitSystem
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Init system and its version", &["linux", "macos"])
+    }
+}
+
+/// Program and arguments that print an init system's version
+#[cfg(target_os = "linux")]
+type VersionCommand = (&'static str, &'static [&'static str]);
+
+/// Known PID 1 process names: (comm, display name, version command)
+#[cfg(target_os = "linux")]
+const INIT_SYSTEMS: &[(&str, &str, Option<VersionCommand>)] = &[
+    ("systemd", "systemd", Some(("systemctl", &["--version"]))),
+    ("runit", "runit", None),
+    ("runit-init", "runit", None),
+    ("s6-svscan", "s6", None),
+    ("dinit", "dinit", Some(("dinit", &["--version"]))),
+    ("shepherd", "GNU Shepherd", Some(("herd", &["--version"]))),
+];
+
+#[cfg(target_os = "linux")]
+fn detect_init_system(ctx: &dyn SystemContext) -> DetectionResult<InitSystemInfo> {
+    use std::path::Path;
+
+    let comm = match ctx.read_file(Path::new("/proc/1/comm")) {
+        Ok(comm) => comm.trim().to_string(),
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    let (name, version_command) = match INIT_SYSTEMS.iter().find(|(c, ..)| *c == comm) {
+        Some(&(_, name, version_command)) => (name.to_string(), version_command),
+        // OpenRC runs on top of sysvinit's /sbin/init but leaves its state in /run/openrc
+        None if comm == "init" && ctx.read_dir(Path::new("/run/openrc")).is_ok() => {
+            ("OpenRC".to_string(), Some(("openrc", &["--version"][..])))
+        }
+        None if comm == "init" => ("SysVinit".to_string(), None),
+        None => (comm, None),
+    };
+
+    let version = version_command.and_then(|(program, args)| command_version(ctx, program, args));
+
+    DetectionResult::Detected(InitSystemInfo { name, version })
+}
+
+#[cfg(target_os = "macos")]
+fn detect_init_system(ctx: &dyn SystemContext) -> DetectionResult<InitSystemInfo> {
+    // "Darwin System Bootstrapper Version 7.0.0: Tue Sep 24 ..."
+    DetectionResult::Detected(InitSystemInfo {
+        name: "launchd".to_string(),
+        version: command_version(ctx, "launchctl", &["version"]),
+    })
+}
+
+/// Run a version command and pick the first version-looking token from its output
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn command_version(ctx: &dyn SystemContext, program: &str, args: &[&str]) -> Option<String> {
+    let output = ctx.execute_command(program, args).ok()?;
+    if !output.success {
+        return None;
+    }
+    parse_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract "252" from "systemd 252 (252.38-1)" or "0.54" from "openrc (OpenRC) 0.54 (Gentoo Linux)"
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn parse_version(output: &str) -> Option<String> {
+    output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .find(|token| token.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|token| token.trim_end_matches(['.', ',', ':']).to_string())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_init_system(_ctx: &dyn SystemContext) -> DetectionResult<InitSystemInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "macos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("systemd 252 (252.38-1~deb12u1)\n+PAM +AUDIT").as_deref(),
+            Some("252")
+        );
+        assert_eq!(
+            parse_version("openrc (OpenRC) 0.54 (Gentoo Linux)").as_deref(),
+            Some("0.54")
+        );
+        assert_eq!(
+            parse_version("Dinit version 0.17.0.").as_deref(),
+            Some("0.17.0")
+        );
+        assert_eq!(parse_version("runit"), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Kernel information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
 use std::fmt;
 
 /// Kernel detection module
@@ -8,10 +11,33 @@ use std::fmt;
 pub struct KernelModule;
 
 /// Kernel information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct KernelInfo {
     pub name: String,
+    /// Kernel release, e.g. "6.9.1-zen1-1-zen"
     pub version: String,
+    /// Machine architecture, e.g. "x86_64"
+    pub arch: Option<String>,
+    /// Build variant taken from the release suffix, e.g. "zen", "lts" or "generic"
+    pub flavor: Option<String>,
+    pub build_date: Option<String>,
+    /// Compiler the kernel was built with, e.g. "gcc (GCC) 13.2.1 20230801"
+    pub compiler: Option<String>,
+}
+
+impl KernelInfo {
+    /// Template field: `name`, `version`, `arch`, `flavor`, `build_date` or `compiler`
+    pub fn field(&self, name: &str) -> Option<String> {
+        match name {
+            "name" => Some(self.name.clone()),
+            "version" => Some(self.version.clone()),
+            "arch" => self.arch.clone(),
+            "flavor" => self.flavor.clone(),
+            "build_date" => self.build_date.clone(),
+            "compiler" => self.compiler.clone(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for KernelInfo {
@@ -28,24 +54,67 @@ impl Module for KernelModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Kernel
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Kernel name and release", ModuleMetadata::ALL).with_fields(&[
+            ("name", "Kernel name, e.g. Linux"),
+            ("version", "Release"),
+            ("arch", "Machine architecture"),
+            ("flavor", "Build flavor, e.g. zen"),
+            ("build_date", "When the kernel was built"),
+            ("compiler", "Compiler the kernel was built with"),
+        ])
+    }
 }
 
-#[cfg(unix)]
+#[cfg(target_os = "linux")]
+fn detect_kernel(ctx: &dyn SystemContext) -> DetectionResult<KernelInfo> {
+    use std::path::Path;
+
+    let utsname = match ctx.uname() {
+        Ok(utsname) => utsname,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+
+    // "Linux version 6.1.0-arch1-1 (linux@archlinux) (gcc (GCC) 12.2.1, GNU ld 2.40) #1 SMP ..."
+    let proc_version = ctx.read_file(Path::new("/proc/version")).ok();
+    let compiler = proc_version.as_deref().and_then(parse_compiler);
+    let build_date = parse_build_date(proc_version.as_deref().unwrap_or(&utsname.version));
+
+    DetectionResult::Detected(KernelInfo {
+        flavor: parse_flavor(&utsname.release),
+        name: utsname.sysname,
+        version: utsname.release,
+        arch: Some(utsname.machine).filter(|arch| !arch.is_empty()),
+        build_date,
+        compiler,
+    })
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
 fn detect_kernel(ctx: &dyn SystemContext) -> DetectionResult<KernelInfo> {
     match ctx.uname() {
         Ok(utsname) => DetectionResult::Detected(KernelInfo {
+            build_date: parse_build_date(&utsname.version),
             name: utsname.sysname,
             version: utsname.release,
+            arch: Some(utsname.machine).filter(|arch| !arch.is_empty()),
+            flavor: None,
+            compiler: None,
         }),
         Err(_) => DetectionResult::Unavailable,
     }
 }
 
 #[cfg(target_os = "windows")]
-fn detect_kernel(_ctx: &dyn SystemContext) -> DetectionResult<KernelInfo> {
+fn detect_kernel(ctx: &dyn SystemContext) -> DetectionResult<KernelInfo> {
     DetectionResult::Detected(KernelInfo {
         name: "Windows NT".to_string(),
         version: "Unknown".to_string(),
+        arch: ctx.get_env("PROCESSOR_ARCHITECTURE"),
+        flavor: None,
+        build_date: None,
+        compiler: None,
     })
 }
 
@@ -54,3 +123,154 @@ fn detect_kernel(_ctx: &dyn SystemContext) -> DetectionResult<KernelInfo> {
     use crate::error::Error;
     DetectionResult::Error(Error::UnsupportedPlatform)
 }
+
+/// Release suffixes that name the architecture rather than a build flavor
+#[cfg(target_os = "linux")]
+const ARCH_SUFFIXES: &[&str] = &["amd64", "arm64", "armmp", "x86_64", "aarch64", "686", "pae"];
+
+/// Flavor from the last dash-separated part of a release, e.g. "zen" from
+/// "6.9.1-zen1-1-zen", "lts" from "6.6.30-1-lts" or "generic" from "6.8.0-35-generic"
+#[cfg(target_os = "linux")]
+fn parse_flavor(release: &str) -> Option<String> {
+    let (_, suffix) = release.rsplit_once('-')?;
+
+    // Numeric package revisions, dotted distro tags ("300.fc40.x86_64") and release candidates
+    if !suffix.starts_with(|c: char| c.is_ascii_alphabetic())
+        || suffix.contains('.')
+        || ARCH_SUFFIXES.contains(&suffix)
+        || suffix
+            .strip_prefix("rc")
+            .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    // "zen1" -> "zen"; a lone letter left over was a version like "v130"
+    let flavor = suffix.trim_end_matches(|c: char| c.is_ascii_digit());
+    (flavor.len() > 1).then(|| flavor.to_string())
+}
+
+/// Compiler from the second top-level parenthesized group of `/proc/version`, up to the linker
+#[cfg(target_os = "linux")]
+fn parse_compiler(proc_version: &str) -> Option<String> {
+    let mut groups = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in proc_version.char_indices() {
+        match c {
+            '(' => {
+                if depth == 0 {
+                    start = i + 1;
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    groups.push(&proc_version[start..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The first group is the "builder@host" that compiled it
+    let compiler = groups.get(1)?.split(", ").next()?.trim();
+    (!compiler.is_empty()).then(|| compiler.to_string())
+}
+
+/// Build timestamp from a kernel version string, starting at the weekday,
+/// e.g. "Sat, 04 Feb 2023 12:34:56 +0000" from "#1 SMP PREEMPT_DYNAMIC Sat, 04 Feb 2023 ..."
+#[cfg(unix)]
+fn parse_build_date(version: &str) -> Option<String> {
+    const WEEKDAYS: &[&str] = &["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+    let start = version.char_indices().map(|(i, _)| i).find(|&i| {
+        (i == 0 || version[..i].ends_with(' '))
+            && WEEKDAYS.iter().any(|day| version[i..].starts_with(day))
+    })?;
+
+    // Darwin follows the date with "; root:xnu-..."
+    let date = version[start..].split(';').next()?.trim();
+    (!date.is_empty()).then(|| date.to_string())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    const PROC_VERSION: &str = "Linux version 6.1.0-arch1-1 (linux@archlinux) \
+        (gcc (GCC) 12.2.1 20230201, GNU ld (GNU Binutils) 2.40) \
+        #1 SMP PREEMPT_DYNAMIC Sat, 04 Feb 2023 12:34:56 +0000\n";
+
+    fn info() -> KernelInfo {
+        KernelInfo {
+            name: "Linux".to_string(),
+            version: "6.9.1-zen1-1-zen".to_string(),
+            arch: Some("x86_64".to_string()),
+            flavor: Some("zen".to_string()),
+            build_date: None,
+            compiler: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_flavor() {
+        assert_eq!(parse_flavor("6.9.1-zen1-1-zen").as_deref(), Some("zen"));
+        assert_eq!(parse_flavor("6.6.30-1-lts").as_deref(), Some("lts"));
+        assert_eq!(parse_flavor("6.1.0-arch1-1"), None);
+        assert_eq!(parse_flavor("6.8.0-35-generic").as_deref(), Some("generic"));
+        assert_eq!(parse_flavor("6.1.0-21-amd64"), None);
+        assert_eq!(parse_flavor("6.8.9-300.fc40.x86_64"), None);
+        assert_eq!(parse_flavor("6.10.0-rc1"), None);
+        assert_eq!(parse_flavor("6.18.44-fc-v130"), None);
+        assert_eq!(parse_flavor("6.5.0-mykernel").as_deref(), Some("mykernel"));
+    }
+
+    #[test]
+    fn test_parse_proc_version() {
+        assert_eq!(
+            parse_compiler(PROC_VERSION).as_deref(),
+            Some("gcc (GCC) 12.2.1 20230201")
+        );
+        assert_eq!(
+            parse_build_date(PROC_VERSION).as_deref(),
+            Some("Sat, 04 Feb 2023 12:34:56 +0000")
+        );
+        assert_eq!(parse_compiler("Linux version 6.1.0"), None);
+    }
+
+    #[test]
+    fn test_template_fields() {
+        use crate::output::Template;
+
+        let info = info();
+        assert_eq!(info.to_string(), "Linux 6.9.1-zen1-1-zen");
+
+        let template: Template = "{name} {version}{?arch} ({arch}){?}{?compiler}, {compiler}{?}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            template.render(&|name| info.field(name), false),
+            "Linux 6.9.1-zen1-1-zen (x86_64)"
+        );
+    }
+
+    #[test]
+    fn test_version_with_brackets() {
+        use crate::output::Template;
+
+        let info = KernelInfo {
+            version: "6.1.0-custom (test) [] ()".to_string(),
+            arch: None,
+            ..info()
+        };
+        assert_eq!(info.to_string(), "Linux 6.1.0-custom (test) [] ()");
+
+        let template: Template = "{version}{?arch} ({arch}){?}".parse().unwrap();
+        assert_eq!(
+            template.render(&|name| info.field(name), false),
+            "6.1.0-custom (test) [] ()"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Loaded kernel modules detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Kernel modules detection module
+#[derive(Debug, Default)]
+pub struct KernelModulesModule {
+    show_tainted: bool,
+}
+
+impl KernelModulesModule {
+    /// Create a module, optionally naming taint-causing modules
+    pub const fn new(show_tainted: bool) -> Self {
+        Self { show_tainted }
+    }
+}
+
+/// Loaded kernel modules
+#[derive(Debug, Clone, Serialize)]
+pub struct KernelModulesInfo {
+    pub count: usize,
+    /// Modules that taint the kernel with their flags, only filled when requested
+    pub tainted: Vec<(String, String)>,
+}
+
+impl fmt::Display for KernelModulesInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} loaded", self.count)?;
+        if !self.tainted.is_empty() {
+            let tainted: Vec<String> = self
+                .tainted
+                .iter()
+                .map(|(name, flags)| format!("{name} [{flags}]"))
+                .collect();
+            write!(f, " (tainted: {})", tainted.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for KernelModulesModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_kernel_modules(ctx, self.show_tainted).map(ModuleInfo::KernelModules)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::KernelModules
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Number of loaded kernel modules", ModuleMetadata::LINUX).with_options(
+            &[(
+                "show_tainted",
+                "Also list the modules tainting the kernel, false by default",
+            )],
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_kernel_modules(
+    ctx: &dyn SystemContext,
+    show_tainted: bool,
+) -> DetectionResult<KernelModulesInfo> {
+    use crate::platform::linux::proc::parse_modules_content;
+    use std::path::Path;
+
+    let modules = match ctx.read_file(Path::new("/proc/modules")) {
+        Ok(content) => parse_modules_content(&content),
+        // Kernels built without module support have no /proc/modules
+        Err(_) => return DetectionResult::Unavailable,
+    };
+
+    let tainted = if show_tainted {
+        modules
+            .iter()
+            .filter(|module| !module.taints.is_empty())
+            .map(|module| (module.name.clone(), module.taints.iter().collect()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    DetectionResult::Detected(KernelModulesInfo {
+        count: modules.len(),
+        tainted,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_kernel_modules(
+    _ctx: &dyn SystemContext,
+    _show_tainted: bool,
+) -> DetectionResult<KernelModulesInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,115 @@
+//! Keyboard device detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Keyboard detection module
+#[derive(Debug)]
+pub struct KeyboardModule;
+
+/// How a keyboard is attached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyboardConnection {
+    Usb,
+    Bluetooth,
+    /// Laptop keyboards on the PS/2 controller or I2C
+    Builtin,
+}
+
+impl fmt::Display for KeyboardConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usb => write!(f, "USB"),
+            Self::Bluetooth => write!(f, "Bluetooth"),
+            Self::Builtin => write!(f, "Built-in"),
+        }
+    }
+}
+
+/// An attached keyboard
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyboardDevice {
+    pub name: String,
+    pub connection: Option<KeyboardConnection>,
+}
+
+/// Keyboard devices information
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyboardInfo {
+    pub devices: Vec<KeyboardDevice>,
+}
+
+impl fmt::Display for KeyboardInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, device) in self.devices.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", device.name)?;
+            if let Some(connection) = device.connection {
+                write!(f, " ({connection})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Module for KeyboardModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_keyboard(ctx).map(ModuleInfo::Keyboard)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Keyboard
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Attached keyboards", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_keyboard(ctx: &dyn SystemContext) -> DetectionResult<KeyboardInfo> {
+    use crate::platform::linux::input::{self, InputBus};
+
+    let devices = match input::input_devices(ctx) {
+        Ok(devices) => devices,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    let mut keyboards: Vec<KeyboardDevice> = Vec::new();
+    for device in devices.iter().filter(|device| device.is_keyboard()) {
+        let connection = match device.bus {
+            InputBus::Usb => Some(KeyboardConnection::Usb),
+            InputBus::Bluetooth => Some(KeyboardConnection::Bluetooth),
+            InputBus::Ps2 | InputBus::I2c => Some(KeyboardConnection::Builtin),
+            InputBus::Virtual => continue,
+            InputBus::Other(_) => None,
+        };
+
+        // USB keyboards often register extra interfaces under the same name
+        if keyboards.iter().any(|k| k.name == device.name) {
+            continue;
+        }
+        keyboards.push(KeyboardDevice {
+            name: device.name.clone(),
+            connection,
+        });
+    }
+
+    if keyboards.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(KeyboardInfo { devices: keyboards })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_keyboard(_ctx: &dyn SystemContext) -> DetectionResult<KeyboardInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,80 @@
+//! Load average detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Load average detection module
+#[derive(Debug)]
+pub struct LoadAvgModule;
+
+/// System load averages over 1, 5, and 15 minutes
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadAvgInfo {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+impl fmt::Display for LoadAvgInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} {:.2} {:.2}", self.one, self.five, self.fifteen)
+    }
+}
+
+impl Module for LoadAvgModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_loadavg(ctx).map(ModuleInfo::LoadAvg)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::LoadAvg
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Load averages over 1, 5 and 15 minutes",
+            ModuleMetadata::UNIX,
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_loadavg(ctx: &dyn SystemContext) -> DetectionResult<LoadAvgInfo> {
+    use crate::platform::linux::proc::parse_loadavg_content;
+    use std::path::Path;
+
+    let content = match ctx.read_file(Path::new("/proc/loadavg")) {
+        Ok(content) => content,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    match parse_loadavg_content(&content) {
+        Ok((one, five, fifteen)) => DetectionResult::Detected(LoadAvgInfo { one, five, fifteen }),
+        Err(err) => DetectionResult::Error(err.into()),
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn detect_loadavg(_ctx: &dyn SystemContext) -> DetectionResult<LoadAvgInfo> {
+    let mut loads = [0f64; 3];
+    let count = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+
+    if count == 3 {
+        DetectionResult::Detected(LoadAvgInfo {
+            one: loads[0],
+            five: loads[1],
+            fifteen: loads[2],
+        })
+    } else {
+        DetectionResult::Unavailable
+    }
+}
+
+#[cfg(not(unix))]
+fn detect_loadavg(_ctx: &dyn SystemContext) -> DetectionResult<LoadAvgInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,66 @@
+//! Locale information detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/// Locale detection module
+#[derive(Debug)]
+pub struct LocaleModule;
+
+/// Locale information
+#[derive(Debug, Clone, Serialize)]
+pub struct LocaleInfo {
+    pub locale: String,
+}
+
+impl fmt::Display for LocaleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.locale)
+    }
+}
+
+impl Module for LocaleModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_locale(ctx).map(ModuleInfo::Locale)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Locale
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("System locale", ModuleMetadata::ALL)
+    }
+}
+
+fn detect_locale(ctx: &dyn SystemContext) -> DetectionResult<LocaleInfo> {
+    // Same precedence the C library uses: LC_ALL overrides LANG
+    let from_env = ["LC_ALL", "LANG"]
+        .iter()
+        .filter_map(|var| ctx.get_env(var))
+        .find(|value| !value.is_empty());
+
+    match from_env.or_else(|| locale_from_conf(ctx)) {
+        Some(locale) => DetectionResult::Detected(LocaleInfo { locale }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Read `LANG=` from the system-wide locale configuration
+fn locale_from_conf(ctx: &dyn SystemContext) -> Option<String> {
+    let content = ctx
+        .read_file(Path::new("/etc/locale.conf"))
+        .or_else(|_| ctx.read_file(Path::new("/etc/default/locale")))
+        .ok()?;
+
+    content.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("LANG=")
+            .map(|value| value.trim_matches('"').to_string())
+            .filter(|value| !value.is_empty())
+    })
+}
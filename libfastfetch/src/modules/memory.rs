@@ -1,6 +1,10 @@
 //! Memory information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    context::SystemContext, util::format_bytes, DetectionResult, Module, ModuleInfo, ModuleKind,
+    ModuleMetadata,
+};
+use serde::Serialize;
 use std::fmt;
 use std::path::Path;
 
@@ -9,7 +13,7 @@ use std::path::Path;
 pub struct MemoryModule;
 
 /// Memory information (in bytes)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MemoryInfo {
     pub total: u64,
     pub used: u64,
@@ -21,18 +25,9 @@ impl MemoryInfo {
         self.total.saturating_sub(self.used)
     }
 
-    /// Format bytes as human-readable string
-    fn format_bytes(bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB"];
-        let mut size = bytes as f64;
-        let mut unit_idx = 0;
-
-        while size >= 1024.0 && unit_idx < UNITS.len() - 1 {
-            size /= 1024.0;
-            unit_idx += 1;
-        }
-
-        format!("{size:.2} {}", UNITS[unit_idx])
+    /// Share of memory in use, in percent
+    pub fn percent(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.used as f64 / self.total as f64 * 100.0)
     }
 }
 
@@ -41,8 +36,8 @@ impl fmt::Display for MemoryInfo {
         write!(
             f,
             "{} / {}",
-            Self::format_bytes(self.used),
-            Self::format_bytes(self.total)
+            format_bytes(self.used),
+            format_bytes(self.total)
         )
     }
 }
@@ -55,6 +50,11 @@ impl Module for MemoryModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Memory
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Used and total memory", ModuleMetadata::ALL)
+            .with_options(ModuleMetadata::CAPACITY_OPTIONS)
+    }
 }
 
 #[cfg(target_os = "linux")]
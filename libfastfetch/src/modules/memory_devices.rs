@@ -0,0 +1,307 @@
+//! Installed memory module (DIMM) detection module
+//!
+//! SMBIOS type 17 records are only readable by root, so without privileges
+//! this module reports nothing rather than failing.
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Memory devices detection module
+#[derive(Debug)]
+pub struct MemoryDevicesModule;
+
+/// An installed RAM module
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemoryDevice {
+    /// Slot label, e.g. "DIMM_A1"
+    pub slot: String,
+    pub size_bytes: u64,
+    /// Memory technology, e.g. "DDR5"
+    pub memory_type: Option<String>,
+    /// Rated speed in MT/s
+    pub speed: Option<u32>,
+    pub manufacturer: Option<String>,
+}
+
+impl fmt::Display for MemoryDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.slot,
+            crate::util::format_bytes(self.size_bytes)
+        )?;
+        if let Some(memory_type) = &self.memory_type {
+            write!(f, " {memory_type}")?;
+        }
+        if let Some(speed) = self.speed {
+            write!(f, " {speed} MT/s")?;
+        }
+        if let Some(manufacturer) = &self.manufacturer {
+            write!(f, " ({manufacturer})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Installed memory modules
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryDevicesInfo {
+    pub devices: Vec<MemoryDevice>,
+}
+
+impl fmt::Display for MemoryDevicesInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, device) in self.devices.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{device}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for MemoryDevicesModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_memory_devices(ctx).map(ModuleInfo::MemoryDevices)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::MemoryDevices
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Installed memory modules", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_memory_devices(ctx: &dyn SystemContext) -> DetectionResult<MemoryDevicesInfo> {
+    let devices = sysfs_memory_devices(ctx)
+        .filter(|devices| !devices.is_empty())
+        .or_else(|| dmidecode_memory_devices(ctx))
+        .unwrap_or_default();
+
+    if devices.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(MemoryDevicesInfo { devices })
+    }
+}
+
+/// Decode the raw type 17 records the kernel exports under /sys/firmware/dmi/entries
+#[cfg(target_os = "linux")]
+fn sysfs_memory_devices(ctx: &dyn SystemContext) -> Option<Vec<MemoryDevice>> {
+    use std::path::Path;
+
+    let dir = Path::new("/sys/firmware/dmi/entries");
+    let mut entries: Vec<String> = ctx
+        .read_dir(dir)
+        .ok()?
+        .into_iter()
+        .filter(|entry| entry.starts_with("17-"))
+        .collect();
+    entries.sort_by_key(|entry| entry[3..].parse::<u32>().unwrap_or(u32::MAX));
+
+    entries
+        .iter()
+        .map(|entry| {
+            ctx.read_bytes(&dir.join(entry).join("raw"))
+                .ok()
+                .map(|raw| parse_smbios_memory_device(&raw))
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|devices| devices.into_iter().flatten().collect())
+}
+
+/// Parse an SMBIOS type 17 (Memory Device) structure; empty slots yield `None`
+#[cfg(target_os = "linux")]
+fn parse_smbios_memory_device(raw: &[u8]) -> Option<MemoryDevice> {
+    let length = usize::from(*raw.get(1)?);
+    let formatted = raw.get(..length)?;
+    let word = |offset: usize| {
+        formatted
+            .get(offset..offset + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    };
+
+    // Strings follow the formatted area, NUL-terminated and numbered from 1
+    let strings: Vec<&[u8]> = raw[length..]
+        .split(|&b| b == 0)
+        .take_while(|s| !s.is_empty())
+        .collect();
+    let string = |offset: usize| {
+        let index = usize::from(*formatted.get(offset)?);
+        let value = String::from_utf8_lossy(strings.get(index.checked_sub(1)?)?)
+            .trim()
+            .to_string();
+        (!crate::platform::is_smbios_placeholder(&value)).then_some(value)
+    };
+
+    let size_bytes = match word(0x0C)? {
+        0 | 0xFFFF => return None,
+        // Extended size field, in MiB
+        0x7FFF => {
+            u64::from(u32::from_le_bytes(
+                formatted.get(0x1C..0x20)?.try_into().ok()?,
+            )) << 20
+        }
+        size if size & 0x8000 != 0 => u64::from(size & 0x7FFF) << 10,
+        size => u64::from(size) << 20,
+    };
+
+    Some(MemoryDevice {
+        slot: string(0x10).unwrap_or_else(|| "Unknown".to_string()),
+        size_bytes,
+        memory_type: formatted
+            .get(0x12)
+            .and_then(|&t| smbios_memory_type(t))
+            .map(str::to_string),
+        speed: word(0x15).filter(|&speed| speed != 0).map(u32::from),
+        manufacturer: string(0x17),
+    })
+}
+
+/// SMBIOS memory type codes
+#[cfg(target_os = "linux")]
+fn smbios_memory_type(code: u8) -> Option<&'static str> {
+    let name = match code {
+        0x12 => "DDR",
+        0x13 => "DDR2",
+        0x18 => "DDR3",
+        0x1A => "DDR4",
+        0x1B => "LPDDR",
+        0x1C => "LPDDR2",
+        0x1D => "LPDDR3",
+        0x1E => "LPDDR4",
+        0x22 => "DDR5",
+        0x23 => "LPDDR5",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Fall back to `dmidecode`, which itself only succeeds as root
+#[cfg(target_os = "linux")]
+fn dmidecode_memory_devices(ctx: &dyn SystemContext) -> Option<Vec<MemoryDevice>> {
+    let output = ctx.execute_command("dmidecode", &["--type", "17"]).ok()?;
+    if !output.success {
+        return None;
+    }
+    Some(parse_dmidecode(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse the "Memory Device" sections of `dmidecode --type 17`
+#[cfg(target_os = "linux")]
+fn parse_dmidecode(output: &str) -> Vec<MemoryDevice> {
+    output
+        .split("Memory Device")
+        .skip(1)
+        .filter_map(|section| {
+            let field = |key: &str| {
+                section.lines().find_map(|line| {
+                    let (k, v) = line.trim().split_once(": ")?;
+                    let v = v.trim();
+                    (k == key && !crate::platform::is_smbios_placeholder(v) && v != "Unknown")
+                        .then(|| v.to_string())
+                })
+            };
+
+            let size_bytes = parse_dmidecode_size(&field("Size")?)?;
+            Some(MemoryDevice {
+                slot: field("Locator").unwrap_or_else(|| "Unknown".to_string()),
+                size_bytes,
+                memory_type: field("Type"),
+                speed: field("Speed")
+                    .and_then(|speed| speed.split_whitespace().next()?.parse().ok()),
+                manufacturer: field("Manufacturer"),
+            })
+        })
+        .collect()
+}
+
+/// Parse "16 GB" or "8192 MB"; empty slots read "No Module Installed"
+#[cfg(target_os = "linux")]
+fn parse_dmidecode_size(size: &str) -> Option<u64> {
+    let (value, unit) = size.split_once(' ')?;
+    let value: u64 = value.parse().ok()?;
+    let shift = match unit {
+        "kB" | "KB" => 10,
+        "MB" => 20,
+        "GB" => 30,
+        "TB" => 40,
+        _ => return None,
+    };
+    Some(value << shift)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_memory_devices(_ctx: &dyn SystemContext) -> DetectionResult<MemoryDevicesInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dmidecode() {
+        let output = "\
+# dmidecode 3.5
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+\tTotal Width: 64 bits
+\tSize: 16 GB
+\tLocator: DIMM_A1
+\tType: DDR5
+\tSpeed: 4800 MT/s
+\tManufacturer: Samsung
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tSize: No Module Installed
+\tLocator: DIMM_A2
+\tType: Unknown
+";
+        let devices = parse_dmidecode(output);
+        assert_eq!(
+            devices,
+            [MemoryDevice {
+                slot: "DIMM_A1".to_string(),
+                size_bytes: 16 << 30,
+                memory_type: Some("DDR5".to_string()),
+                speed: Some(4800),
+                manufacturer: Some("Samsung".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_smbios_memory_device() {
+        let mut raw = vec![0u8; 0x28];
+        raw[0] = 17;
+        raw[1] = 0x28;
+        raw[0x0C..0x0E].copy_from_slice(&8192u16.to_le_bytes());
+        raw[0x10] = 1;
+        raw[0x12] = 0x1A;
+        raw[0x15..0x17].copy_from_slice(&3200u16.to_le_bytes());
+        raw[0x17] = 2;
+        raw.extend_from_slice(b"ChannelA-DIMM0\0Micron\0\0");
+
+        let device = parse_smbios_memory_device(&raw).unwrap();
+        assert_eq!(device.slot, "ChannelA-DIMM0");
+        assert_eq!(device.size_bytes, 8 << 30);
+        assert_eq!(device.memory_type.as_deref(), Some("DDR4"));
+        assert_eq!(device.speed, Some(3200));
+        assert_eq!(device.manufacturer.as_deref(), Some("Micron"));
+
+        // Empty slot
+        raw[0x0C..0x0E].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(parse_smbios_memory_device(&raw), None);
+    }
+}
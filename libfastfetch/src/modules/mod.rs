@@ -3,15 +3,69 @@
 //! This module provides the core trait and enum dispatch system for
 //! detecting various system information.
 
+pub mod battery;
+pub mod bios;
+pub mod bluetooth;
+pub mod board;
+pub mod bootloader;
+pub mod brightness;
+pub mod camera;
+pub mod container;
 pub mod cpu;
+pub mod cpu_temp;
+pub mod custom;
+pub mod disk_io;
+pub mod dns;
+pub mod font;
+pub mod gpu_driver;
 pub mod host;
+pub mod icons;
+pub mod init_system;
 pub mod kernel;
+pub mod kernel_modules;
+pub mod keyboard;
+pub mod loadavg;
+pub mod locale;
 pub mod memory;
+pub mod memory_devices;
+pub mod monitor;
+pub mod mouse;
+pub mod net_io;
+pub mod options;
 pub mod os;
+pub mod packages;
+pub mod pci;
+pub mod physical_disk;
+pub mod player;
+pub mod power_profile;
+pub mod processes;
+pub mod sampler;
+pub mod secure_boot;
+pub mod security;
+pub mod separator;
 pub mod shell;
+pub mod sound;
+pub mod swap;
+pub mod terminal;
+pub mod terminal_font;
+pub mod text;
+pub mod theme;
+pub mod title;
+pub mod tpm;
 pub mod uptime;
+pub mod usb;
+pub mod users;
+pub mod version;
+pub mod virtualization;
+pub mod vpn;
+pub mod wm;
+pub mod wm_theme;
+pub mod zram;
+
+pub use options::{ModuleOptions, ModuleOverrides, TemperatureUnit};
 
 use crate::{context::SystemContext, DetectionResult};
+use serde::Serialize;
 use std::{fmt, str::FromStr};
 
 /// Module trait for all detection modules
@@ -31,11 +85,68 @@ pub trait Module: Send + Sync {
     fn name(&self) -> &'static str {
         self.kind().name()
     }
+
+    /// Describe the module for `--help-module`
+    fn metadata(&self) -> ModuleMetadata;
+}
+
+/// What a module shows and how it can be configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleMetadata {
+    /// One sentence on what the module shows
+    pub description: &'static str,
+    /// Operating systems the module detects anything on, named as in `if_os`
+    pub platforms: &'static [&'static str],
+    /// Keys a `"modules"` entry of this module accepts besides `label`,
+    /// `format` and the colors, with what they do
+    pub options: &'static [(&'static str, &'static str)],
+    /// Template fields besides `value`, with what they hold
+    pub fields: &'static [(&'static str, &'static str)],
+}
+
+impl ModuleMetadata {
+    /// Every supported operating system
+    pub const ALL: &'static [&'static str] = &["linux", "macos", "freebsd", "windows"];
+    /// Every supported Unix
+    pub const UNIX: &'static [&'static str] = &["linux", "macos", "freebsd"];
+    pub const LINUX: &'static [&'static str] = &["linux"];
+    /// Options of modules that report how full they are
+    pub const CAPACITY_OPTIONS: &'static [(&'static str, &'static str)] = &[
+        (
+            "bar",
+            "\"on\" for a percentage bar instead of the value, \"prefix\" for one in front of it",
+        ),
+        (
+            "thresholds",
+            "Value colors keyed by the percentage they start at, e.g. { \"80\": \"red\" }",
+        ),
+    ];
+
+    /// Metadata of a module without options or extra fields
+    pub const fn new(description: &'static str, platforms: &'static [&'static str]) -> Self {
+        Self {
+            description,
+            platforms,
+            options: &[],
+            fields: &[],
+        }
+    }
+
+    pub const fn with_options(mut self, options: &'static [(&'static str, &'static str)]) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub const fn with_fields(mut self, fields: &'static [(&'static str, &'static str)]) -> Self {
+        self.fields = fields;
+        self
+    }
 }
 
 /// Enum representing all available module types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleKind {
+    Title,
     Os,
     Host,
     Kernel,
@@ -43,6 +154,55 @@ pub enum ModuleKind {
     Shell,
     Cpu,
     Memory,
+    Wm,
+    Terminal,
+    TerminalFont,
+    Packages,
+    Locale,
+    Swap,
+    Processes,
+    Users,
+    LoadAvg,
+    CpuTemp,
+    Bluetooth,
+    Sound,
+    Player,
+    Board,
+    Bios,
+    InitSystem,
+    Bootloader,
+    GpuDriver,
+    Icons,
+    Theme,
+    Font,
+    Monitor,
+    Brightness,
+    Camera,
+    Mouse,
+    Keyboard,
+    PhysicalDisk,
+    DiskIo,
+    NetIo,
+    Dns,
+    Vpn,
+    Container,
+    Virtualization,
+    KernelModules,
+    Security,
+    Tpm,
+    SecureBoot,
+    PowerProfile,
+    Zram,
+    MemoryDevices,
+    Pci,
+    Usb,
+    Separator,
+    Break,
+    Custom,
+    Text,
+    Version,
+    Battery,
+    WmTheme,
 }
 
 impl ModuleKind {
@@ -56,12 +216,126 @@ impl ModuleKind {
             Self::Shell => "Shell",
             Self::Cpu => "CPU",
             Self::Memory => "Memory",
+            Self::Wm => "WM",
+            Self::Terminal => "Terminal",
+            Self::TerminalFont => "Terminal Font",
+            Self::Packages => "Packages",
+            Self::Locale => "Locale",
+            Self::Swap => "Swap",
+            Self::Processes => "Processes",
+            Self::Users => "Users",
+            Self::LoadAvg => "Load Average",
+            Self::CpuTemp => "CPU Temp",
+            Self::Bluetooth => "Bluetooth",
+            Self::Sound => "Sound",
+            Self::Player => "Player",
+            Self::Board => "Board",
+            Self::Bios => "BIOS",
+            Self::InitSystem => "Init System",
+            Self::Bootloader => "Bootloader",
+            Self::GpuDriver => "GPU Driver",
+            Self::Icons => "Icons",
+            Self::Theme => "Theme",
+            Self::Font => "Font",
+            Self::Monitor => "Monitor",
+            Self::Brightness => "Brightness",
+            Self::Camera => "Camera",
+            Self::Mouse => "Mouse",
+            Self::Keyboard => "Keyboard",
+            Self::PhysicalDisk => "Physical Disk",
+            Self::DiskIo => "Disk IO",
+            Self::NetIo => "Net IO",
+            Self::Dns => "DNS",
+            Self::Vpn => "VPN",
+            Self::Container => "Container",
+            Self::Virtualization => "Virtualization",
+            Self::KernelModules => "Kernel Modules",
+            Self::Security => "Security",
+            Self::Tpm => "TPM",
+            Self::SecureBoot => "Secure Boot",
+            Self::PowerProfile => "Power Profile",
+            Self::Zram => "Zram",
+            Self::MemoryDevices => "Memory Devices",
+            Self::Pci => "PCI",
+            Self::Usb => "USB",
+            Self::Separator => "Separator",
+            Self::Break => "Break",
+            Self::Custom => "Custom",
+            Self::Text => "Text",
+            Self::Version => "Version",
+            Self::Battery => "Battery",
+            Self::WmTheme => "WM Theme",
+            Self::Title => "Title",
+        }
+    }
+
+    /// Get the lowercase identifier used to select this module
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::Os => "os",
+            Self::Host => "host",
+            Self::Kernel => "kernel",
+            Self::Uptime => "uptime",
+            Self::Shell => "shell",
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+            Self::Wm => "wm",
+            Self::Terminal => "terminal",
+            Self::TerminalFont => "terminalfont",
+            Self::Packages => "packages",
+            Self::Locale => "locale",
+            Self::Swap => "swap",
+            Self::Processes => "processes",
+            Self::Users => "users",
+            Self::LoadAvg => "loadavg",
+            Self::CpuTemp => "cputemp",
+            Self::Bluetooth => "bluetooth",
+            Self::Sound => "sound",
+            Self::Player => "player",
+            Self::Board => "board",
+            Self::Bios => "bios",
+            Self::InitSystem => "initsystem",
+            Self::Bootloader => "bootloader",
+            Self::GpuDriver => "gpudriver",
+            Self::Icons => "icons",
+            Self::Theme => "theme",
+            Self::Font => "font",
+            Self::Monitor => "monitor",
+            Self::Brightness => "brightness",
+            Self::Camera => "camera",
+            Self::Mouse => "mouse",
+            Self::Keyboard => "keyboard",
+            Self::PhysicalDisk => "physicaldisk",
+            Self::DiskIo => "diskio",
+            Self::NetIo => "netio",
+            Self::Dns => "dns",
+            Self::Vpn => "vpn",
+            Self::Container => "container",
+            Self::Virtualization => "virtualization",
+            Self::KernelModules => "kernelmodules",
+            Self::Security => "security",
+            Self::Tpm => "tpm",
+            Self::SecureBoot => "secureboot",
+            Self::PowerProfile => "powerprofile",
+            Self::Zram => "zram",
+            Self::MemoryDevices => "memorydevices",
+            Self::Pci => "pci",
+            Self::Usb => "usb",
+            Self::Separator => "separator",
+            Self::Break => "break",
+            Self::Custom => "custom",
+            Self::Text => "text",
+            Self::Version => "version",
+            Self::Battery => "battery",
+            Self::WmTheme => "wmtheme",
+            Self::Title => "title",
         }
     }
 
     /// Get all available module kinds
     pub const fn all() -> &'static [Self] {
         &[
+            Self::Title,
             Self::Os,
             Self::Host,
             Self::Kernel,
@@ -69,24 +343,232 @@ impl ModuleKind {
             Self::Shell,
             Self::Cpu,
             Self::Memory,
+            Self::Wm,
+            Self::Terminal,
+            Self::TerminalFont,
+            Self::Packages,
+            Self::Locale,
+            Self::Swap,
+            Self::Processes,
+            Self::Users,
+            Self::LoadAvg,
+            Self::CpuTemp,
+            Self::Bluetooth,
+            Self::Sound,
+            Self::Player,
+            Self::Board,
+            Self::Bios,
+            Self::InitSystem,
+            Self::Bootloader,
+            Self::GpuDriver,
+            Self::Icons,
+            Self::Theme,
+            Self::Font,
+            Self::Monitor,
+            Self::Brightness,
+            Self::Camera,
+            Self::Mouse,
+            Self::Keyboard,
+            Self::PhysicalDisk,
+            Self::DiskIo,
+            Self::NetIo,
+            Self::Dns,
+            Self::Vpn,
+            Self::Container,
+            Self::Virtualization,
+            Self::KernelModules,
+            Self::Security,
+            Self::Tpm,
+            Self::SecureBoot,
+            Self::PowerProfile,
+            Self::Zram,
+            Self::MemoryDevices,
+            Self::Pci,
+            Self::Usb,
+            Self::Separator,
+            Self::Break,
+            Self::Custom,
+            Self::Text,
+            Self::Version,
+            Self::Battery,
+            Self::WmTheme,
         ]
     }
+
+    /// Whether the module blocks for a sampling window while detecting
+    pub const fn is_sampling(self) -> bool {
+        matches!(self, Self::DiskIo | Self::NetIo)
+    }
+
+    /// Whether the module's output stays the same while the system is up,
+    /// so `--live` can keep showing the first result
+    pub const fn is_static(self) -> bool {
+        matches!(
+            self,
+            Self::Title
+                | Self::Os
+                | Self::Host
+                | Self::Kernel
+                | Self::Shell
+                | Self::Cpu
+                | Self::Board
+                | Self::Bios
+                | Self::InitSystem
+                | Self::Bootloader
+                | Self::MemoryDevices
+                | Self::Virtualization
+                | Self::Tpm
+                | Self::Version
+                | Self::Text
+        )
+    }
+
+    /// Whether the kind only shapes the layout instead of detecting anything
+    pub const fn is_layout(self) -> bool {
+        matches!(self, Self::Separator | Self::Break)
+    }
+
+    /// Whether the module is left out of the default module list
+    ///
+    /// Sampling modules are opt-in so the default run never waits on them,
+    /// layout modules only make sense at a position the user picks, and
+    /// user-defined modules show nothing until configured.
+    pub const fn is_opt_in(self) -> bool {
+        self.is_sampling() || self.is_layout() || matches!(self, Self::Custom | Self::Text)
+    }
+
+    /// Glyph shown in front of this module's label with `set`, if it has one
+    pub const fn icon(self, set: IconSet) -> Option<&'static str> {
+        match (set, self.icons()) {
+            (IconSet::Nerd, Some((nerd, _))) => Some(nerd),
+            (IconSet::Emoji, Some((_, emoji))) => Some(emoji),
+            _ => None,
+        }
+    }
+
+    /// Nerd Font and emoji glyphs for the module; layout modules and those
+    /// showing the user's own text have none
+    const fn icons(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Os => Some(("\u{f17c}", "🐧")),
+            Self::Host => Some(("\u{f0322}", "💻")),
+            Self::Kernel => Some(("\u{f013}", "🌰")),
+            Self::Uptime => Some(("\u{f017}", "⏰")),
+            Self::Shell => Some(("\u{f489}", "🐚")),
+            Self::Cpu => Some(("\u{f4bc}", "🧠")),
+            Self::Memory => Some(("\u{f035b}", "🧮")),
+            Self::Wm => Some(("\u{f2d0}", "🪟")),
+            Self::Terminal => Some(("\u{f120}", "📟")),
+            Self::TerminalFont => Some(("\u{f031}", "🔤")),
+            Self::Packages => Some(("\u{f03d7}", "📦")),
+            Self::Locale => Some(("\u{f1ab}", "🌐")),
+            Self::Swap => Some(("\u{f04e1}", "🔁")),
+            Self::Processes => Some(("\u{f085}", "🏃")),
+            Self::Users => Some(("\u{f0c0}", "👥")),
+            Self::LoadAvg => Some(("\u{f029a}", "📈")),
+            Self::CpuTemp => Some(("\u{f2c9}", "🔥")),
+            Self::Bluetooth => Some(("\u{f293}", "📡")),
+            Self::Sound => Some(("\u{f028}", "🔊")),
+            Self::Player => Some(("\u{f001}", "🎵")),
+            Self::Board => Some(("\u{f2db}", "🔩")),
+            Self::Bios => Some(("\u{f061a}", "🔧")),
+            Self::InitSystem => Some(("\u{f011}", "🚦")),
+            Self::Bootloader => Some(("\u{f135}", "🥾")),
+            Self::GpuDriver => Some(("\u{f08ae}", "🎮")),
+            Self::Icons => Some(("\u{f03e}", "🔣")),
+            Self::Theme => Some(("\u{f1fc}", "🎨")),
+            Self::Font => Some(("\u{f031}", "🔤")),
+            Self::Monitor => Some(("\u{f108}", "📺")),
+            Self::Brightness => Some(("\u{f185}", "🔆")),
+            Self::Camera => Some(("\u{f030}", "📷")),
+            Self::Mouse => Some(("\u{f037d}", "🐭")),
+            Self::Keyboard => Some(("\u{f11c}", "🎹")),
+            Self::PhysicalDisk => Some(("\u{f0a0}", "💾")),
+            Self::DiskIo => Some(("\u{f02ca}", "💽")),
+            Self::NetIo => Some(("\u{f06f3}", "📶")),
+            Self::Dns => Some(("\u{f0ac}", "🌍")),
+            Self::Vpn => Some(("\u{f0582}", "🔒")),
+            Self::Container => Some(("\u{f308}", "🐳")),
+            Self::Virtualization => Some(("\u{f048b}", "🪞")),
+            Self::KernelModules => Some(("\u{f12e}", "🧩")),
+            Self::Security => Some(("\u{f132}", "🔐")),
+            Self::Tpm => Some(("\u{f084}", "🔑")),
+            Self::SecureBoot => Some(("\u{f023}", "🔏")),
+            Self::PowerProfile => Some(("\u{f0e7}", "⚡")),
+            Self::Zram => Some(("\u{f066}", "🧊")),
+            Self::MemoryDevices => Some(("\u{f035b}", "🧮")),
+            Self::Pci => Some(("\u{f0fb2}", "🧱")),
+            Self::Usb => Some(("\u{f287}", "🔌")),
+            Self::Version => Some(("\u{f05a}", "🔖")),
+            Self::Battery => Some(("\u{f240}", "🔋")),
+            Self::WmTheme => Some(("\u{f53f}", "🌈")),
+            Self::Title | Self::Separator | Self::Break | Self::Custom | Self::Text => None,
+        }
+    }
+
+    /// Other names accepted for this module, e.g. `ram` for Memory
+    pub fn aliases(self) -> impl Iterator<Item = &'static str> {
+        ALIASES
+            .iter()
+            .filter(move |(_, kind)| *kind == self)
+            .map(|(alias, _)| *alias)
+    }
+
+    /// Module kinds displayed when none are requested explicitly
+    pub fn defaults() -> Vec<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .filter(|kind| !kind.is_opt_in())
+            .collect()
+    }
 }
 
+/// Common alternative names for modules, as used by other fetch tools
+const ALIASES: &[(&str, ModuleKind)] = &[
+    ("distro", ModuleKind::Os),
+    ("model", ModuleKind::Host),
+    ("ram", ModuleKind::Memory),
+    ("mem", ModuleKind::Memory),
+    ("term", ModuleKind::Terminal),
+    ("pkgs", ModuleKind::Packages),
+    ("load", ModuleKind::LoadAvg),
+    ("temp", ModuleKind::CpuTemp),
+    ("gpu", ModuleKind::GpuDriver),
+    ("graphics", ModuleKind::GpuDriver),
+    ("display", ModuleKind::Monitor),
+    ("disk", ModuleKind::PhysicalDisk),
+    ("motherboard", ModuleKind::Board),
+    ("init", ModuleKind::InitSystem),
+    ("virt", ModuleKind::Virtualization),
+    ("power", ModuleKind::PowerProfile),
+    ("bat", ModuleKind::Battery),
+    ("sep", ModuleKind::Separator),
+    ("command", ModuleKind::Custom),
+];
+
 impl FromStr for ModuleKind {
     type Err = String;
 
+    /// Parse a module id or alias; case, `-`, `_` and spaces are ignored so
+    /// `terminal-font` and `Terminal Font` both name Terminal Font
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "os" => Ok(Self::Os),
-            "host" => Ok(Self::Host),
-            "kernel" => Ok(Self::Kernel),
-            "uptime" => Ok(Self::Uptime),
-            "shell" => Ok(Self::Shell),
-            "cpu" => Ok(Self::Cpu),
-            "memory" => Ok(Self::Memory),
-            _ => Err(format!("Unknown module: {s}")),
-        }
+        let id: String = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|kind| kind.id() == id)
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == id)
+                    .map(|(_, kind)| *kind)
+            })
+            .ok_or_else(|| format!("Unknown module: {s}"))
     }
 }
 
@@ -96,8 +578,46 @@ impl fmt::Display for ModuleKind {
     }
 }
 
+/// Glyphs put in front of module labels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconSet {
+    /// Plain labels
+    #[default]
+    None,
+    /// Nerd Font symbols, which need a patched font in the terminal
+    Nerd,
+    /// Emoji
+    Emoji,
+}
+
+impl FromStr for IconSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" | "off" => Ok(Self::None),
+            "nerd" | "nerdfont" => Ok(Self::Nerd),
+            "emoji" => Ok(Self::Emoji),
+            _ => Err(format!(
+                "Unknown icon set: {s} (expected none, nerd or emoji)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for IconSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Nerd => write!(f, "nerd"),
+            Self::Emoji => write!(f, "emoji"),
+        }
+    }
+}
+
 /// Information returned by a module
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum ModuleInfo {
     Os(os::OsInfo),
     Host(host::HostInfo),
@@ -106,6 +626,56 @@ pub enum ModuleInfo {
     Shell(shell::ShellInfo),
     Cpu(cpu::CpuInfo),
     Memory(memory::MemoryInfo),
+    Wm(wm::WmInfo),
+    Terminal(terminal::TerminalInfo),
+    TerminalFont(terminal_font::TerminalFontInfo),
+    Packages(packages::PackagesInfo),
+    Locale(locale::LocaleInfo),
+    Swap(swap::SwapInfo),
+    Processes(processes::ProcessesInfo),
+    Users(users::UsersInfo),
+    LoadAvg(loadavg::LoadAvgInfo),
+    CpuTemp(cpu_temp::CpuTempInfo),
+    Bluetooth(bluetooth::BluetoothInfo),
+    Sound(sound::SoundInfo),
+    Player(player::PlayerInfo),
+    Board(board::BoardInfo),
+    Bios(bios::BiosInfo),
+    InitSystem(init_system::InitSystemInfo),
+    Bootloader(bootloader::BootloaderInfo),
+    GpuDriver(gpu_driver::GpuDriverInfo),
+    Icons(icons::IconsInfo),
+    Theme(theme::ThemeInfo),
+    Font(font::FontInfo),
+    Monitor(monitor::MonitorInfo),
+    Brightness(brightness::BrightnessInfo),
+    Camera(camera::CameraInfo),
+    Mouse(mouse::MouseInfo),
+    Keyboard(keyboard::KeyboardInfo),
+    PhysicalDisk(physical_disk::PhysicalDiskInfo),
+    DiskIo(disk_io::DiskIoInfo),
+    NetIo(net_io::NetIoInfo),
+    Dns(dns::DnsInfo),
+    Vpn(vpn::VpnInfo),
+    Container(container::ContainerInfo),
+    Virtualization(virtualization::VirtualizationInfo),
+    KernelModules(kernel_modules::KernelModulesInfo),
+    Security(security::SecurityInfo),
+    Tpm(tpm::TpmInfo),
+    SecureBoot(secure_boot::SecureBootInfo),
+    PowerProfile(power_profile::PowerProfileInfo),
+    Zram(zram::ZramInfo),
+    MemoryDevices(memory_devices::MemoryDevicesInfo),
+    Pci(pci::PciInfo),
+    Usb(usb::UsbInfo),
+    Separator(separator::LayoutInfo),
+    Break(separator::LayoutInfo),
+    Custom(custom::CustomInfo),
+    Text(text::TextInfo),
+    Version(version::VersionInfo),
+    Battery(battery::BatteryInfo),
+    WmTheme(wm_theme::WmThemeInfo),
+    Title(title::TitleInfo),
 }
 
 impl fmt::Display for ModuleInfo {
@@ -118,12 +688,99 @@ impl fmt::Display for ModuleInfo {
             Self::Shell(info) => write!(f, "{info}"),
             Self::Cpu(info) => write!(f, "{info}"),
             Self::Memory(info) => write!(f, "{info}"),
+            Self::Wm(info) => write!(f, "{info}"),
+            Self::Terminal(info) => write!(f, "{info}"),
+            Self::TerminalFont(info) => write!(f, "{info}"),
+            Self::Packages(info) => write!(f, "{info}"),
+            Self::Locale(info) => write!(f, "{info}"),
+            Self::Swap(info) => write!(f, "{info}"),
+            Self::Processes(info) => write!(f, "{info}"),
+            Self::Users(info) => write!(f, "{info}"),
+            Self::LoadAvg(info) => write!(f, "{info}"),
+            Self::CpuTemp(info) => write!(f, "{info}"),
+            Self::Bluetooth(info) => write!(f, "{info}"),
+            Self::Sound(info) => write!(f, "{info}"),
+            Self::Player(info) => write!(f, "{info}"),
+            Self::Board(info) => write!(f, "{info}"),
+            Self::Bios(info) => write!(f, "{info}"),
+            Self::InitSystem(info) => write!(f, "{info}"),
+            Self::Bootloader(info) => write!(f, "{info}"),
+            Self::GpuDriver(info) => write!(f, "{info}"),
+            Self::Icons(info) => write!(f, "{info}"),
+            Self::Theme(info) => write!(f, "{info}"),
+            Self::Font(info) => write!(f, "{info}"),
+            Self::Monitor(info) => write!(f, "{info}"),
+            Self::Brightness(info) => write!(f, "{info}"),
+            Self::Camera(info) => write!(f, "{info}"),
+            Self::Mouse(info) => write!(f, "{info}"),
+            Self::Keyboard(info) => write!(f, "{info}"),
+            Self::PhysicalDisk(info) => write!(f, "{info}"),
+            Self::DiskIo(info) => write!(f, "{info}"),
+            Self::NetIo(info) => write!(f, "{info}"),
+            Self::Dns(info) => write!(f, "{info}"),
+            Self::Vpn(info) => write!(f, "{info}"),
+            Self::Container(info) => write!(f, "{info}"),
+            Self::Virtualization(info) => write!(f, "{info}"),
+            Self::KernelModules(info) => write!(f, "{info}"),
+            Self::Security(info) => write!(f, "{info}"),
+            Self::Tpm(info) => write!(f, "{info}"),
+            Self::SecureBoot(info) => write!(f, "{info}"),
+            Self::PowerProfile(info) => write!(f, "{info}"),
+            Self::Zram(info) => write!(f, "{info}"),
+            Self::MemoryDevices(info) => write!(f, "{info}"),
+            Self::Pci(info) => write!(f, "{info}"),
+            Self::Usb(info) => write!(f, "{info}"),
+            Self::Separator(info) => write!(f, "{info}"),
+            Self::Break(info) => write!(f, "{info}"),
+            Self::Custom(info) => write!(f, "{info}"),
+            Self::Text(info) => write!(f, "{info}"),
+            Self::Version(info) => write!(f, "{info}"),
+            Self::Battery(info) => write!(f, "{info}"),
+            Self::WmTheme(info) => write!(f, "{info}"),
+            Self::Title(info) => write!(f, "{info}"),
+        }
+    }
+}
+
+impl ModuleInfo {
+    /// How full a capacity-style module is, in percent, for each line of
+    /// its value; empty for other modules
+    pub fn percentages(&self) -> Vec<f64> {
+        match self {
+            Self::Memory(info) => info.percent().into_iter().collect(),
+            Self::Swap(info) => info.percent().into_iter().collect(),
+            Self::Battery(info) => vec![f64::from(info.percentage)],
+            Self::Brightness(info) => info
+                .backlights
+                .iter()
+                .map(|backlight| f64::from(backlight.percent))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Value of a template field
+    ///
+    /// Every module provides `value`, its usual output; some offer their
+    /// parts individually, e.g. Kernel's `version` or Title's `host`.
+    pub fn field(&self, name: &str) -> Option<String> {
+        match (self, name) {
+            (_, "value") => Some(self.to_string()),
+            (Self::Host(info), name) => info.field(name),
+            (Self::Kernel(info), name) => info.field(name),
+            (Self::Title(info), name) => info.field(name),
+            _ => None,
         }
     }
 }
 
-/// Create a module instance for the given kind
+/// Create a module instance for the given kind with default options
 pub fn create_module(kind: ModuleKind) -> Box<dyn Module> {
+    create_module_with_options(kind, &ModuleOptions::default())
+}
+
+/// Create a module instance for the given kind, configured by `options`
+pub fn create_module_with_options(kind: ModuleKind, options: &ModuleOptions) -> Box<dyn Module> {
     match kind {
         ModuleKind::Os => Box::new(os::OsModule),
         ModuleKind::Host => Box::new(host::HostModule),
@@ -132,5 +789,168 @@ pub fn create_module(kind: ModuleKind) -> Box<dyn Module> {
         ModuleKind::Shell => Box::new(shell::ShellModule),
         ModuleKind::Cpu => Box::new(cpu::CpuModule),
         ModuleKind::Memory => Box::new(memory::MemoryModule),
+        ModuleKind::Wm => Box::new(wm::WmModule),
+        ModuleKind::Terminal => Box::new(terminal::TerminalModule),
+        ModuleKind::TerminalFont => Box::new(terminal_font::TerminalFontModule),
+        ModuleKind::Packages => Box::new(packages::PackagesModule),
+        ModuleKind::Locale => Box::new(locale::LocaleModule),
+        ModuleKind::Swap => Box::new(swap::SwapModule),
+        ModuleKind::Processes => Box::new(processes::ProcessesModule),
+        ModuleKind::Users => Box::new(users::UsersModule),
+        ModuleKind::LoadAvg => Box::new(loadavg::LoadAvgModule),
+        ModuleKind::CpuTemp => Box::new(cpu_temp::CpuTempModule::new(options.temperature_unit)),
+        ModuleKind::Bluetooth => Box::new(bluetooth::BluetoothModule),
+        ModuleKind::Sound => Box::new(sound::SoundModule),
+        ModuleKind::Player => Box::new(player::PlayerModule),
+        ModuleKind::Board => Box::new(board::BoardModule),
+        ModuleKind::Bios => Box::new(bios::BiosModule),
+        ModuleKind::InitSystem => Box::new(init_system::InitSystemModule),
+        ModuleKind::Bootloader => Box::new(bootloader::BootloaderModule),
+        ModuleKind::GpuDriver => Box::new(gpu_driver::GpuDriverModule),
+        ModuleKind::Icons => Box::new(icons::IconsModule),
+        ModuleKind::Theme => Box::new(theme::ThemeModule),
+        ModuleKind::Font => Box::new(font::FontModule),
+        ModuleKind::Monitor => Box::new(monitor::MonitorModule),
+        ModuleKind::Brightness => Box::new(brightness::BrightnessModule),
+        ModuleKind::Camera => Box::new(camera::CameraModule),
+        ModuleKind::Mouse => Box::new(mouse::MouseModule),
+        ModuleKind::Keyboard => Box::new(keyboard::KeyboardModule),
+        ModuleKind::PhysicalDisk => Box::new(physical_disk::PhysicalDiskModule),
+        ModuleKind::DiskIo => Box::new(disk_io::DiskIoModule::new(options.sample_interval)),
+        ModuleKind::NetIo => Box::new(net_io::NetIoModule::new(options.sample_interval)),
+        ModuleKind::Dns => Box::new(dns::DnsModule),
+        ModuleKind::Vpn => Box::new(vpn::VpnModule),
+        ModuleKind::Container => Box::new(container::ContainerModule),
+        ModuleKind::Virtualization => Box::new(virtualization::VirtualizationModule),
+        ModuleKind::KernelModules => Box::new(kernel_modules::KernelModulesModule::new(
+            options.show_tainted_modules,
+        )),
+        ModuleKind::Security => Box::new(security::SecurityModule),
+        ModuleKind::Tpm => Box::new(tpm::TpmModule),
+        ModuleKind::SecureBoot => Box::new(secure_boot::SecureBootModule),
+        ModuleKind::PowerProfile => Box::new(power_profile::PowerProfileModule),
+        ModuleKind::Zram => Box::new(zram::ZramModule),
+        ModuleKind::MemoryDevices => Box::new(memory_devices::MemoryDevicesModule),
+        ModuleKind::Pci => Box::new(pci::PciModule),
+        ModuleKind::Usb => Box::new(usb::UsbModule::new(options.usb_hide_hubs)),
+        ModuleKind::Separator => Box::new(separator::SeparatorModule),
+        ModuleKind::Break => Box::new(separator::BreakModule),
+        ModuleKind::Custom => Box::new(custom::CustomModule::new(
+            options.custom_command.clone(),
+            options.custom_timeout,
+        )),
+        ModuleKind::Text => Box::new(text::TextModule::new(options.text.clone())),
+        ModuleKind::Version => Box::new(version::VersionModule),
+        ModuleKind::Battery => Box::new(battery::BatteryModule),
+        ModuleKind::WmTheme => Box::new(wm_theme::WmThemeModule),
+        ModuleKind::Title => Box::new(title::TitleModule),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_module_kind() {
+        assert_eq!("Memory".parse(), Ok(ModuleKind::Memory));
+        assert_eq!("ram".parse(), Ok(ModuleKind::Memory));
+        assert_eq!("distro".parse(), Ok(ModuleKind::Os));
+        assert_eq!("GPU".parse(), Ok(ModuleKind::GpuDriver));
+        assert_eq!("terminal-font".parse(), Ok(ModuleKind::TerminalFont));
+        assert_eq!("Terminal Font".parse(), Ok(ModuleKind::TerminalFont));
+        assert_eq!("cpu_temp".parse(), Ok(ModuleKind::CpuTemp));
+        assert!("nonsense".parse::<ModuleKind>().is_err());
+    }
+
+    #[test]
+    fn test_aliases_are_not_ids() {
+        for (alias, _) in ALIASES {
+            assert!(
+                ModuleKind::all().iter().all(|kind| kind.id() != *alias),
+                "{alias} shadows a module id"
+            );
+        }
+        assert_eq!(
+            ModuleKind::Memory.aliases().collect::<Vec<_>>(),
+            ["ram", "mem"]
+        );
+    }
+
+    #[test]
+    fn test_static_kinds() {
+        assert!(ModuleKind::Os.is_static());
+        assert!(ModuleKind::Cpu.is_static());
+        assert!(!ModuleKind::LoadAvg.is_static());
+        assert!(!ModuleKind::Memory.is_static());
+        assert!(!ModuleKind::Uptime.is_static());
+    }
+
+    #[test]
+    fn test_icons() {
+        for kind in ModuleKind::defaults() {
+            if kind == ModuleKind::Title {
+                continue;
+            }
+            let emoji = kind.icon(IconSet::Emoji).unwrap_or_default();
+            assert_eq!(crate::output::visible_width(emoji), 2, "{kind}");
+            assert!(kind.icon(IconSet::Nerd).is_some(), "{kind}");
+            assert_eq!(kind.icon(IconSet::None), None);
+        }
+        assert_eq!(ModuleKind::Cpu.icon(IconSet::Nerd), Some("\u{f4bc}"));
+        assert_eq!(ModuleKind::Separator.icon(IconSet::Emoji), None);
+        assert_eq!("Nerd".parse::<IconSet>(), Ok(IconSet::Nerd));
+        assert!("ascii".parse::<IconSet>().is_err());
+    }
+
+    #[test]
+    fn test_metadata() {
+        for &kind in ModuleKind::all() {
+            let metadata = create_module(kind).metadata();
+            assert!(!metadata.description.is_empty(), "{kind}");
+            assert!(!metadata.platforms.is_empty(), "{kind}");
+            assert!(
+                metadata
+                    .platforms
+                    .iter()
+                    .all(|os| ModuleMetadata::ALL.contains(os)),
+                "{kind}"
+            );
+        }
+        let usb = create_module(ModuleKind::Usb).metadata();
+        assert_eq!(usb.options[0].0, "hide_hubs");
+        assert_eq!(create_module(ModuleKind::Title).metadata().fields.len(), 2);
+    }
+
+    #[test]
+    fn test_info_serializes_fields() {
+        let info = ModuleInfo::Memory(memory::MemoryInfo {
+            total: 2048,
+            used: 512,
+        });
+        assert_eq!(
+            serde_json::to_value(&info).unwrap(),
+            serde_json::json!({ "total": 2048, "used": 512 })
+        );
+        let info = ModuleInfo::SecureBoot(secure_boot::SecureBootInfo::Enabled);
+        assert_eq!(serde_json::to_value(&info).unwrap(), "enabled");
+    }
+
+    #[test]
+    fn test_percentages() {
+        let info = ModuleInfo::Memory(memory::MemoryInfo {
+            total: 2048,
+            used: 512,
+        });
+        assert_eq!(info.percentages(), [25.0]);
+        let info = ModuleInfo::Swap(swap::SwapInfo { total: 0, used: 0 });
+        assert!(info.percentages().is_empty());
+        let info = ModuleInfo::Brightness(brightness::BrightnessInfo {
+            backlights: vec![brightness::Backlight {
+                name: "intel_backlight".to_string(),
+                percent: 80,
+            }],
+        });
+        assert_eq!(info.percentages(), [80.0]);
     }
 }
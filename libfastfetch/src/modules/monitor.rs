@@ -0,0 +1,116 @@
+//! Monitor detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+    platform::edid::Edid,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Monitor detection module
+#[derive(Debug)]
+pub struct MonitorModule;
+
+/// A connected monitor identified by its EDID
+#[derive(Debug, Clone, Serialize)]
+pub struct Monitor {
+    /// Connector name, e.g. "DP-1"
+    pub connector: String,
+    pub edid: Edid,
+}
+
+impl fmt::Display for Monitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let edid = &self.edid;
+        match &edid.name {
+            Some(name) => write!(f, "{name}")?,
+            None => write!(f, "{} {:04X}", edid.manufacturer(), edid.product_code)?,
+        }
+
+        if let Some((width, height)) = edid.resolution {
+            write!(f, " ({width}x{height}")?;
+            if let Some(refresh) = edid.refresh_rate {
+                write!(f, " @ {} Hz", refresh.round())?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(inches) = edid.diagonal_inches() {
+            write!(f, " {}\"", inches.round())?;
+        }
+        Ok(())
+    }
+}
+
+/// Connected monitors information
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorInfo {
+    pub monitors: Vec<Monitor>,
+}
+
+impl fmt::Display for MonitorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, monitor) in self.monitors.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{monitor}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for MonitorModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_monitor(ctx).map(ModuleInfo::Monitor)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Monitor
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Connected monitors and their resolutions",
+            ModuleMetadata::LINUX,
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_monitor(ctx: &dyn SystemContext) -> DetectionResult<MonitorInfo> {
+    use crate::platform::edid::parse_edid;
+    use std::path::Path;
+
+    let drm = Path::new("/sys/class/drm");
+    let mut connectors = match ctx.read_dir(drm) {
+        Ok(entries) => entries,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+    connectors.sort();
+
+    // Connector entries look like "card0-DP-1"; disconnected ones have an empty EDID
+    let monitors: Vec<Monitor> = connectors
+        .iter()
+        .filter_map(|entry| {
+            let (_, connector) = entry.split_once('-')?;
+            let edid = ctx.read_bytes(&drm.join(entry).join("edid")).ok()?;
+            let edid = parse_edid(&edid).ok()?;
+            Some(Monitor {
+                connector: connector.to_string(),
+                edid,
+            })
+        })
+        .collect();
+
+    if monitors.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(MonitorInfo { monitors })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_monitor(_ctx: &dyn SystemContext) -> DetectionResult<MonitorInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
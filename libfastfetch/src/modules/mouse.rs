@@ -0,0 +1,92 @@
+//! Pointing device detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Mouse detection module
+#[derive(Debug)]
+pub struct MouseModule;
+
+/// A connected pointing device
+#[derive(Debug, Clone, Serialize)]
+pub struct PointingDevice {
+    pub name: String,
+    /// Battery level in percent for wireless devices that report one
+    pub battery: Option<u8>,
+}
+
+/// Pointing devices information
+#[derive(Debug, Clone, Serialize)]
+pub struct MouseInfo {
+    pub devices: Vec<PointingDevice>,
+}
+
+impl fmt::Display for MouseInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, device) in self.devices.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", device.name)?;
+            if let Some(battery) = device.battery {
+                write!(f, " ({battery}%)")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Module for MouseModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_mouse(ctx).map(ModuleInfo::Mouse)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Mouse
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Attached pointing devices", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_mouse(ctx: &dyn SystemContext) -> DetectionResult<MouseInfo> {
+    use crate::platform::linux::input::{self, InputBus};
+
+    let devices = match input::input_devices(ctx) {
+        Ok(devices) => devices,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+    let batteries = input::peripheral_batteries(ctx);
+
+    let mut pointers: Vec<PointingDevice> = Vec::new();
+    for device in devices
+        .iter()
+        .filter(|device| device.is_pointer() && device.bus != InputBus::Virtual)
+    {
+        // Wireless receivers register one input device per interface
+        if pointers.iter().any(|p| p.name == device.name) {
+            continue;
+        }
+        pointers.push(PointingDevice {
+            name: device.name.clone(),
+            battery: input::battery_for(&batteries, &device.name),
+        });
+    }
+
+    if pointers.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(MouseInfo { devices: pointers })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_mouse(_ctx: &dyn SystemContext) -> DetectionResult<MouseInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
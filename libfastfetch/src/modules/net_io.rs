@@ -0,0 +1,122 @@
+//! Network throughput detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::{fmt, time::Duration};
+
+/// Network throughput detection module
+#[derive(Debug)]
+pub struct NetIoModule {
+    interval: Duration,
+}
+
+impl NetIoModule {
+    /// Create a module that samples counters over the given window
+    pub const fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+}
+
+impl Default for NetIoModule {
+    fn default() -> Self {
+        Self::new(super::sampler::DEFAULT_INTERVAL)
+    }
+}
+
+/// Current and cumulative traffic of the default interface
+#[derive(Debug, Clone, Serialize)]
+pub struct NetIoInfo {
+    pub interface: String,
+    /// Bytes received per second
+    pub rx_rate: f64,
+    /// Bytes transmitted per second
+    pub tx_rate: f64,
+    /// Bytes received since boot
+    pub rx_total: u64,
+    /// Bytes transmitted since boot
+    pub tx_total: u64,
+}
+
+impl fmt::Display for NetIoInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::util::format_bytes;
+
+        write!(
+            f,
+            "{}/s (RX) - {}/s (TX) [{}], {} (RX) - {} (TX) since boot",
+            format_bytes(self.rx_rate as u64),
+            format_bytes(self.tx_rate as u64),
+            self.interface,
+            format_bytes(self.rx_total),
+            format_bytes(self.tx_total)
+        )
+    }
+}
+
+impl Module for NetIoModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_net_io(ctx, self.interval).map(ModuleInfo::NetIo)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::NetIo
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Network receive and transmit rates", ModuleMetadata::LINUX)
+            .with_options(&[(
+                "sample_interval",
+                "Milliseconds between the two samples, 200 by default",
+            )])
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_net_io(ctx: &dyn SystemContext, interval: Duration) -> DetectionResult<NetIoInfo> {
+    use super::sampler::sample;
+    use crate::platform::linux::proc::{parse_default_route_content, parse_net_dev_content};
+    use std::{io, path::Path};
+
+    let interface = match ctx
+        .read_file(Path::new("/proc/net/route"))
+        .ok()
+        .and_then(|route| parse_default_route_content(&route))
+    {
+        Some(interface) => interface,
+        None => return DetectionResult::Unavailable,
+    };
+
+    // (received, transmitted) bytes of the default interface
+    let read_counters = |ctx: &dyn SystemContext| {
+        let content = ctx.read_file(Path::new("/proc/net/dev"))?;
+        parse_net_dev_content(&content)
+            .into_iter()
+            .find(|stat| stat.name == interface)
+            .map(|stat| (stat.rx_bytes, stat.tx_bytes))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "Interface not in /proc/net/dev")
+            })
+    };
+
+    let sample = match sample(ctx, interval, read_counters) {
+        Ok(sample) => sample,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    let (rx_total, tx_total) = sample.after;
+    DetectionResult::Detected(NetIoInfo {
+        rx_rate: sample.per_second(rx_total.saturating_sub(sample.before.0)),
+        tx_rate: sample.per_second(tx_total.saturating_sub(sample.before.1)),
+        rx_total,
+        tx_total,
+        interface,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_net_io(_ctx: &dyn SystemContext, _interval: Duration) -> DetectionResult<NetIoInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
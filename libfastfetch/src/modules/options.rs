@@ -0,0 +1,203 @@
+//! Options that influence how modules detect and present information
+
+use super::ModuleKind;
+use crate::output::{BarMode, Color, Template, Thresholds};
+use serde::Serialize;
+use std::{collections::HashMap, fmt, str::FromStr, time::Duration};
+
+/// Unit used when rendering temperatures
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Convert a Celsius reading into this unit
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Unit suffix including the degree sign
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+}
+
+impl FromStr for TemperatureUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "c" | "celsius" => Ok(Self::Celsius),
+            "f" | "fahrenheit" => Ok(Self::Fahrenheit),
+            _ => Err(format!("Unknown temperature unit: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Celsius => write!(f, "celsius"),
+            Self::Fahrenheit => write!(f, "fahrenheit"),
+        }
+    }
+}
+
+/// Options passed to modules when they are created
+#[derive(Debug, Clone)]
+pub struct ModuleOptions {
+    /// Unit for temperature readings
+    pub temperature_unit: TemperatureUnit,
+    /// Templates replacing a module's default output, e.g. for Kernel
+    /// `"{name} {version}{?arch} ({arch}){?}"`
+    pub formats: HashMap<ModuleKind, Template>,
+    /// Capacity-style modules drawing their percentage as a bar
+    pub bars: HashMap<ModuleKind, BarMode>,
+    /// Value styles of capacity-style modules by how full they are
+    pub thresholds: HashMap<ModuleKind, Thresholds>,
+    /// Columns a module's value lines are cut short at, over the shared limit
+    pub max_lengths: HashMap<ModuleKind, usize>,
+    /// Window over which rate-based modules sample their counters
+    pub sample_interval: Duration,
+    /// List out-of-tree and other taint-causing kernel modules by name
+    pub show_tainted_modules: bool,
+    /// Leave USB hubs and root hubs out of the USB device list
+    pub usb_hide_hubs: bool,
+    /// Shell command run by the Custom module
+    pub custom_command: Option<String>,
+    /// Label shown for the Custom module instead of "Custom"
+    pub custom_label: Option<String>,
+    /// How long the Custom module's command may run before it is killed
+    pub custom_timeout: Duration,
+    /// String shown by the Text module
+    pub text: Option<String>,
+    /// Color the Text module's string is drawn in
+    pub text_color: Option<Color>,
+}
+
+impl Default for ModuleOptions {
+    fn default() -> Self {
+        Self {
+            temperature_unit: TemperatureUnit::default(),
+            formats: HashMap::new(),
+            bars: HashMap::new(),
+            thresholds: HashMap::new(),
+            max_lengths: HashMap::new(),
+            sample_interval: super::sampler::DEFAULT_INTERVAL,
+            show_tainted_modules: false,
+            usb_hide_hubs: false,
+            custom_command: None,
+            custom_label: None,
+            custom_timeout: super::custom::DEFAULT_TIMEOUT,
+            text: None,
+            text_color: None,
+        }
+    }
+}
+
+/// Options set for a single module entry, taking precedence over the
+/// shared [`ModuleOptions`] for that entry only
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleOverrides {
+    pub temperature_unit: Option<TemperatureUnit>,
+    pub format: Option<Template>,
+    pub bar: Option<BarMode>,
+    pub thresholds: Option<Thresholds>,
+    pub max_length: Option<usize>,
+    pub sample_interval: Option<Duration>,
+    pub show_tainted_modules: Option<bool>,
+    pub usb_hide_hubs: Option<bool>,
+    pub custom_command: Option<String>,
+    pub custom_timeout: Option<Duration>,
+    pub text: Option<String>,
+    pub text_color: Option<Color>,
+}
+
+impl ModuleOptions {
+    /// Options for one `kind` entry: these options with `overrides` applied
+    pub fn with_overrides(&self, kind: ModuleKind, overrides: &ModuleOverrides) -> Self {
+        let mut options = self.clone();
+        if let Some(unit) = overrides.temperature_unit {
+            options.temperature_unit = unit;
+        }
+        if let Some(format) = &overrides.format {
+            options.formats.insert(kind, format.clone());
+        }
+        if let Some(mode) = overrides.bar {
+            options.bars.insert(kind, mode);
+        }
+        if let Some(thresholds) = &overrides.thresholds {
+            options.thresholds.insert(kind, thresholds.clone());
+        }
+        if let Some(max_length) = overrides.max_length {
+            options.max_lengths.insert(kind, max_length);
+        }
+        if let Some(interval) = overrides.sample_interval {
+            options.sample_interval = interval;
+        }
+        if let Some(show) = overrides.show_tainted_modules {
+            options.show_tainted_modules = show;
+        }
+        if let Some(hide) = overrides.usb_hide_hubs {
+            options.usb_hide_hubs = hide;
+        }
+        if let Some(command) = &overrides.custom_command {
+            options.custom_command = Some(command.clone());
+        }
+        if let Some(timeout) = overrides.custom_timeout {
+            options.custom_timeout = timeout;
+        }
+        if let Some(text) = &overrides.text {
+            options.text = Some(text.clone());
+        }
+        if let Some(color) = overrides.text_color {
+            options.text_color = Some(color);
+        }
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_unit() {
+        assert_eq!(
+            "F".parse::<TemperatureUnit>(),
+            Ok(TemperatureUnit::Fahrenheit)
+        );
+        assert!("kelvin".parse::<TemperatureUnit>().is_err());
+        assert_eq!(TemperatureUnit::Fahrenheit.convert(100.0), 212.0);
+    }
+
+    #[test]
+    fn test_with_overrides() {
+        let shared = ModuleOptions {
+            custom_command: Some("uptime".to_string()),
+            custom_label: Some("Uptime".to_string()),
+            ..ModuleOptions::default()
+        };
+        let overrides = ModuleOverrides {
+            custom_command: Some("date".to_string()),
+            format: Some("[{value}]".parse().unwrap()),
+            ..ModuleOverrides::default()
+        };
+
+        let options = shared.with_overrides(ModuleKind::Custom, &overrides);
+        assert_eq!(options.custom_command.as_deref(), Some("date"));
+        assert_eq!(options.custom_label.as_deref(), Some("Uptime"));
+        assert!(options.formats.contains_key(&ModuleKind::Custom));
+        assert!(shared.formats.is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 //! OS information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata,
+};
+use serde::Serialize;
 use std::fmt;
 use std::path::Path;
 
@@ -9,7 +12,7 @@ use std::path::Path;
 pub struct OsModule;
 
 /// OS information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OsInfo {
     pub name: String,
     pub version: Option<String>,
@@ -34,6 +37,10 @@ impl Module for OsModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Os
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Operating system name and version", ModuleMetadata::ALL)
+    }
 }
 
 #[cfg(target_os = "linux")]
@@ -0,0 +1,121 @@
+//! Installed packages detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Packages detection module
+#[derive(Debug)]
+pub struct PackagesModule;
+
+/// Installed package count for a single package manager
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageCount {
+    pub manager: String,
+    pub count: usize,
+}
+
+/// Package information across all detected package managers
+#[derive(Debug, Clone, Serialize)]
+pub struct PackagesInfo {
+    pub managers: Vec<PackageCount>,
+}
+
+impl PackagesInfo {
+    /// Total number of packages across all managers
+    pub fn total(&self) -> usize {
+        self.managers.iter().map(|m| m.count).sum()
+    }
+}
+
+impl fmt::Display for PackagesInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, manager) in self.managers.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} ({})", manager.count, manager.manager)?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for PackagesModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_packages(ctx).map(ModuleInfo::Packages)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Packages
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Installed package counts by package manager",
+            ModuleMetadata::UNIX,
+        )
+    }
+}
+
+/// Build the result from `(manager, count)` pairs, dropping empty managers
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+fn collect_counts<I>(counts: I) -> DetectionResult<PackagesInfo>
+where
+    I: IntoIterator<Item = (&'static str, Option<usize>)>,
+{
+    let managers: Vec<PackageCount> = counts
+        .into_iter()
+        .filter_map(|(manager, count)| {
+            count.filter(|&c| c > 0).map(|count| PackageCount {
+                manager: manager.to_string(),
+                count,
+            })
+        })
+        .collect();
+
+    if managers.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(PackagesInfo { managers })
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_packages(ctx: &dyn SystemContext) -> DetectionResult<PackagesInfo> {
+    use crate::platform::linux::packages;
+
+    collect_counts([
+        ("pacman", packages::count_pacman(ctx)),
+        ("dpkg", packages::count_dpkg(ctx)),
+        ("rpm", packages::count_rpm(ctx)),
+        ("apk", packages::count_apk(ctx)),
+        ("nix", packages::count_nix(ctx)),
+        ("flatpak", packages::count_flatpak(ctx)),
+        ("snap", packages::count_snap(ctx)),
+        ("brew", packages::count_brew(ctx)),
+    ])
+}
+
+#[cfg(target_os = "macos")]
+fn detect_packages(ctx: &dyn SystemContext) -> DetectionResult<PackagesInfo> {
+    collect_counts([("brew", crate::platform::macos::count_brew(ctx))])
+}
+
+#[cfg(target_os = "freebsd")]
+fn detect_packages(ctx: &dyn SystemContext) -> DetectionResult<PackagesInfo> {
+    let count = ctx
+        .execute_command("pkg", &["info", "-q"])
+        .ok()
+        .filter(|output| output.success)
+        .map(|output| String::from_utf8_lossy(&output.stdout).lines().count());
+
+    collect_counts([("pkg", count)])
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
+fn detect_packages(_ctx: &dyn SystemContext) -> DetectionResult<PackagesInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,145 @@
+//! PCI devices detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// PCI detection module
+#[derive(Debug)]
+pub struct PciModule;
+
+/// A PCI device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PciDevice {
+    /// Bus address, e.g. "0000:01:00.0"
+    pub address: String,
+    /// 24-bit class code: class, subclass, programming interface
+    pub class: u32,
+    pub vendor_id: u16,
+    pub device_id: u16,
+    /// Names from pci.ids, when a database is available
+    pub vendor: Option<String>,
+    pub name: Option<String>,
+}
+
+impl PciDevice {
+    /// Short label for device classes worth showing, `None` for the rest
+    pub fn category(&self) -> Option<&'static str> {
+        let category = match (self.class >> 16, (self.class >> 8) & 0xFF) {
+            (0x01, 0x06) => "SATA",
+            (0x01, 0x08) => "NVMe",
+            (0x01, _) => "Storage",
+            (0x02, 0x80) => "Wireless",
+            (0x02, _) => "Ethernet",
+            (0x03, _) => "Display",
+            (0x04, 0x03) => "Audio",
+            (0x04, _) => "Multimedia",
+            (0x0D, _) => "Wireless",
+            _ => return None,
+        };
+        Some(category)
+    }
+}
+
+impl fmt::Display for PciDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.vendor {
+            Some(vendor) => write!(f, "{vendor} ")?,
+            None => write!(f, "{:04x}:", self.vendor_id)?,
+        }
+        match &self.name {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{:04x}", self.device_id),
+        }
+    }
+}
+
+/// PCI devices, with the notable ones summarized
+#[derive(Debug, Clone, Serialize)]
+pub struct PciInfo {
+    pub devices: Vec<PciDevice>,
+}
+
+impl fmt::Display for PciInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let notable: Vec<String> = self
+            .devices
+            .iter()
+            .filter_map(|device| Some(format!("{}: {device}", device.category()?)))
+            .collect();
+
+        if notable.is_empty() {
+            write!(f, "{} devices", self.devices.len())
+        } else {
+            write!(f, "{}", notable.join("\n"))
+        }
+    }
+}
+
+impl Module for PciModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_pci(ctx).map(ModuleInfo::Pci)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Pci
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("PCI devices", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_pci(ctx: &dyn SystemContext) -> DetectionResult<PciInfo> {
+    use crate::platform::pci_ids;
+    use std::path::Path;
+
+    let mut addresses = match ctx.read_dir(Path::new("/sys/bus/pci/devices")) {
+        Ok(addresses) => addresses,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+    addresses.sort();
+
+    let db = pci_ids::database(ctx);
+    let devices: Vec<PciDevice> = addresses
+        .into_iter()
+        .filter_map(|address| {
+            let dir = Path::new("/sys/bus/pci/devices").join(&address);
+            let read_hex = |attr: &str| {
+                let value = ctx.read_file(&dir.join(attr)).ok()?;
+                u32::from_str_radix(value.trim().trim_start_matches("0x"), 16).ok()
+            };
+
+            let vendor_id = u16::try_from(read_hex("vendor")?).ok()?;
+            let device_id = u16::try_from(read_hex("device")?).ok()?;
+            let (vendor, name) = db
+                .as_deref()
+                .map(|db| pci_ids::lookup(db, vendor_id, device_id))
+                .unwrap_or_default();
+
+            Some(PciDevice {
+                address,
+                class: read_hex("class")?,
+                vendor_id,
+                device_id,
+                vendor: vendor.map(str::to_string),
+                name: name.map(str::to_string),
+            })
+        })
+        .collect();
+
+    if devices.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(PciInfo { devices })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_pci(_ctx: &dyn SystemContext) -> DetectionResult<PciInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
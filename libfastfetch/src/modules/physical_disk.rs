@@ -0,0 +1,205 @@
+//! Physical disk detection module
+//!
+//! Lists the drives themselves rather than mounted filesystems.
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Physical disk detection module
+#[derive(Debug)]
+pub struct PhysicalDiskModule;
+
+/// Storage medium
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskKind {
+    Ssd,
+    Hdd,
+}
+
+/// Bus a disk is attached through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskTransport {
+    Nvme,
+    Sata,
+    Usb,
+    Mmc,
+    Virtio,
+}
+
+impl fmt::Display for DiskTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Nvme => "NVMe",
+            Self::Sata => "SATA",
+            Self::Usb => "USB",
+            Self::Mmc => "MMC",
+            Self::Virtio => "VirtIO",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A physical disk
+#[derive(Debug, Clone, Serialize)]
+pub struct PhysicalDisk {
+    /// Kernel device name, e.g. "nvme0n1"
+    pub name: String,
+    pub model: Option<String>,
+    pub size: u64,
+    pub kind: Option<DiskKind>,
+    pub transport: Option<DiskTransport>,
+}
+
+impl fmt::Display for PhysicalDisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::util::format_bytes;
+
+        write!(f, "{}", self.model.as_deref().unwrap_or(&self.name))?;
+        write!(f, " ({}", format_bytes(self.size))?;
+        if let Some(transport) = self.transport {
+            write!(f, ", {transport}")?;
+        }
+        match self.kind {
+            Some(DiskKind::Ssd) => write!(f, " SSD")?,
+            Some(DiskKind::Hdd) => write!(f, " HDD")?,
+            None => {}
+        }
+        write!(f, ")")
+    }
+}
+
+/// Physical disks information
+#[derive(Debug, Clone, Serialize)]
+pub struct PhysicalDiskInfo {
+    pub disks: Vec<PhysicalDisk>,
+}
+
+impl fmt::Display for PhysicalDiskInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // One line per disk
+        for (i, disk) in self.disks.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{disk}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for PhysicalDiskModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_physical_disk(ctx).map(ModuleInfo::PhysicalDisk)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::PhysicalDisk
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Physical disks and their sizes", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_physical_disk(_ctx: &dyn SystemContext) -> DetectionResult<PhysicalDiskInfo> {
+    use crate::platform::linux::sys::block;
+
+    let mut devices = match block::list_devices() {
+        Ok(devices) => devices,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+    devices.sort();
+
+    let disks: Vec<PhysicalDisk> = devices
+        .into_iter()
+        .filter(|device| block::is_physical(device))
+        .filter_map(|device| {
+            // Empty card readers and optical drives report a size of zero
+            let size = block::device_size(&device).ok().filter(|&size| size > 0)?;
+            let model = block::read_attr(&device, "device/model")
+                .ok()
+                .filter(|model| !model.is_empty());
+            let kind = match block::read_attr(&device, "queue/rotational").as_deref() {
+                Ok("0") => Some(DiskKind::Ssd),
+                Ok("1") => Some(DiskKind::Hdd),
+                _ => None,
+            };
+            let transport = block::device_path(&device)
+                .ok()
+                .and_then(|path| transport_from_path(&path.to_string_lossy()));
+
+            Some(PhysicalDisk {
+                name: device,
+                model,
+                size,
+                kind,
+                transport,
+            })
+        })
+        .collect();
+
+    if disks.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(PhysicalDiskInfo { disks })
+    }
+}
+
+/// Classify the bus from a resolved sysfs path such as
+/// `/sys/devices/pci0000:00/0000:00:1d.0/0000:3d:00.0/nvme/nvme0/nvme0n1`
+#[cfg(target_os = "linux")]
+fn transport_from_path(path: &str) -> Option<DiskTransport> {
+    // USB bridges can front any disk type, so check them first
+    if path.contains("/usb") {
+        Some(DiskTransport::Usb)
+    } else if path.contains("/nvme") {
+        Some(DiskTransport::Nvme)
+    } else if path.contains("/ata") {
+        Some(DiskTransport::Sata)
+    } else if path.contains("/mmc") {
+        Some(DiskTransport::Mmc)
+    } else if path.contains("/virtio") {
+        Some(DiskTransport::Virtio)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_physical_disk(_ctx: &dyn SystemContext) -> DetectionResult<PhysicalDiskInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transport_from_path() {
+        assert_eq!(
+            transport_from_path(
+                "/sys/devices/pci0000:00/0000:00:1d.0/0000:3d:00.0/nvme/nvme0/nvme0n1"
+            ),
+            Some(DiskTransport::Nvme)
+        );
+        assert_eq!(
+            transport_from_path(
+                "/sys/devices/pci0000:00/0000:00:17.0/ata1/host0/target0:0:0/0:0:0:0/block/sda"
+            ),
+            Some(DiskTransport::Sata)
+        );
+        assert_eq!(
+            transport_from_path(
+                "/sys/devices/pci0000:00/0000:00:14.0/usb2/2-1/2-1:1.0/host4/target4:0:0/4:0:0:0/block/sdb"
+            ),
+            Some(DiskTransport::Usb)
+        );
+    }
+}
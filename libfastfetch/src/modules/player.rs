@@ -0,0 +1,187 @@
+//! Media player detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Media player detection module
+#[derive(Debug)]
+pub struct PlayerModule;
+
+/// Active media player and current track
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerInfo {
+    pub player: String,
+    pub artist: Option<String>,
+    pub title: Option<String>,
+    pub paused: bool,
+}
+
+impl fmt::Display for PlayerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.player)?;
+
+        match (&self.artist, &self.title) {
+            (Some(artist), Some(title)) => write!(f, ": {artist} - {title}")?,
+            (None, Some(title)) => write!(f, ": {title}")?,
+            _ => {}
+        }
+
+        if self.paused {
+            write!(f, " (Paused)")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for PlayerModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_player(ctx).map(ModuleInfo::Player)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Player
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Media player and what it's playing", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+#[cfg(target_os = "linux")]
+const MPRIS_PATH: &str = "/org/mpris/MediaPlayer2";
+
+#[cfg(target_os = "linux")]
+fn detect_player(ctx: &dyn SystemContext) -> DetectionResult<PlayerInfo> {
+    use crate::platform::linux::dbus::{DbusBus, get_property, list_names};
+
+    let Ok(names) = list_names(ctx, DbusBus::Session) else {
+        return DetectionResult::Unavailable;
+    };
+
+    // Prefer a player that is actually playing over paused ones
+    let mut paused = None;
+    for name in names.iter().filter(|name| name.starts_with(MPRIS_PREFIX)) {
+        let status = get_property(
+            ctx,
+            DbusBus::Session,
+            name,
+            MPRIS_PATH,
+            "org.mpris.MediaPlayer2.Player",
+            "PlaybackStatus",
+        );
+        match status.ok().as_ref().and_then(|status| status.as_str()) {
+            Some("Playing") => {
+                if let Some(info) = player_info(ctx, name, false) {
+                    return DetectionResult::Detected(info);
+                }
+            }
+            Some("Paused") if paused.is_none() => paused = Some(name),
+            _ => {}
+        }
+    }
+
+    match paused.and_then(|name| player_info(ctx, name, true)) {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn player_info(ctx: &dyn SystemContext, name: &str, paused: bool) -> Option<PlayerInfo> {
+    use crate::platform::linux::dbus::{DbusBus, get_property};
+
+    let metadata = get_property(
+        ctx,
+        DbusBus::Session,
+        name,
+        MPRIS_PATH,
+        "org.mpris.MediaPlayer2.Player",
+        "Metadata",
+    )
+    .ok()?;
+
+    // Fall back to the bus name suffix, e.g. "spotify" or "firefox.instance_1_42"
+    let identity = get_property(
+        ctx,
+        DbusBus::Session,
+        name,
+        MPRIS_PATH,
+        "org.mpris.MediaPlayer2",
+        "Identity",
+    )
+    .ok()
+    .and_then(|identity| identity.as_str().map(str::to_string))
+    .unwrap_or_else(|| {
+        let suffix = &name[MPRIS_PREFIX.len()..];
+        suffix.split('.').next().unwrap_or(suffix).to_string()
+    });
+
+    Some(player_from_metadata(identity, &metadata, paused))
+}
+
+/// Extract the track from an MPRIS `Metadata` dictionary
+#[cfg(target_os = "linux")]
+fn player_from_metadata(
+    player: String,
+    metadata: &crate::platform::linux::dbus::DbusValue,
+    paused: bool,
+) -> PlayerInfo {
+    let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+    let title = metadata
+        .get("xesam:title")
+        .and_then(|title| title.as_str())
+        .and_then(non_empty);
+    let artist = metadata
+        .get("xesam:artist")
+        .and_then(|artists| artists.as_array())
+        .map(|artists| {
+            artists
+                .iter()
+                .filter_map(|artist| artist.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .as_deref()
+        .and_then(non_empty);
+
+    PlayerInfo {
+        player,
+        artist,
+        title,
+        paused,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_player(_ctx: &dyn SystemContext) -> DetectionResult<PlayerInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::platform::linux::dbus::parse_busctl_output;
+
+    #[test]
+    fn test_player_from_metadata() {
+        let metadata = parse_busctl_output(concat!(
+            "a{sv} 3 \"mpris:trackid\" o \"/com/spotify/track/1\" ",
+            "\"xesam:artist\" as 1 \"Daft Punk\" \"xesam:title\" s \"One More Time\"",
+        ))
+        .unwrap();
+
+        let info = player_from_metadata("Spotify".to_string(), &metadata, false);
+        assert_eq!(info.to_string(), "Spotify: Daft Punk - One More Time");
+
+        let empty = parse_busctl_output("a{sv} 0").unwrap();
+        let info = player_from_metadata("mpv".to_string(), &empty, true);
+        assert_eq!(info.to_string(), "mpv (Paused)");
+    }
+}
@@ -0,0 +1,82 @@
+//! Platform power profile detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Power profile detection module
+#[derive(Debug)]
+pub struct PowerProfileModule;
+
+/// Active power profile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PowerProfileInfo {
+    /// Profile name, e.g. "balanced", "performance" or "power-saver"
+    pub profile: String,
+    /// Where the profile came from: the daemon or the firmware interface
+    pub source: String,
+}
+
+impl fmt::Display for PowerProfileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.profile, self.source)
+    }
+}
+
+impl Module for PowerProfileModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_power_profile(ctx).map(ModuleInfo::PowerProfile)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::PowerProfile
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Active platform power profile", ModuleMetadata::LINUX)
+    }
+}
+
+/// power-profiles-daemon bus names: the current UPower one and the legacy one
+#[cfg(target_os = "linux")]
+const DAEMON_NAMES: &[(&str, &str)] = &[
+    (
+        "org.freedesktop.UPower.PowerProfiles",
+        "/org/freedesktop/UPower/PowerProfiles",
+    ),
+    ("net.hadess.PowerProfiles", "/net/hadess/PowerProfiles"),
+];
+
+#[cfg(target_os = "linux")]
+fn detect_power_profile(ctx: &dyn SystemContext) -> DetectionResult<PowerProfileInfo> {
+    use crate::platform::linux::dbus::{DbusBus, get_property};
+    use std::path::Path;
+
+    let from_daemon = DAEMON_NAMES.iter().find_map(|&(name, path)| {
+        let profile = get_property(ctx, DbusBus::System, name, path, name, "ActiveProfile").ok()?;
+        profile.as_str().map(str::to_string)
+    });
+    if let Some(profile) = from_daemon {
+        return DetectionResult::Detected(PowerProfileInfo {
+            profile,
+            source: "power-profiles-daemon".to_string(),
+        });
+    }
+
+    // The ACPI platform profile the daemon would otherwise drive
+    match ctx.read_file(Path::new("/sys/firmware/acpi/platform_profile")) {
+        Ok(profile) if !profile.trim().is_empty() => DetectionResult::Detected(PowerProfileInfo {
+            profile: profile.trim().to_string(),
+            source: "ACPI".to_string(),
+        }),
+        _ => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_power_profile(_ctx: &dyn SystemContext) -> DetectionResult<PowerProfileInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,87 @@
+//! Running processes count module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Processes detection module
+#[derive(Debug)]
+pub struct ProcessesModule;
+
+/// Process count information
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessesInfo {
+    pub count: usize,
+}
+
+impl fmt::Display for ProcessesInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.count)
+    }
+}
+
+impl Module for ProcessesModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_processes(ctx).map(ModuleInfo::Processes)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Processes
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Number of running processes", ModuleMetadata::UNIX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_processes(ctx: &dyn SystemContext) -> DetectionResult<ProcessesInfo> {
+    use std::path::Path;
+
+    let entries = match ctx.read_dir(Path::new("/proc")) {
+        Ok(entries) => entries,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    // Every process has a directory named after its PID
+    let count = entries
+        .iter()
+        .filter(|name| name.bytes().all(|b| b.is_ascii_digit()))
+        .count();
+
+    DetectionResult::Detected(ProcessesInfo { count })
+}
+
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+fn detect_processes(_ctx: &dyn SystemContext) -> DetectionResult<ProcessesInfo> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_ALL, 0];
+    let mut size: libc::size_t = 0;
+
+    // Query the buffer size needed for the full process table; each entry is
+    // one kinfo_proc, so the size alone gives the count.
+    let result = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            std::ptr::null_mut(),
+            &mut size,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+
+    if result != 0 {
+        return DetectionResult::Error(std::io::Error::last_os_error().into());
+    }
+
+    let count = size / std::mem::size_of::<libc::kinfo_proc>();
+    DetectionResult::Detected(ProcessesInfo { count })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
+fn detect_processes(_ctx: &dyn SystemContext) -> DetectionResult<ProcessesInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
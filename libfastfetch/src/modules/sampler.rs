@@ -0,0 +1,48 @@
+//! Two-point sampling for rate-based modules
+//!
+//! Throughput and utilisation figures are derived from cumulative kernel
+//! counters, which have to be read twice some interval apart. Modules share
+//! this helper so the sampling window is configured in one place.
+
+use crate::context::SystemContext;
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+/// Default time between the two readings
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Two readings of a counter and the time that actually elapsed between them
+#[derive(Debug, Clone)]
+pub struct Sample<T> {
+    pub before: T,
+    pub after: T,
+    pub elapsed: Duration,
+}
+
+impl<T> Sample<T> {
+    /// Convert a counter delta into a per-second rate
+    pub fn per_second(&self, delta: u64) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 { delta as f64 / secs } else { 0.0 }
+    }
+}
+
+/// Read a counter, wait for `interval`, and read it again
+pub fn sample<T>(
+    ctx: &dyn SystemContext,
+    interval: Duration,
+    read: impl Fn(&dyn SystemContext) -> io::Result<T>,
+) -> io::Result<Sample<T>> {
+    let before = read(ctx)?;
+    let start = Instant::now();
+    std::thread::sleep(interval);
+    let after = read(ctx)?;
+
+    Ok(Sample {
+        before,
+        after,
+        elapsed: start.elapsed(),
+    })
+}
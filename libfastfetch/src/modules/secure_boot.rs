@@ -0,0 +1,105 @@
+//! UEFI Secure Boot state detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Secure Boot detection module
+#[derive(Debug)]
+pub struct SecureBootModule;
+
+/// Secure Boot state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecureBootInfo {
+    Enabled,
+    Disabled,
+    /// Legacy BIOS boot or firmware without Secure Boot
+    Unsupported,
+}
+
+impl fmt::Display for SecureBootInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enabled => write!(f, "Enabled"),
+            Self::Disabled => write!(f, "Disabled"),
+            Self::Unsupported => write!(f, "Unsupported"),
+        }
+    }
+}
+
+impl Module for SecureBootModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_secure_boot(ctx).map(ModuleInfo::SecureBoot)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::SecureBoot
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("UEFI Secure Boot state", ModuleMetadata::LINUX)
+    }
+}
+
+/// `SecureBoot` variable in the EFI global variable namespace
+#[cfg(target_os = "linux")]
+const SECURE_BOOT_VAR: &str =
+    "/sys/firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+#[cfg(target_os = "linux")]
+fn detect_secure_boot(ctx: &dyn SystemContext) -> DetectionResult<SecureBootInfo> {
+    use std::path::Path;
+
+    if ctx.read_dir(Path::new("/sys/firmware/efi")).is_err() {
+        return DetectionResult::Detected(SecureBootInfo::Unsupported);
+    }
+
+    let state = match ctx.read_bytes(Path::new(SECURE_BOOT_VAR)) {
+        Ok(data) => parse_secure_boot(&data),
+        // UEFI firmware without Secure Boot support does not define the variable
+        Err(_) => SecureBootInfo::Unsupported,
+    };
+
+    DetectionResult::Detected(state)
+}
+
+/// Decode the efivarfs file: 4 attribute bytes followed by a single u8 flag
+#[cfg(target_os = "linux")]
+fn parse_secure_boot(data: &[u8]) -> SecureBootInfo {
+    match data.get(4) {
+        Some(1) => SecureBootInfo::Enabled,
+        Some(_) => SecureBootInfo::Disabled,
+        None => SecureBootInfo::Unsupported,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_secure_boot(_ctx: &dyn SystemContext) -> DetectionResult<SecureBootInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_secure_boot() {
+        // The attribute prefix (0x06 = boot service + runtime access) must be skipped
+        assert_eq!(
+            parse_secure_boot(&[0x06, 0, 0, 0, 1]),
+            SecureBootInfo::Enabled
+        );
+        assert_eq!(
+            parse_secure_boot(&[0x06, 0, 0, 0, 0]),
+            SecureBootInfo::Disabled
+        );
+        assert_eq!(
+            parse_secure_boot(&[0x06, 0, 0, 0]),
+            SecureBootInfo::Unsupported
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Mandatory access control (MAC) detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Security module detection module
+#[derive(Debug)]
+pub struct SecurityModule;
+
+/// SELinux enforcement mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelinuxMode {
+    Enforcing,
+    Permissive,
+}
+
+impl fmt::Display for SelinuxMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enforcing => write!(f, "Enforcing"),
+            Self::Permissive => write!(f, "Permissive"),
+        }
+    }
+}
+
+/// Active mandatory access control systems
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityInfo {
+    /// SELinux mode, if SELinux is enabled
+    pub selinux: Option<SelinuxMode>,
+    pub apparmor: bool,
+}
+
+impl fmt::Display for SecurityInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut systems = Vec::new();
+        if let Some(mode) = self.selinux {
+            systems.push(format!("SELinux ({mode})"));
+        }
+        if self.apparmor {
+            systems.push("AppArmor".to_string());
+        }
+        write!(f, "{}", systems.join(", "))
+    }
+}
+
+impl Module for SecurityModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_security(ctx).map(ModuleInfo::Security)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Security
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new(
+            "Active mandatory access control, like SELinux or AppArmor",
+            ModuleMetadata::LINUX,
+        )
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_security(ctx: &dyn SystemContext) -> DetectionResult<SecurityInfo> {
+    use std::path::Path;
+
+    let read = |path: &str| {
+        ctx.read_file(Path::new(path))
+            .map(|value| value.trim().to_string())
+    };
+
+    // selinuxfs is only mounted when SELinux is enabled
+    let selinux = match read("/sys/fs/selinux/enforce").as_deref() {
+        Ok("1") => Some(SelinuxMode::Enforcing),
+        Ok("0") => Some(SelinuxMode::Permissive),
+        _ => None,
+    };
+    let apparmor = read("/sys/module/apparmor/parameters/enabled").is_ok_and(|v| v == "Y");
+
+    if selinux.is_none() && !apparmor {
+        return DetectionResult::Unavailable;
+    }
+
+    DetectionResult::Detected(SecurityInfo { selinux, apparmor })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_security(_ctx: &dyn SystemContext) -> DetectionResult<SecurityInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
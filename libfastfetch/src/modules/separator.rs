@@ -0,0 +1,56 @@
+//! Layout pseudo-modules that group other modules visually
+//!
+//! They detect nothing; the output formatter recognizes their kinds and
+//! draws a dashed line or leaves a blank line at their position.
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Dashed separator line
+#[derive(Debug)]
+pub struct SeparatorModule;
+
+/// Blank line
+#[derive(Debug)]
+pub struct BreakModule;
+
+/// Placeholder info for layout modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LayoutInfo;
+
+impl fmt::Display for LayoutInfo {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl Module for SeparatorModule {
+    fn detect(&self, _ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        DetectionResult::Detected(ModuleInfo::Separator(LayoutInfo))
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Separator
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("A line of dashes under the title", ModuleMetadata::ALL)
+    }
+}
+
+impl Module for BreakModule {
+    fn detect(&self, _ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        DetectionResult::Detected(ModuleInfo::Break(LayoutInfo))
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Break
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("An empty line", ModuleMetadata::ALL)
+    }
+}
@@ -1,6 +1,9 @@
 //! Shell information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata,
+};
+use serde::Serialize;
 use std::fmt;
 
 /// Shell detection module
@@ -8,7 +11,7 @@ use std::fmt;
 pub struct ShellModule;
 
 /// Shell information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ShellInfo {
     pub name: String,
     pub version: Option<String>,
@@ -32,6 +35,10 @@ impl Module for ShellModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Shell
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Shell and its version", ModuleMetadata::ALL)
+    }
 }
 
 #[cfg(unix)]
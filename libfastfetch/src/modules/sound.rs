@@ -0,0 +1,234 @@
+//! Audio output detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Sound detection module
+#[derive(Debug)]
+pub struct SoundModule;
+
+/// Default audio output information
+#[derive(Debug, Clone, Serialize)]
+pub struct SoundInfo {
+    /// Human-readable name of the default sink
+    pub name: String,
+    /// Volume in percent
+    pub volume: Option<u32>,
+    pub muted: bool,
+}
+
+impl fmt::Display for SoundInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        match (self.muted, self.volume) {
+            (true, _) => write!(f, " (muted)"),
+            (false, Some(volume)) => write!(f, " ({volume}%)"),
+            (false, None) => Ok(()),
+        }
+    }
+}
+
+impl Module for SoundModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_sound(ctx).map(ModuleInfo::Sound)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Sound
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Default audio output", &["linux", "macos"])
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_sound(ctx: &dyn SystemContext) -> DetectionResult<SoundInfo> {
+    // pactl talks to both PulseAudio and pipewire-pulse; wpctl covers bare PipeWire
+    match sound_from_pactl(ctx).or_else(|| sound_from_wpctl(ctx)) {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn command_stdout(ctx: &dyn SystemContext, program: &str, args: &[&str]) -> Option<String> {
+    let output = ctx.execute_command(program, args).ok()?;
+    output
+        .success
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "linux")]
+fn sound_from_pactl(ctx: &dyn SystemContext) -> Option<SoundInfo> {
+    let sink = command_stdout(ctx, "pactl", &["get-default-sink"])?;
+    let sink = sink.trim();
+    if sink.is_empty() {
+        return None;
+    }
+
+    let name = command_stdout(ctx, "pactl", &["list", "sinks"])
+        .and_then(|sinks| pactl_sink_description(&sinks, sink))
+        .unwrap_or_else(|| sink.to_string());
+    let volume = command_stdout(ctx, "pactl", &["get-sink-volume", "@DEFAULT_SINK@"])
+        .and_then(|output| parse_pactl_volume(&output));
+    let muted = command_stdout(ctx, "pactl", &["get-sink-mute", "@DEFAULT_SINK@"])
+        .is_some_and(|output| output.trim() == "Mute: yes");
+
+    Some(SoundInfo {
+        name,
+        volume,
+        muted,
+    })
+}
+
+/// Find the description of the named sink in `pactl list sinks`
+#[cfg(target_os = "linux")]
+fn pactl_sink_description(sinks: &str, sink: &str) -> Option<String> {
+    let mut in_sink = false;
+
+    for line in sinks.lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix("Name:") {
+            in_sink = name.trim() == sink;
+        } else if in_sink && let Some(description) = line.strip_prefix("Description:") {
+            return Some(description.trim().to_string());
+        }
+    }
+
+    None
+}
+
+/// Average the channel percentages of
+/// "Volume: front-left: 29491 /  45% / -20.81 dB,   front-right: ..."
+#[cfg(target_os = "linux")]
+fn parse_pactl_volume(output: &str) -> Option<u32> {
+    let line = output.lines().find(|line| line.starts_with("Volume:"))?;
+    let levels: Vec<u32> = line
+        .split('/')
+        .filter_map(|part| part.trim().strip_suffix('%')?.trim().parse().ok())
+        .collect();
+
+    if levels.is_empty() {
+        return None;
+    }
+    Some(levels.iter().sum::<u32>() / levels.len() as u32)
+}
+
+#[cfg(target_os = "linux")]
+fn sound_from_wpctl(ctx: &dyn SystemContext) -> Option<SoundInfo> {
+    let volume = command_stdout(ctx, "wpctl", &["get-volume", "@DEFAULT_AUDIO_SINK@"])?;
+    let (volume, muted) = parse_wpctl_volume(&volume)?;
+
+    // Properties are listed as `  * node.description = "Built-in Audio"`
+    let name = command_stdout(ctx, "wpctl", &["inspect", "@DEFAULT_AUDIO_SINK@"])
+        .and_then(|inspect| {
+            inspect.lines().find_map(|line| {
+                let (key, value) = line.trim_start_matches([' ', '*']).split_once(" = ")?;
+                (key == "node.description").then(|| value.trim_matches('"').to_string())
+            })
+        })
+        .unwrap_or_else(|| "Default sink".to_string());
+
+    Some(SoundInfo {
+        name,
+        volume: Some(volume),
+        muted,
+    })
+}
+
+/// Parse "Volume: 0.45" or "Volume: 0.45 [MUTED]"
+#[cfg(target_os = "linux")]
+fn parse_wpctl_volume(output: &str) -> Option<(u32, bool)> {
+    let rest = output.trim().strip_prefix("Volume:")?;
+    let mut parts = rest.split_whitespace();
+    let volume: f64 = parts.next()?.parse().ok()?;
+    let muted = parts.any(|part| part == "[MUTED]");
+
+    Some(((volume * 100.0).round() as u32, muted))
+}
+
+#[cfg(target_os = "macos")]
+fn detect_sound(ctx: &dyn SystemContext) -> DetectionResult<SoundInfo> {
+    // CoreAudio state as exposed by AppleScript:
+    // "output volume:44, input volume:50, alert volume:100, output muted:false"
+    let output = match ctx.execute_command("osascript", &["-e", "get volume settings"]) {
+        Ok(output) if output.success => output,
+        _ => return DetectionResult::Unavailable,
+    };
+    let settings = String::from_utf8_lossy(&output.stdout);
+
+    let mut volume = None;
+    let mut muted = false;
+    for setting in settings.trim().split(", ") {
+        match setting.split_once(':') {
+            Some(("output volume", value)) => volume = value.parse().ok(),
+            Some(("output muted", value)) => muted = value == "true",
+            _ => {}
+        }
+    }
+
+    let name = default_output_device(ctx).unwrap_or_else(|| "Default output".to_string());
+
+    DetectionResult::Detected(SoundInfo {
+        name,
+        volume,
+        muted,
+    })
+}
+
+/// Name of the device flagged "Default Output Device: Yes" in `system_profiler`
+#[cfg(target_os = "macos")]
+fn default_output_device(ctx: &dyn SystemContext) -> Option<String> {
+    let output = ctx
+        .execute_command("system_profiler", &["SPAudioDataType"])
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut device = None;
+    for line in stdout.lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_suffix(':') {
+            device = Some(name.to_string());
+        } else if line == "Default Output Device: Yes" {
+            return device;
+        }
+    }
+
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_sound(_ctx: &dyn SystemContext) -> DetectionResult<SoundInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_volume() {
+        let pactl = "Volume: front-left: 29491 /  45% / -20.81 dB,   front-right: 32768 /  50% / -18.06 dB\n        balance 0.00\n";
+        assert_eq!(parse_pactl_volume(pactl), Some(47));
+
+        assert_eq!(parse_wpctl_volume("Volume: 0.45\n"), Some((45, false)));
+        assert_eq!(
+            parse_wpctl_volume("Volume: 1.00 [MUTED]\n"),
+            Some((100, true))
+        );
+    }
+
+    #[test]
+    fn test_pactl_sink_description() {
+        let sinks = "Sink #1\n\tName: hdmi\n\tDescription: HDMI\nSink #2\n\tName: alsa_output.analog\n\tDescription: Built-in Audio\n";
+        assert_eq!(
+            pactl_sink_description(sinks, "alsa_output.analog").as_deref(),
+            Some("Built-in Audio")
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Swap information detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+    util::format_bytes,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Swap detection module
+#[derive(Debug)]
+pub struct SwapModule;
+
+/// Swap information (in bytes)
+#[derive(Debug, Clone, Serialize)]
+pub struct SwapInfo {
+    pub total: u64,
+    pub used: u64,
+}
+
+impl SwapInfo {
+    /// Share of swap in use, in percent; `None` when swap is disabled
+    pub fn percent(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.used as f64 / self.total as f64 * 100.0)
+    }
+}
+
+impl fmt::Display for SwapInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.total == 0 {
+            return write!(f, "Disabled");
+        }
+
+        write!(
+            f,
+            "{} / {}",
+            format_bytes(self.used),
+            format_bytes(self.total)
+        )
+    }
+}
+
+impl Module for SwapModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_swap(ctx).map(ModuleInfo::Swap)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Swap
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Used and total swap", ModuleMetadata::UNIX)
+            .with_options(ModuleMetadata::CAPACITY_OPTIONS)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_swap(ctx: &dyn SystemContext) -> DetectionResult<SwapInfo> {
+    use std::path::Path;
+
+    let meminfo = match ctx.read_file(Path::new("/proc/meminfo")) {
+        Ok(content) => content,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    let mut total = None;
+    let mut free = None;
+
+    for line in meminfo.lines() {
+        let field = if line.starts_with("SwapTotal:") {
+            &mut total
+        } else if line.starts_with("SwapFree:") {
+            &mut free
+        } else {
+            continue;
+        };
+
+        *field = line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|kb| kb.parse::<u64>().ok())
+            .map(|kb| kb * 1024);
+    }
+
+    match (total, free) {
+        (Some(total), Some(free)) => DetectionResult::Detected(SwapInfo {
+            total,
+            used: total.saturating_sub(free),
+        }),
+        _ => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_swap(ctx: &dyn SystemContext) -> DetectionResult<SwapInfo> {
+    let output = match ctx.execute_command("sysctl", &["-n", "vm.swapusage"]) {
+        Ok(output) => output,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    if !output.success {
+        return DetectionResult::Unavailable;
+    }
+
+    // Format: "total = 2048.00M  used = 1056.25M  free = 991.75M  (encrypted)"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value_of = |key: &str| -> Option<u64> {
+        let rest = stdout.split(key).nth(1)?;
+        let value = rest
+            .trim_start_matches([' ', '='])
+            .split_whitespace()
+            .next()?;
+        let megabytes: f64 = value.trim_end_matches('M').parse().ok()?;
+        Some((megabytes * 1024.0 * 1024.0) as u64)
+    };
+
+    match (value_of("total"), value_of("used")) {
+        (Some(total), Some(used)) => DetectionResult::Detected(SwapInfo { total, used }),
+        _ => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn detect_swap(ctx: &dyn SystemContext) -> DetectionResult<SwapInfo> {
+    let output = match ctx.execute_command("swapinfo", &["-k"]) {
+        Ok(output) => output,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+
+    if !output.success {
+        return DetectionResult::Unavailable;
+    }
+
+    // Format: "Device 1K-blocks Used Avail Capacity" followed by one line per device
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (mut total, mut used) = (0u64, 0u64);
+    for line in stdout.lines().skip(1).filter(|l| l.starts_with('/')) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if let (Some(t), Some(u)) = (fields.get(1), fields.get(2)) {
+            total += t.parse::<u64>().unwrap_or(0) * 1024;
+            used += u.parse::<u64>().unwrap_or(0) * 1024;
+        }
+    }
+
+    DetectionResult::Detected(SwapInfo { total, used })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
+fn detect_swap(_ctx: &dyn SystemContext) -> DetectionResult<SwapInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,219 @@
+//! Terminal emulator detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Terminal detection module
+#[derive(Debug)]
+pub struct TerminalModule;
+
+/// Terminal emulator information
+#[derive(Debug, Clone, Serialize)]
+pub struct TerminalInfo {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl fmt::Display for TerminalInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(ref version) = self.version {
+            write!(f, " {version}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for TerminalModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_terminal(ctx).map(ModuleInfo::Terminal)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Terminal
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Terminal emulator", ModuleMetadata::ALL)
+    }
+}
+
+/// Known terminal emulators: process name, display name, and version command
+#[cfg(target_os = "linux")]
+const KNOWN_TERMINALS: &[(&str, &str, &[&str])] = &[
+    ("kitty", "kitty", &["kitty", "--version"]),
+    ("alacritty", "Alacritty", &["alacritty", "--version"]),
+    ("wezterm-gui", "WezTerm", &["wezterm", "--version"]),
+    ("wezterm", "WezTerm", &["wezterm", "--version"]),
+    ("foot", "foot", &["foot", "--version"]),
+    ("footclient", "foot", &["foot", "--version"]),
+    ("ghostty", "Ghostty", &["ghostty", "--version"]),
+    (
+        "gnome-terminal-",
+        "GNOME Terminal",
+        &["gnome-terminal", "--version"],
+    ),
+    (
+        "gnome-terminal",
+        "GNOME Terminal",
+        &["gnome-terminal", "--version"],
+    ),
+    ("kgx", "GNOME Console", &["kgx", "--version"]),
+    ("ptyxis-agent", "Ptyxis", &["ptyxis", "--version"]),
+    ("ptyxis", "Ptyxis", &["ptyxis", "--version"]),
+    ("konsole", "Konsole", &["konsole", "--version"]),
+    (
+        "xfce4-terminal",
+        "Xfce Terminal",
+        &["xfce4-terminal", "--version"],
+    ),
+    ("tilix", "Tilix", &["tilix", "--version"]),
+    ("terminator", "Terminator", &["terminator", "--version"]),
+    ("xterm", "xterm", &["xterm", "-version"]),
+    ("urxvt", "urxvt", &[]),
+    ("st", "st", &[]),
+    ("tmux: server", "tmux", &["tmux", "-V"]),
+    ("tmux", "tmux", &["tmux", "-V"]),
+    ("screen", "screen", &["screen", "--version"]),
+    ("zellij", "Zellij", &["zellij", "--version"]),
+    ("code", "Visual Studio Code", &[]),
+    ("sshd", "SSH", &[]),
+];
+
+/// Processes that sit between the terminal and us and should be skipped
+#[cfg(target_os = "linux")]
+const PASSTHROUGH_PROCESSES: &[&str] = &[
+    "sh",
+    "bash",
+    "zsh",
+    "fish",
+    "dash",
+    "ksh",
+    "mksh",
+    "tcsh",
+    "csh",
+    "nu",
+    "elvish",
+    "xonsh",
+    "sudo",
+    "su",
+    "doas",
+    "login",
+    "script",
+    "time",
+    "strace",
+    "cargo",
+    "fastfetch-rs",
+];
+
+/// Detect the terminal emulator hosting the current process.
+///
+/// Shared with the terminal font module, which needs to know which
+/// terminal's configuration to inspect.
+pub(crate) fn detect_terminal(ctx: &dyn SystemContext) -> DetectionResult<TerminalInfo> {
+    #[cfg(target_os = "linux")]
+    if let Some(info) = terminal_from_process_tree(ctx) {
+        return DetectionResult::Detected(info);
+    }
+
+    match terminal_from_env(ctx) {
+        Some(info) => DetectionResult::Detected(info),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Walk up the parent process chain until a known terminal emulator is found
+#[cfg(target_os = "linux")]
+fn terminal_from_process_tree(ctx: &dyn SystemContext) -> Option<TerminalInfo> {
+    use crate::platform::linux::proc::parse_stat;
+    use std::path::Path;
+
+    // Bounded to avoid looping forever on a malformed process table
+    const MAX_DEPTH: usize = 16;
+
+    let mut pid = std::process::id();
+
+    for _ in 0..MAX_DEPTH {
+        let stat = ctx
+            .read_file(Path::new(&format!("/proc/{pid}/stat")))
+            .ok()?;
+        let (comm, ppid) = parse_stat(&stat)?;
+
+        if let Some(&(_, name, version_cmd)) = KNOWN_TERMINALS
+            .iter()
+            .find(|(process, _, _)| *process == comm)
+        {
+            return Some(TerminalInfo {
+                name: name.to_string(),
+                version: terminal_version(ctx, version_cmd),
+            });
+        }
+
+        if pid != std::process::id() && !PASSTHROUGH_PROCESSES.contains(&comm.as_str()) {
+            // An unknown, non-shell ancestor is most likely the terminal itself
+            return Some(TerminalInfo {
+                name: comm,
+                version: None,
+            });
+        }
+
+        if ppid <= 1 {
+            break;
+        }
+        pid = ppid;
+    }
+
+    None
+}
+
+/// Fall back to the variables terminals export for their children
+fn terminal_from_env(ctx: &dyn SystemContext) -> Option<TerminalInfo> {
+    if ctx.get_env("WT_SESSION").is_some() {
+        return Some(TerminalInfo {
+            name: "Windows Terminal".to_string(),
+            version: None,
+        });
+    }
+
+    if let Some(program) = ctx.get_env("TERM_PROGRAM") {
+        let name = match program.as_str() {
+            "Apple_Terminal" => "Apple Terminal".to_string(),
+            "iTerm.app" => "iTerm2".to_string(),
+            "vscode" => "Visual Studio Code".to_string(),
+            _ => program,
+        };
+
+        return Some(TerminalInfo {
+            name,
+            version: ctx.get_env("TERM_PROGRAM_VERSION"),
+        });
+    }
+
+    ctx.get_env("TERM").map(|name| TerminalInfo {
+        name,
+        version: None,
+    })
+}
+
+/// Run the terminal's version command and pick out the version number
+#[cfg(target_os = "linux")]
+fn terminal_version(ctx: &dyn SystemContext, command: &[&str]) -> Option<String> {
+    let (program, args) = command.split_first()?;
+    let output = ctx.execute_command(program, args).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_line = stdout.lines().next()?;
+
+    // Formats vary: "kitty 0.35.2 created by Kovid Goyal", "foot version: 1.17.2",
+    // "XTerm(390)"; take the first token that starts with a digit.
+    first_line
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .find(|token| token.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|token| token.trim_end_matches([',', ';']).to_string())
+}
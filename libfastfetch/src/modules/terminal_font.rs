@@ -0,0 +1,271 @@
+//! Terminal font detection module
+//!
+//! Reads the font configured for the detected terminal emulator from its
+//! configuration file (or `gsettings` for GNOME Terminal).
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata,
+    context::SystemContext,
+    modules::terminal::detect_terminal,
+    platform::{
+        desktop::{gsettings_get, parse_pango_font},
+        xdg,
+    },
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Terminal font detection module
+#[derive(Debug)]
+pub struct TerminalFontModule;
+
+/// Terminal font information
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TerminalFontInfo {
+    pub name: String,
+    pub size: Option<f64>,
+}
+
+impl fmt::Display for TerminalFontInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(size) = self.size {
+            write!(f, " ({size}pt)")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for TerminalFontModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_terminal_font(ctx).map(ModuleInfo::TerminalFont)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::TerminalFont
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Font of the terminal emulator", ModuleMetadata::ALL)
+    }
+}
+
+fn detect_terminal_font(ctx: &dyn SystemContext) -> DetectionResult<TerminalFontInfo> {
+    let terminal = match detect_terminal(ctx) {
+        DetectionResult::Detected(terminal) => terminal,
+        DetectionResult::Unavailable => return DetectionResult::Unavailable,
+        DetectionResult::Error(err) => return DetectionResult::Error(err),
+    };
+
+    let font = match terminal.name.as_str() {
+        "kitty" => read_config(ctx, &["kitty/kitty.conf"], &[], parse_kitty),
+        "Alacritty" => read_config(
+            ctx,
+            &["alacritty/alacritty.toml"],
+            &[".alacritty.toml"],
+            parse_alacritty,
+        ),
+        "WezTerm" => read_config(
+            ctx,
+            &["wezterm/wezterm.lua"],
+            &[".wezterm.lua"],
+            parse_wezterm,
+        ),
+        "foot" => read_config(ctx, &["foot/foot.ini"], &[], parse_foot),
+        "GNOME Terminal" => gnome_terminal_font(ctx),
+        _ => None,
+    };
+
+    match font {
+        Some(font) => DetectionResult::Detected(font),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Locate a terminal config file and run the given parser over it
+fn read_config(
+    ctx: &dyn SystemContext,
+    config_relative: &[&str],
+    home_relative: &[&str],
+    parse: fn(&str) -> Option<TerminalFontInfo>,
+) -> Option<TerminalFontInfo> {
+    let candidates = xdg::config_candidates(ctx, config_relative, home_relative);
+    let (_, content) = xdg::read_first(ctx, &candidates)?;
+    parse(&content)
+}
+
+/// kitty.conf: `font_family <name>` and `font_size <pt>`
+fn parse_kitty(content: &str) -> Option<TerminalFontInfo> {
+    let mut name = None;
+    let mut size = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(value) = line.strip_prefix("font_family") {
+            name = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("font_size") {
+            size = value.trim().parse().ok();
+        }
+    }
+
+    name.filter(|n| !n.is_empty() && n != "monospace")
+        .map(|name| TerminalFontInfo { name, size })
+}
+
+/// alacritty.toml: `family` under `[font.normal]` (or an inline `normal = { .. }`)
+/// and `size` under `[font]`
+fn parse_alacritty(content: &str) -> Option<TerminalFontInfo> {
+    let mut section = String::new();
+    let mut name = None;
+    let mut size = None;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            section = line.trim_matches(|c| c == '[' || c == ']').to_string();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        match (section.as_str(), key) {
+            ("font.normal", "family") => name = Some(unquote(value).to_string()),
+            ("font", "size") => size = value.parse().ok(),
+            ("font", "normal") => name = inline_table_value(value, "family"),
+            _ => {}
+        }
+    }
+
+    name.map(|name| TerminalFontInfo { name, size })
+}
+
+/// wezterm.lua: `wezterm.font("Name")` / `wezterm.font_with_fallback { "Name" }`
+/// and `font_size = <pt>`
+fn parse_wezterm(content: &str) -> Option<TerminalFontInfo> {
+    let mut name = None;
+    let mut size = None;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with("--") {
+            continue;
+        }
+
+        if name.is_none()
+            && let Some(idx) = line.find("wezterm.font")
+        {
+            name = first_quoted(&line[idx..]).map(str::to_string);
+        }
+
+        if let Some(value) = line
+            .strip_prefix("config.font_size")
+            .or_else(|| line.strip_prefix("font_size"))
+            .and_then(|rest| rest.trim().strip_prefix('='))
+        {
+            size = value.trim().trim_end_matches(',').parse().ok();
+        }
+    }
+
+    name.map(|name| TerminalFontInfo { name, size })
+}
+
+/// foot.ini: `font=<name>:size=<pt>[,fallback...]`
+fn parse_foot(content: &str) -> Option<TerminalFontInfo> {
+    let value = content.lines().map(str::trim).find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "font").then(|| value.trim())
+    })?;
+
+    let primary = value.split(',').next()?;
+    let mut parts = primary.split(':');
+    let name = parts.next()?.trim().to_string();
+    let size = parts
+        .find_map(|attr| attr.strip_prefix("size="))
+        .and_then(|s| s.parse().ok());
+
+    Some(TerminalFontInfo { name, size })
+}
+
+/// GNOME Terminal stores fonts per profile in dconf
+fn gnome_terminal_font(ctx: &dyn SystemContext) -> Option<TerminalFontInfo> {
+    let profile = gsettings_get(ctx, "org.gnome.Terminal.ProfilesList", "default")?;
+    let schema = format!(
+        "org.gnome.Terminal.Legacy.Profile:/org/gnome/terminal/legacy/profiles:/:{profile}/"
+    );
+
+    let font = if gsettings_get(ctx, &schema, "use-system-font").as_deref() == Some("true") {
+        gsettings_get(ctx, "org.gnome.desktop.interface", "monospace-font-name")?
+    } else {
+        gsettings_get(ctx, &schema, "font")?
+    };
+
+    let (name, size) = parse_pango_font(&font);
+    Some(TerminalFontInfo { name, size })
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim_matches(|c| c == '"' || c == '\'')
+}
+
+/// Extract `key = "value"` from an inline TOML table like `{ family = "X", style = "Y" }`
+fn inline_table_value(table: &str, key: &str) -> Option<String> {
+    table
+        .trim_matches(|c| c == '{' || c == '}')
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, v)| unquote(v.trim()).to_string())
+}
+
+/// First single- or double-quoted string in a line
+fn first_quoted(line: &str) -> Option<&str> {
+    let start = line.find(['"', '\''])?;
+    let quote = line[start..].chars().next()?;
+    let rest = &line[start + 1..];
+    let end = rest.find(quote)?;
+    Some(&rest[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kitty() {
+        let conf = "# comment\nfont_family      JetBrains Mono\nfont_size 11.5\n";
+        assert_eq!(
+            parse_kitty(conf),
+            Some(TerminalFontInfo {
+                name: "JetBrains Mono".to_string(),
+                size: Some(11.5),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_alacritty() {
+        let conf = "[font]\nsize = 12\n\n[font.normal]\nfamily = \"Fira Code\"\n";
+        let font = parse_alacritty(conf).unwrap();
+        assert_eq!(font.name, "Fira Code");
+        assert_eq!(font.size, Some(12.0));
+
+        let inline = "[font]\nnormal = { family = \"Hack\", style = \"Regular\" }\n";
+        assert_eq!(parse_alacritty(inline).unwrap().name, "Hack");
+    }
+
+    #[test]
+    fn test_parse_wezterm() {
+        let conf = "local config = {}\nconfig.font = wezterm.font('Iosevka Term')\nconfig.font_size = 13.0\n";
+        let font = parse_wezterm(conf).unwrap();
+        assert_eq!(font.name, "Iosevka Term");
+        assert_eq!(font.size, Some(13.0));
+    }
+
+    #[test]
+    fn test_parse_foot() {
+        let conf = "[main]\nfont=Terminus:size=10,Noto Color Emoji:size=9\n";
+        let font = parse_foot(conf).unwrap();
+        assert_eq!(font.name, "Terminus");
+        assert_eq!(font.size, Some(10.0));
+    }
+}
@@ -0,0 +1,53 @@
+//! Static text module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Text module showing a configured string
+#[derive(Debug, Default)]
+pub struct TextModule {
+    text: Option<String>,
+}
+
+impl TextModule {
+    pub fn new(text: Option<String>) -> Self {
+        Self { text }
+    }
+}
+
+/// Configured text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TextInfo {
+    pub text: String,
+}
+
+impl fmt::Display for TextInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl Module for TextModule {
+    fn detect(&self, _ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        match &self.text {
+            Some(text) => {
+                DetectionResult::Detected(ModuleInfo::Text(TextInfo { text: text.clone() }))
+            }
+            None => DetectionResult::Unavailable,
+        }
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Text
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("A fixed line of text", ModuleMetadata::ALL).with_options(&[
+            ("text", "Text to show"),
+            ("color", "A color name like \"cyan\" or a \"#rrggbb\" code"),
+        ])
+    }
+}
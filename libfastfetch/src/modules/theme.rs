@@ -0,0 +1,147 @@
+//! Widget theme detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Theme detection module
+#[derive(Debug)]
+pub struct ThemeModule;
+
+/// Preferred light/dark appearance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl fmt::Display for ColorScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Light => write!(f, "Light"),
+            Self::Dark => write!(f, "Dark"),
+        }
+    }
+}
+
+/// Widget themes configured for each toolkit
+#[derive(Debug, Clone, Serialize)]
+pub struct ThemeInfo {
+    pub gtk2: Option<String>,
+    pub gtk3: Option<String>,
+    pub gtk4: Option<String>,
+    pub qt: Option<String>,
+    pub color_scheme: Option<ColorScheme>,
+}
+
+impl fmt::Display for ThemeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Group GTK versions sharing a theme: "Adwaita [GTK2/3/4]"
+        let mut gtk: Vec<(&str, Vec<&str>)> = Vec::new();
+        for (version, theme) in [("2", &self.gtk2), ("3", &self.gtk3), ("4", &self.gtk4)] {
+            let Some(theme) = theme.as_deref() else {
+                continue;
+            };
+            match gtk.iter_mut().find(|(name, _)| *name == theme) {
+                Some((_, versions)) => versions.push(version),
+                None => gtk.push((theme, vec![version])),
+            }
+        }
+
+        let mut parts: Vec<String> = gtk
+            .into_iter()
+            .map(|(theme, versions)| format!("{theme} [GTK{}]", versions.join("/")))
+            .collect();
+        if let Some(qt) = &self.qt {
+            parts.push(format!("{qt} [Qt]"));
+        }
+
+        write!(f, "{}", parts.join(", "))?;
+        if let Some(scheme) = self.color_scheme {
+            write!(f, " ({scheme})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for ThemeModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_theme(ctx).map(ModuleInfo::Theme)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Theme
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Desktop widget theme", &["linux", "freebsd"])
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_theme(ctx: &dyn SystemContext) -> DetectionResult<ThemeInfo> {
+    use crate::platform::desktop;
+
+    const INTERFACE: &str = "org.gnome.desktop.interface";
+
+    let gtk2 = desktop::gtk2_setting(ctx, "gtk-theme-name");
+    let gtk3 = desktop::gtk_settings_ini(ctx, "gtk-3.0", "gtk-theme-name")
+        .or_else(|| desktop::gsettings_get(ctx, INTERFACE, "gtk-theme"));
+    let gtk4 = desktop::gtk_settings_ini(ctx, "gtk-4.0", "gtk-theme-name");
+    let qt = desktop::kde_setting(ctx, "KDE", "widgetStyle")
+        .or_else(|| desktop::qtct_setting(ctx, "Appearance", "style"));
+
+    if gtk2.is_none() && gtk3.is_none() && gtk4.is_none() && qt.is_none() {
+        return DetectionResult::Unavailable;
+    }
+
+    // GNOME 42+ records the preference explicitly; older setups flag dark GTK themes
+    let color_scheme =
+        match desktop::gsettings_get(ctx, INTERFACE, "color-scheme").as_deref() {
+            Some("prefer-dark") => Some(ColorScheme::Dark),
+            Some("prefer-light") => Some(ColorScheme::Light),
+            _ => desktop::gtk_setting(ctx, "gtk-application-prefer-dark-theme").map(|value| {
+                match value.as_str() {
+                    "1" | "true" => ColorScheme::Dark,
+                    _ => ColorScheme::Light,
+                }
+            }),
+        };
+
+    DetectionResult::Detected(ThemeInfo {
+        gtk2,
+        gtk3,
+        gtk4,
+        qt,
+        color_scheme,
+    })
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn detect_theme(_ctx: &dyn SystemContext) -> DetectionResult<ThemeInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_display() {
+        let info = ThemeInfo {
+            gtk2: Some("Adwaita".to_string()),
+            gtk3: Some("adw-gtk3".to_string()),
+            gtk4: Some("Adwaita".to_string()),
+            qt: Some("Breeze".to_string()),
+            color_scheme: Some(ColorScheme::Dark),
+        };
+        assert_eq!(
+            info.to_string(),
+            "Adwaita [GTK2/4], adw-gtk3 [GTK3], Breeze [Qt] (Dark)"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Title (user@hostname) module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Title module
+#[derive(Debug)]
+pub struct TitleModule;
+
+/// The "user@hostname" header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TitleInfo {
+    pub user: String,
+    pub hostname: String,
+}
+
+impl TitleInfo {
+    /// Template field: `user` or `host` (`hostname`)
+    pub fn field(&self, name: &str) -> Option<String> {
+        match name {
+            "user" => Some(self.user.clone()),
+            "host" | "hostname" => Some(self.hostname.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TitleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.user, self.hostname)
+    }
+}
+
+impl Module for TitleModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_title(ctx).map(ModuleInfo::Title)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Title
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("User and host name", ModuleMetadata::ALL)
+            .with_fields(&[("user", "User name"), ("host", "Host name")])
+    }
+}
+
+#[cfg(unix)]
+fn detect_title(ctx: &dyn SystemContext) -> DetectionResult<TitleInfo> {
+    let user = ctx
+        .get_env("USER")
+        .or_else(|| ctx.get_env("LOGNAME"))
+        .unwrap_or_else(|| "user".to_string());
+
+    match ctx.get_hostname() {
+        Ok(hostname) => DetectionResult::Detected(TitleInfo { user, hostname }),
+        Err(err) => DetectionResult::Error(err.into()),
+    }
+}
+
+#[cfg(windows)]
+fn detect_title(ctx: &dyn SystemContext) -> DetectionResult<TitleInfo> {
+    match (ctx.get_env("USERNAME"), ctx.get_env("COMPUTERNAME")) {
+        (Some(user), Some(hostname)) => DetectionResult::Detected(TitleInfo { user, hostname }),
+        _ => DetectionResult::Unavailable,
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn detect_title(_ctx: &dyn SystemContext) -> DetectionResult<TitleInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,101 @@
+//! Trusted Platform Module detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// TPM detection module
+#[derive(Debug)]
+pub struct TpmModule;
+
+/// TPM information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TpmInfo {
+    /// Specification version, "1.2" or "2.0"
+    pub version: String,
+    /// Kernel driver bound to the chip, e.g. "tpm_crb" or "tpm_tis"
+    pub driver: Option<String>,
+}
+
+impl fmt::Display for TpmInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TPM {}", self.version)?;
+        if let Some(driver) = &self.driver {
+            write!(f, " ({driver})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for TpmModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_tpm(ctx).map(ModuleInfo::Tpm)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Tpm
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Trusted Platform Module version", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_tpm(ctx: &dyn SystemContext) -> DetectionResult<TpmInfo> {
+    use std::path::Path;
+
+    let dir = Path::new("/sys/class/tpm/tpm0");
+    if ctx.read_dir(dir).is_err() {
+        return DetectionResult::Unavailable;
+    }
+
+    let read = |attr: &str| ctx.read_file(&dir.join(attr)).ok();
+
+    // tpm_version_major exists since Linux 5.6; older kernels only expose
+    // the caps file for 1.2 chips, so a missing caps file implies 2.0
+    let version = match read("tpm_version_major").as_deref().map(str::trim) {
+        Some("1") => "1.2".to_string(),
+        Some("2") => "2.0".to_string(),
+        _ => read("caps")
+            .and_then(|caps| caps_version(&caps))
+            .unwrap_or_else(|| "2.0".to_string()),
+    };
+
+    let driver = read("device/uevent").and_then(|uevent| {
+        uevent
+            .lines()
+            .find_map(|line| line.strip_prefix("DRIVER="))
+            .map(str::to_string)
+    });
+
+    DetectionResult::Detected(TpmInfo { version, driver })
+}
+
+/// Extract "1.2" from the "TCG version: 1.2" line of a TPM 1.2 caps file
+#[cfg(target_os = "linux")]
+fn caps_version(caps: &str) -> Option<String> {
+    caps.lines()
+        .find_map(|line| line.strip_prefix("TCG version:"))
+        .map(|version| version.trim().to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_tpm(_ctx: &dyn SystemContext) -> DetectionResult<TpmInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caps_version() {
+        let caps = "Manufacturer: 0x53544d20\nTCG version: 1.2\nFirmware version: 13.12\n";
+        assert_eq!(caps_version(caps).as_deref(), Some("1.2"));
+        assert_eq!(caps_version(""), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Uptime information detection module
 
-use crate::{context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind};
+use crate::{
+    context::SystemContext, DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata,
+};
+use serde::Serialize;
 use std::fmt;
 use std::path::Path;
 
@@ -9,7 +12,7 @@ use std::path::Path;
 pub struct UptimeModule;
 
 /// Uptime information (in seconds)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UptimeInfo {
     pub seconds: u64,
 }
@@ -54,6 +57,10 @@ impl Module for UptimeModule {
     fn kind(&self) -> ModuleKind {
         ModuleKind::Uptime
     }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Time since boot", ModuleMetadata::ALL)
+    }
 }
 
 #[cfg(target_os = "linux")]
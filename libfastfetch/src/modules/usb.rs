@@ -0,0 +1,138 @@
+//! USB devices detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// USB detection module
+#[derive(Debug, Default)]
+pub struct UsbModule {
+    hide_hubs: bool,
+}
+
+impl UsbModule {
+    /// Create a module, optionally leaving hubs and root hubs out
+    pub const fn new(hide_hubs: bool) -> Self {
+        Self { hide_hubs }
+    }
+}
+
+/// A connected USB device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsbDevice {
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub manufacturer: Option<String>,
+    pub product: Option<String>,
+    pub is_hub: bool,
+}
+
+impl fmt::Display for UsbDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: Vec<&str> = [&self.manufacturer, &self.product]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        if name.is_empty() {
+            write!(f, "{:04x}:{:04x}", self.vendor_id, self.product_id)
+        } else {
+            write!(
+                f,
+                "{} ({:04x}:{:04x})",
+                name.join(" "),
+                self.vendor_id,
+                self.product_id
+            )
+        }
+    }
+}
+
+/// Connected USB devices
+#[derive(Debug, Clone, Serialize)]
+pub struct UsbInfo {
+    pub devices: Vec<UsbDevice>,
+}
+
+impl fmt::Display for UsbInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, device) in self.devices.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{device}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for UsbModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_usb(ctx, self.hide_hubs).map(ModuleInfo::Usb)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Usb
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("USB devices", ModuleMetadata::LINUX)
+            .with_options(&[("hide_hubs", "Leave out USB hubs, false by default")])
+    }
+}
+
+/// `bDeviceClass` of hubs
+#[cfg(target_os = "linux")]
+const USB_CLASS_HUB: u16 = 0x09;
+
+#[cfg(target_os = "linux")]
+fn detect_usb(ctx: &dyn SystemContext, hide_hubs: bool) -> DetectionResult<UsbInfo> {
+    use std::path::Path;
+
+    let mut entries = match ctx.read_dir(Path::new("/sys/bus/usb/devices")) {
+        Ok(entries) => entries,
+        Err(_) => return DetectionResult::Unavailable,
+    };
+    entries.sort();
+
+    let devices: Vec<UsbDevice> = entries
+        .into_iter()
+        // "1-2:1.0" entries are interfaces of the "1-2" device
+        .filter(|entry| !entry.contains(':'))
+        .filter_map(|entry| {
+            let dir = Path::new("/sys/bus/usb/devices").join(&entry);
+            let read = |attr: &str| {
+                ctx.read_file(&dir.join(attr))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            let read_hex = |attr: &str| u16::from_str_radix(&read(attr)?, 16).ok();
+
+            let class = read_hex("bDeviceClass").unwrap_or(0);
+            Some(UsbDevice {
+                vendor_id: read_hex("idVendor")?,
+                product_id: read_hex("idProduct")?,
+                manufacturer: read("manufacturer"),
+                product: read("product"),
+                // Root hubs ("usb1") are the host controllers' virtual hubs
+                is_hub: class == USB_CLASS_HUB || entry.starts_with("usb"),
+            })
+        })
+        .filter(|device| !(hide_hubs && device.is_hub))
+        .collect();
+
+    if devices.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(UsbInfo { devices })
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_usb(_ctx: &dyn SystemContext, _hide_hubs: bool) -> DetectionResult<UsbInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
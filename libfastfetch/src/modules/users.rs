@@ -0,0 +1,173 @@
+//! Logged-in users and sessions detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Users detection module
+#[derive(Debug)]
+pub struct UsersModule;
+
+/// A single login session
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSession {
+    pub user: String,
+    /// Whether the session was opened over the network (SSH)
+    pub remote: bool,
+}
+
+/// Logged-in users information
+#[derive(Debug, Clone, Serialize)]
+pub struct UsersInfo {
+    pub sessions: Vec<UserSession>,
+}
+
+impl UsersInfo {
+    /// Distinct user names in login order
+    pub fn users(&self) -> Vec<&str> {
+        let mut users: Vec<&str> = Vec::new();
+        for session in &self.sessions {
+            if !users.contains(&session.user.as_str()) {
+                users.push(&session.user);
+            }
+        }
+        users
+    }
+
+    /// Number of remote (SSH) sessions
+    pub fn remote_sessions(&self) -> usize {
+        self.sessions.iter().filter(|s| s.remote).count()
+    }
+}
+
+impl fmt::Display for UsersInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.users().join(", "))?;
+
+        let total = self.sessions.len();
+        let remote = self.remote_sessions();
+        if total > 1 || remote > 0 {
+            write!(f, " ({total} session{}", if total == 1 { "" } else { "s" })?;
+            if remote > 0 {
+                write!(f, ", {remote} SSH")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for UsersModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_users(ctx).map(ModuleInfo::Users)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Users
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Logged-in users", ModuleMetadata::UNIX)
+    }
+}
+
+#[cfg(unix)]
+fn detect_users(ctx: &dyn SystemContext) -> DetectionResult<UsersInfo> {
+    #[cfg(target_os = "linux")]
+    let sessions = sessions_from_utmp(ctx)
+        .or_else(|| sessions_from_loginctl(ctx))
+        .or_else(|| sessions_from_who(ctx));
+
+    #[cfg(not(target_os = "linux"))]
+    let sessions = sessions_from_who(ctx);
+
+    match sessions {
+        Some(sessions) if !sessions.is_empty() => DetectionResult::Detected(UsersInfo { sessions }),
+        _ => DetectionResult::Unavailable,
+    }
+}
+
+/// Read login records straight from utmp
+#[cfg(target_os = "linux")]
+fn sessions_from_utmp(ctx: &dyn SystemContext) -> Option<Vec<UserSession>> {
+    use crate::platform::linux::utmp::parse_utmp;
+    use std::path::Path;
+
+    let data = ctx
+        .read_bytes(Path::new("/run/utmp"))
+        .or_else(|_| ctx.read_bytes(Path::new("/var/run/utmp")))
+        .ok()?;
+
+    let sessions: Vec<UserSession> = parse_utmp(&data)
+        .into_iter()
+        .map(|entry| UserSession {
+            remote: entry.is_remote(),
+            user: entry.user,
+        })
+        .collect();
+
+    (!sessions.is_empty()).then_some(sessions)
+}
+
+/// Ask systemd-logind, for systems where utmp is no longer maintained
+#[cfg(target_os = "linux")]
+fn sessions_from_loginctl(ctx: &dyn SystemContext) -> Option<Vec<UserSession>> {
+    let output = ctx
+        .execute_command("loginctl", &["list-sessions", "--no-legend"])
+        .ok()?;
+    if !output.success {
+        return None;
+    }
+
+    // Columns: SESSION UID USER [SEAT TTY ...]
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let sessions = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let id = columns.next()?;
+            let user = columns.nth(1)?.to_string();
+
+            let remote = ctx
+                .execute_command("loginctl", &["show-session", id, "-p", "Remote", "--value"])
+                .ok()
+                .is_some_and(|out| String::from_utf8_lossy(&out.stdout).trim() == "yes");
+
+            Some(UserSession { user, remote })
+        })
+        .collect();
+
+    Some(sessions)
+}
+
+/// Portable fallback: `who` prints "user tty date [(host)]"
+#[cfg(unix)]
+fn sessions_from_who(ctx: &dyn SystemContext) -> Option<Vec<UserSession>> {
+    let output = ctx.execute_command("who", &[]).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let sessions = stdout
+        .lines()
+        .filter_map(|line| {
+            let user = line.split_whitespace().next()?.to_string();
+            let remote = line
+                .rsplit_once('(')
+                .map(|(_, host)| host.trim_end_matches(')'))
+                .is_some_and(|host| !host.starts_with(':'));
+            Some(UserSession { user, remote })
+        })
+        .collect();
+
+    Some(sessions)
+}
+
+#[cfg(not(unix))]
+fn detect_users(_ctx: &dyn SystemContext) -> DetectionResult<UsersInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,67 @@
+//! fastfetch-rs version module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Version module reporting on fastfetch-rs itself
+#[derive(Debug)]
+pub struct VersionModule;
+
+/// Build information of the running fastfetch-rs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionInfo {
+    pub version: &'static str,
+    /// Cargo profile, "debug" or "release"
+    pub profile: &'static str,
+    pub target: &'static str,
+    /// Enabled optional cargo features
+    pub features: Vec<&'static str>,
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fastfetch-rs {} ({}, {})",
+            self.version, self.profile, self.target
+        )?;
+        if !self.features.is_empty() {
+            write!(f, " [{}]", self.features.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for VersionModule {
+    fn detect(&self, _ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        DetectionResult::Detected(ModuleInfo::Version(version_info()))
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Version
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Version of fastfetch-rs", ModuleMetadata::ALL)
+    }
+}
+
+/// Optional cargo features and whether they are compiled in
+const FEATURES: &[(&str, bool)] = &[("pci-ids", cfg!(feature = "pci-ids"))];
+
+/// Build information, fixed at compile time
+pub fn version_info() -> VersionInfo {
+    VersionInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        profile: env!("FASTFETCH_BUILD_PROFILE"),
+        target: env!("FASTFETCH_BUILD_TARGET"),
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect(),
+    }
+}
@@ -0,0 +1,175 @@
+//! Virtualization (hypervisor) detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Virtualization detection module
+#[derive(Debug)]
+pub struct VirtualizationModule;
+
+/// Hypervisor the system runs under
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VirtualizationInfo {
+    pub hypervisor: String,
+}
+
+impl fmt::Display for VirtualizationInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hypervisor)
+    }
+}
+
+impl Module for VirtualizationModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_virtualization(ctx).map(ModuleInfo::Virtualization)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Virtualization
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Hypervisor the system runs under", &["linux", "macos"])
+    }
+}
+
+/// CPUID leaf 0x40000000 vendor signatures
+#[cfg(all(target_os = "linux", any(target_arch = "x86", target_arch = "x86_64")))]
+const CPUID_SIGNATURES: &[(&str, &str)] = &[
+    ("KVMKVMKVM", "KVM"),
+    ("TCGTCGTCGTCG", "QEMU"),
+    ("VMwareVMware", "VMware"),
+    ("VBoxVBoxVBox", "VirtualBox"),
+    ("Microsoft Hv", "Hyper-V"),
+    (" lrpepyh  vr", "Parallels"),
+    ("XenVMMXenVMM", "Xen"),
+    ("bhyve bhyve ", "bhyve"),
+    ("ACRNACRNACRN", "ACRN"),
+];
+
+/// Substrings of DMI system vendor/product names identifying a hypervisor
+#[cfg(target_os = "linux")]
+const DMI_SIGNATURES: &[(&str, &str)] = &[
+    ("QEMU", "QEMU"),
+    ("KVM", "KVM"),
+    ("VMware", "VMware"),
+    ("VirtualBox", "VirtualBox"),
+    ("innotek", "VirtualBox"),
+    ("Parallels", "Parallels"),
+    ("Xen", "Xen"),
+    ("Virtual Machine", "Hyper-V"),
+    ("Google Compute Engine", "Google Compute Engine"),
+    ("Amazon EC2", "Amazon EC2"),
+];
+
+#[cfg(target_os = "linux")]
+fn detect_virtualization(ctx: &dyn SystemContext) -> DetectionResult<VirtualizationInfo> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    let from_cpuid = crate::platform::cpuid::hypervisor_signature().map(|signature| {
+        CPUID_SIGNATURES
+            .iter()
+            .find(|(sig, _)| *sig == signature)
+            .map_or(signature, |(_, name)| name.to_string())
+    });
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    let from_cpuid = None;
+
+    let hypervisor = from_cpuid
+        .or_else(|| dmi_hypervisor().map(str::to_string))
+        .or_else(|| detect_virt(ctx));
+
+    match hypervisor {
+        Some(hypervisor) => DetectionResult::Detected(VirtualizationInfo { hypervisor }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Match the DMI vendor and product strings, which also work on non-x86 guests
+#[cfg(target_os = "linux")]
+fn dmi_hypervisor() -> Option<&'static str> {
+    use crate::platform::linux::sys::dmi;
+
+    let strings = [
+        dmi::meaningful(dmi::sys_vendor),
+        dmi::meaningful(dmi::product_name),
+    ];
+    strings
+        .iter()
+        .flatten()
+        .find_map(|value| hypervisor_from_dmi(value))
+}
+
+#[cfg(target_os = "linux")]
+fn hypervisor_from_dmi(value: &str) -> Option<&'static str> {
+    DMI_SIGNATURES
+        .iter()
+        .find(|(needle, _)| value.contains(needle))
+        .map(|&(_, name)| name)
+}
+
+/// Ask `systemd-detect-virt`, which prints "none" and fails on bare metal
+#[cfg(target_os = "linux")]
+fn detect_virt(ctx: &dyn SystemContext) -> Option<String> {
+    let output = ctx.execute_command("systemd-detect-virt", &["--vm"]).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let name = match id.as_str() {
+        "" | "none" => return None,
+        "kvm" => "KVM",
+        "qemu" => "QEMU",
+        "vmware" => "VMware",
+        "oracle" => "VirtualBox",
+        "microsoft" => "Hyper-V",
+        "parallels" => "Parallels",
+        "xen" => "Xen",
+        _ => return Some(id),
+    };
+    Some(name.to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn detect_virtualization(ctx: &dyn SystemContext) -> DetectionResult<VirtualizationInfo> {
+    // kern.hv_vmm_present is 1 inside Virtualization.framework, Parallels and VMware guests
+    let present = ctx
+        .execute_command("sysctl", &["-n", "kern.hv_vmm_present"])
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "1");
+
+    if present {
+        DetectionResult::Detected(VirtualizationInfo {
+            hypervisor: "Apple Hypervisor".to_string(),
+        })
+    } else {
+        DetectionResult::Unavailable
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn detect_virtualization(_ctx: &dyn SystemContext) -> DetectionResult<VirtualizationInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypervisor_from_dmi() {
+        assert_eq!(
+            hypervisor_from_dmi("QEMU Standard PC (Q35 + ICH9, 2009)"),
+            Some("QEMU")
+        );
+        assert_eq!(
+            hypervisor_from_dmi("VMware Virtual Platform"),
+            Some("VMware")
+        );
+        assert_eq!(hypervisor_from_dmi("innotek GmbH"), Some("VirtualBox"));
+        assert_eq!(hypervisor_from_dmi("ThinkPad X1 Carbon Gen 11"), None);
+    }
+}
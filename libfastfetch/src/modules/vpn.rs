@@ -0,0 +1,163 @@
+//! VPN tunnel detection module
+//!
+//! Only interface names and tunnel types are reported. Endpoint and peer
+//! addresses are deliberately never read, so the output is safe to share.
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// VPN detection module
+#[derive(Debug)]
+pub struct VpnModule;
+
+/// Kind of tunnel behind an interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TunnelKind {
+    #[serde(rename = "wireguard")]
+    WireGuard,
+    Tailscale,
+    /// Layer 3 tun device, as used by OpenVPN and most userspace VPNs
+    Tun,
+    /// Layer 2 tap device
+    Tap,
+}
+
+impl fmt::Display for TunnelKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WireGuard => write!(f, "WireGuard"),
+            Self::Tailscale => write!(f, "Tailscale"),
+            Self::Tun => write!(f, "TUN"),
+            Self::Tap => write!(f, "TAP"),
+        }
+    }
+}
+
+/// An active tunnel interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tunnel {
+    pub interface: String,
+    pub kind: TunnelKind,
+}
+
+/// VPN information
+#[derive(Debug, Clone, Serialize)]
+pub struct VpnInfo {
+    pub tunnels: Vec<Tunnel>,
+}
+
+impl fmt::Display for VpnInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, tunnel) in self.tunnels.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} ({})", tunnel.interface, tunnel.kind)?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for VpnModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_vpn(ctx).map(ModuleInfo::Vpn)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Vpn
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Active VPN tunnels", ModuleMetadata::LINUX)
+    }
+}
+
+/// `IFF_UP` in /sys/class/net/<iface>/flags
+#[cfg(target_os = "linux")]
+const IFF_UP: u32 = 0x1;
+
+/// `IFF_TAP` in /sys/class/net/<iface>/tun_flags; tun devices set `IFF_TUN` (0x1)
+#[cfg(target_os = "linux")]
+const IFF_TAP: u32 = 0x2;
+
+#[cfg(target_os = "linux")]
+fn detect_vpn(ctx: &dyn SystemContext) -> DetectionResult<VpnInfo> {
+    use std::path::Path;
+
+    let mut interfaces = match ctx.read_dir(Path::new("/sys/class/net")) {
+        Ok(interfaces) => interfaces,
+        Err(err) => return DetectionResult::Error(err.into()),
+    };
+    interfaces.sort();
+
+    let tunnels: Vec<Tunnel> = interfaces
+        .into_iter()
+        .filter_map(|interface| {
+            let dir = Path::new("/sys/class/net").join(&interface);
+            let read_hex = |attr: &str| {
+                let value = ctx.read_file(&dir.join(attr)).ok()?;
+                u32::from_str_radix(value.trim().trim_start_matches("0x"), 16).ok()
+            };
+
+            if read_hex("flags")? & IFF_UP == 0 {
+                return None;
+            }
+
+            let uevent = ctx.read_file(&dir.join("uevent")).unwrap_or_default();
+            let kind = tunnel_kind(&interface, &uevent, read_hex("tun_flags"))?;
+            Some(Tunnel { interface, kind })
+        })
+        .collect();
+
+    if tunnels.is_empty() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(VpnInfo { tunnels })
+    }
+}
+
+/// Classify an interface from its name, uevent and tun flags (present only for tun/tap devices)
+#[cfg(target_os = "linux")]
+fn tunnel_kind(interface: &str, uevent: &str, tun_flags: Option<u32>) -> Option<TunnelKind> {
+    if uevent.lines().any(|line| line == "DEVTYPE=wireguard") {
+        return Some(TunnelKind::WireGuard);
+    }
+
+    let tun_flags = tun_flags?;
+    // tailscaled creates a plain tun device named tailscale0
+    Some(if interface.starts_with("tailscale") {
+        TunnelKind::Tailscale
+    } else if tun_flags & IFF_TAP != 0 {
+        TunnelKind::Tap
+    } else {
+        TunnelKind::Tun
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_vpn(_ctx: &dyn SystemContext) -> DetectionResult<VpnInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tunnel_kind() {
+        let wg = "DEVTYPE=wireguard\nINTERFACE=wg0\nIFINDEX=5\n";
+        assert_eq!(tunnel_kind("wg0", wg, None), Some(TunnelKind::WireGuard));
+        assert_eq!(
+            tunnel_kind("tailscale0", "INTERFACE=tailscale0\n", Some(0x1001)),
+            Some(TunnelKind::Tailscale)
+        );
+        assert_eq!(tunnel_kind("tun0", "", Some(0x1001)), Some(TunnelKind::Tun));
+        assert_eq!(tunnel_kind("tap0", "", Some(0x1002)), Some(TunnelKind::Tap));
+        assert_eq!(tunnel_kind("eth0", "INTERFACE=eth0\n", None), None);
+    }
+}
@@ -0,0 +1,230 @@
+//! Window manager information detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Window manager detection module
+#[derive(Debug)]
+pub struct WmModule;
+
+/// Display server protocol used by the current session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayProtocol {
+    Wayland,
+    X11,
+}
+
+impl fmt::Display for DisplayProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wayland => write!(f, "Wayland"),
+            Self::X11 => write!(f, "X11"),
+        }
+    }
+}
+
+/// Window manager information
+#[derive(Debug, Clone, Serialize)]
+pub struct WmInfo {
+    pub name: String,
+    pub protocol: Option<DisplayProtocol>,
+}
+
+impl fmt::Display for WmInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(protocol) = self.protocol {
+            write!(f, " ({protocol})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for WmModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_wm(ctx).map(ModuleInfo::Wm)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Wm
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Window manager", ModuleMetadata::ALL)
+    }
+}
+
+/// Process names of known window managers and compositors, mapped to display names
+#[cfg(all(unix, not(target_os = "macos")))]
+const KNOWN_WMS: &[(&str, &str)] = &[
+    ("Hyprland", "Hyprland"),
+    ("sway", "Sway"),
+    ("river", "River"),
+    ("niri", "niri"),
+    ("wayfire", "Wayfire"),
+    ("labwc", "labwc"),
+    ("weston", "Weston"),
+    ("gnome-shell", "Mutter"),
+    ("mutter", "Mutter"),
+    ("kwin_wayland", "KWin"),
+    ("kwin_x11", "KWin"),
+    ("kwin", "KWin"),
+    ("xfwm4", "Xfwm4"),
+    ("marco", "Marco"),
+    ("muffin", "Muffin"),
+    ("cinnamon", "Muffin"),
+    ("i3", "i3"),
+    ("dwm", "dwm"),
+    ("bspwm", "bspwm"),
+    ("awesome", "awesome"),
+    ("xmonad", "xmonad"),
+    ("herbstluftwm", "herbstluftwm"),
+    ("qtile", "Qtile"),
+    ("openbox", "Openbox"),
+    ("fluxbox", "Fluxbox"),
+    ("icewm", "IceWM"),
+    ("enlightenment", "Enlightenment"),
+];
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_wm(ctx: &dyn SystemContext) -> DetectionResult<WmInfo> {
+    let protocol = detect_protocol(ctx);
+
+    let name = wm_from_env(ctx)
+        .or_else(|| {
+            if protocol == Some(DisplayProtocol::X11) {
+                wm_from_x11(ctx)
+            } else {
+                None
+            }
+        })
+        .or_else(|| wm_from_processes(ctx));
+
+    match name {
+        Some(name) => DetectionResult::Detected(WmInfo { name, protocol }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Determine the session type from `XDG_SESSION_TYPE`, falling back to display sockets
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_protocol(ctx: &dyn SystemContext) -> Option<DisplayProtocol> {
+    match ctx.get_env("XDG_SESSION_TYPE").as_deref() {
+        Some("wayland") => return Some(DisplayProtocol::Wayland),
+        Some("x11") => return Some(DisplayProtocol::X11),
+        _ => {}
+    }
+
+    if ctx.get_env("WAYLAND_DISPLAY").is_some() {
+        Some(DisplayProtocol::Wayland)
+    } else if ctx.get_env("DISPLAY").is_some() {
+        Some(DisplayProtocol::X11)
+    } else {
+        None
+    }
+}
+
+/// Compositors that advertise themselves through their IPC socket variables
+#[cfg(all(unix, not(target_os = "macos")))]
+fn wm_from_env(ctx: &dyn SystemContext) -> Option<String> {
+    const ENV_MARKERS: &[(&str, &str)] = &[
+        ("HYPRLAND_INSTANCE_SIGNATURE", "Hyprland"),
+        ("SWAYSOCK", "Sway"),
+        ("NIRI_SOCKET", "niri"),
+        ("I3SOCK", "i3"),
+    ];
+
+    ENV_MARKERS
+        .iter()
+        .find(|(var, _)| ctx.get_env(var).is_some())
+        .map(|(_, name)| (*name).to_string())
+}
+
+/// Query the EWMH `_NET_SUPPORTING_WM_CHECK` window for the WM name
+#[cfg(all(unix, not(target_os = "macos")))]
+fn wm_from_x11(ctx: &dyn SystemContext) -> Option<String> {
+    let output = ctx
+        .execute_command("xprop", &["-root", "-notype", "_NET_SUPPORTING_WM_CHECK"])
+        .ok()?;
+    if !output.success {
+        return None;
+    }
+
+    // Format: "_NET_SUPPORTING_WM_CHECK: window id # 0x1200003"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let window_id = stdout.split_whitespace().last()?.to_string();
+    if !window_id.starts_with("0x") {
+        return None;
+    }
+
+    let output = ctx
+        .execute_command("xprop", &["-id", &window_id, "-notype", "_NET_WM_NAME"])
+        .ok()?;
+    if !output.success {
+        return None;
+    }
+
+    // Format: "_NET_WM_NAME = \"i3\""
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (_, value) = stdout.split_once('=')?;
+    let name = value.trim().trim_matches('"');
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(normalize_wm_name(name))
+    }
+}
+
+/// Scan running processes for a known window manager
+#[cfg(all(unix, not(target_os = "macos")))]
+fn wm_from_processes(ctx: &dyn SystemContext) -> Option<String> {
+    let output = ctx.execute_command("ps", &["-e", "-o", "comm="]).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let processes: Vec<&str> = stdout.lines().map(str::trim).collect();
+
+    KNOWN_WMS
+        .iter()
+        .find(|(process, _)| processes.contains(process))
+        .map(|(_, name)| (*name).to_string())
+}
+
+/// Map a reported WM name onto the canonical display name where known
+#[cfg(all(unix, not(target_os = "macos")))]
+fn normalize_wm_name(name: &str) -> String {
+    KNOWN_WMS
+        .iter()
+        .find(|(process, _)| process.eq_ignore_ascii_case(name))
+        .map(|(_, display)| (*display).to_string())
+        .unwrap_or_else(|| name.to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn detect_wm(_ctx: &dyn SystemContext) -> DetectionResult<WmInfo> {
+    DetectionResult::Detected(WmInfo {
+        name: "Quartz Compositor".to_string(),
+        protocol: None,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn detect_wm(_ctx: &dyn SystemContext) -> DetectionResult<WmInfo> {
+    DetectionResult::Detected(WmInfo {
+        name: "Desktop Window Manager".to_string(),
+        protocol: None,
+    })
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn detect_wm(_ctx: &dyn SystemContext) -> DetectionResult<WmInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
@@ -0,0 +1,140 @@
+//! Window manager decoration theme detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// WM theme detection module
+#[derive(Debug)]
+pub struct WmThemeModule;
+
+/// Window decoration theme of the running window manager
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WmThemeInfo {
+    pub theme: String,
+    /// GNOME Shell theme from the user-theme extension
+    pub shell_theme: Option<String>,
+}
+
+impl fmt::Display for WmThemeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.theme)?;
+        if let Some(shell) = &self.shell_theme {
+            write!(f, " (Shell: {shell})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Module for WmThemeModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_wm_theme(ctx).map(ModuleInfo::WmTheme)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::WmTheme
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Window decoration theme", &["linux", "freebsd"])
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect_wm_theme(ctx: &dyn SystemContext) -> DetectionResult<WmThemeInfo> {
+    use super::wm::WmModule;
+    use crate::platform::{
+        desktop::{gsettings_get, read_ini},
+        xdg,
+    };
+
+    // Where the theme lives depends entirely on which WM is running
+    let wm = match WmModule.detect(ctx) {
+        DetectionResult::Detected(ModuleInfo::Wm(wm)) => wm,
+        DetectionResult::Error(err) => return DetectionResult::Error(err),
+        _ => return DetectionResult::Unavailable,
+    };
+
+    let mut shell_theme = None;
+    let theme = match wm.name.as_str() {
+        // Mutter leaves decorations to GTK's client-side theme
+        "Mutter" => {
+            shell_theme = gsettings_get(ctx, "org.gnome.shell.extensions.user-theme", "name");
+            gsettings_get(ctx, "org.gnome.desktop.interface", "gtk-theme")
+        }
+        "KWin" => read_ini(ctx, &["kwinrc"], &[])
+            .and_then(|kwinrc| {
+                kwinrc
+                    .get("org.kde.kdecoration2", "theme")
+                    .map(kwin_decoration_name)
+            })
+            // Breeze is KWin's built-in default when nothing is configured
+            .or_else(|| Some("Breeze".to_string())),
+        "Xfwm4" => xfconf_get(ctx, "xfwm4", "/general/theme"),
+        "Marco" => gsettings_get(ctx, "org.mate.Marco.general", "theme"),
+        "Muffin" => gsettings_get(ctx, "org.cinnamon.desktop.wm.preferences", "theme"),
+        "Openbox" => {
+            let candidates = xdg::config_candidates(ctx, &["openbox/rc.xml"], &[]);
+            xdg::read_first(ctx, &candidates).and_then(|(_, rc)| openbox_theme(&rc))
+        }
+        _ => None,
+    };
+
+    match theme {
+        Some(theme) => DetectionResult::Detected(WmThemeInfo { theme, shell_theme }),
+        None => DetectionResult::Unavailable,
+    }
+}
+
+/// Aurorae themes are stored as "__aurorae__svg__<Name>"
+#[cfg(all(unix, not(target_os = "macos")))]
+fn kwin_decoration_name(theme: &str) -> String {
+    theme
+        .strip_prefix("__aurorae__svg__")
+        .unwrap_or(theme)
+        .to_string()
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn xfconf_get(ctx: &dyn SystemContext, channel: &str, property: &str) -> Option<String> {
+    let output = ctx
+        .execute_command("xfconf-query", &["-c", channel, "-p", property])
+        .ok()?;
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.success && !value.is_empty()).then_some(value)
+}
+
+/// Extract `<theme><name>…</name></theme>` from Openbox's rc.xml
+#[cfg(all(unix, not(target_os = "macos")))]
+fn openbox_theme(rc: &str) -> Option<String> {
+    let theme = &rc[rc.find("<theme>")?..];
+    let name = &theme[theme.find("<name>")? + "<name>".len()..];
+    let name = name[..name.find("</name>")?].trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn detect_wm_theme(_ctx: &dyn SystemContext) -> DetectionResult<WmThemeInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, unix, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wm_theme_parsers() {
+        assert_eq!(
+            kwin_decoration_name("__aurorae__svg__Sweet-Dark"),
+            "Sweet-Dark"
+        );
+        assert_eq!(kwin_decoration_name("Breeze"), "Breeze");
+
+        let rc = "<openbox_config>\n  <theme>\n    <name>Clearlooks</name>\n    <titleLayout>NLIMC</titleLayout>\n  </theme>\n</openbox_config>\n";
+        assert_eq!(openbox_theme(rc).as_deref(), Some("Clearlooks"));
+        assert_eq!(openbox_theme("<openbox_config/>"), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Compressed swap (zram/zswap) detection module
+
+use crate::{
+    DetectionResult, Module, ModuleInfo, ModuleKind, ModuleMetadata, context::SystemContext,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Zram detection module
+#[derive(Debug)]
+pub struct ZramModule;
+
+/// A configured zram device
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ZramDevice {
+    pub name: String,
+    pub algorithm: Option<String>,
+    /// Uncompressed size of the data stored in the device
+    pub original_bytes: u64,
+    /// Size of that data after compression
+    pub compressed_bytes: u64,
+}
+
+/// Compressed swap information
+#[derive(Debug, Clone, Serialize)]
+pub struct ZramInfo {
+    pub devices: Vec<ZramDevice>,
+    /// zswap compressor, if zswap is enabled
+    pub zswap: Option<String>,
+}
+
+impl fmt::Display for ZramInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::util::format_bytes;
+
+        let mut lines = Vec::new();
+        for device in &self.devices {
+            let mut line = format!(
+                "{}: {} / {} compressed",
+                device.name,
+                format_bytes(device.original_bytes),
+                format_bytes(device.compressed_bytes)
+            );
+            if let Some(algorithm) = &device.algorithm {
+                line.push_str(&format!(" ({algorithm})"));
+            }
+            lines.push(line);
+        }
+        if let Some(compressor) = &self.zswap {
+            lines.push(format!("zswap ({compressor})"));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl Module for ZramModule {
+    fn detect(&self, ctx: &dyn SystemContext) -> DetectionResult<ModuleInfo> {
+        detect_zram(ctx).map(ModuleInfo::Zram)
+    }
+
+    fn kind(&self) -> ModuleKind {
+        ModuleKind::Zram
+    }
+
+    fn metadata(&self) -> ModuleMetadata {
+        ModuleMetadata::new("Compressed swap usage", ModuleMetadata::LINUX)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_zram(ctx: &dyn SystemContext) -> DetectionResult<ZramInfo> {
+    use std::path::Path;
+
+    let mut names: Vec<String> = ctx
+        .read_dir(Path::new("/sys/block"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| name.starts_with("zram"))
+        .collect();
+    names.sort();
+
+    let devices: Vec<ZramDevice> = names
+        .into_iter()
+        .filter_map(|name| {
+            let dir = Path::new("/sys/block").join(&name);
+            let read = |attr: &str| ctx.read_file(&dir.join(attr)).ok();
+
+            // Devices that were never given a size are unused
+            let disksize: u64 = read("disksize")?.trim().parse().ok()?;
+            if disksize == 0 {
+                return None;
+            }
+
+            let (original_bytes, compressed_bytes) =
+                read("mm_stat").and_then(|stat| parse_mm_stat(&stat))?;
+            Some(ZramDevice {
+                algorithm: read("comp_algorithm").and_then(|algs| selected_algorithm(&algs)),
+                name,
+                original_bytes,
+                compressed_bytes,
+            })
+        })
+        .collect();
+
+    let parameter = |name: &str| {
+        ctx.read_file(&Path::new("/sys/module/zswap/parameters").join(name))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+    let zswap = (parameter("enabled").as_deref() == Some("Y"))
+        .then(|| parameter("compressor").unwrap_or_else(|| "enabled".to_string()));
+
+    if devices.is_empty() && zswap.is_none() {
+        DetectionResult::Unavailable
+    } else {
+        DetectionResult::Detected(ZramInfo { devices, zswap })
+    }
+}
+
+/// First two columns of mm_stat: original and compressed data size in bytes
+#[cfg(target_os = "linux")]
+fn parse_mm_stat(stat: &str) -> Option<(u64, u64)> {
+    let mut fields = stat.split_whitespace().map(str::parse::<u64>);
+    Some((fields.next()?.ok()?, fields.next()?.ok()?))
+}
+
+/// Pick the bracketed entry of "lzo lzo-rle lz4 [zstd]"
+#[cfg(target_os = "linux")]
+fn selected_algorithm(algorithms: &str) -> Option<String> {
+    algorithms
+        .split_whitespace()
+        .find_map(|alg| alg.strip_prefix('[')?.strip_suffix(']'))
+        .map(str::to_string)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_zram(_ctx: &dyn SystemContext) -> DetectionResult<ZramInfo> {
+    use crate::error::Error;
+    DetectionResult::Error(Error::UnsupportedPlatform)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_zram_attributes() {
+        let mm_stat = "1287647232 312475648 325423104 0 325423104 18231 0 1024 8\n";
+        assert_eq!(parse_mm_stat(mm_stat), Some((1287647232, 312475648)));
+        assert_eq!(
+            selected_algorithm("lzo lzo-rle lz4 lz4hc 842 [zstd]\n").as_deref(),
+            Some("zstd")
+        );
+        assert_eq!(selected_algorithm("lzo lz4"), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! Percentage bars for capacity-style modules
+//!
+//! Memory, Swap, Battery and Brightness can show how full they are as a bar
+//! like `[████████──] 78%`, either instead of their value or in front of it.
+//! The bar's width and characters are display settings shared by all
+//! modules.
+
+use std::{fmt, str::FromStr};
+
+/// Whether and how a module's percentage is drawn as a bar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BarMode {
+    /// Only the module's usual value
+    #[default]
+    Off,
+    /// A bar and the percentage instead of the value
+    On,
+    /// A bar in front of the value
+    Prefix,
+}
+
+impl FromStr for BarMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" | "false" => Ok(Self::Off),
+            "on" | "true" => Ok(Self::On),
+            "prefix" => Ok(Self::Prefix),
+            _ => Err(format!(
+                "Unknown bar mode: {s} (expected off, on or prefix)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BarMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::On => write!(f, "on"),
+            Self::Prefix => write!(f, "prefix"),
+        }
+    }
+}
+
+/// How bars are drawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarStyle {
+    /// Number of cells between the brackets
+    pub width: usize,
+    /// Text of a filled cell
+    pub filled: String,
+    /// Text of an empty cell
+    pub empty: String,
+}
+
+impl Default for BarStyle {
+    fn default() -> Self {
+        Self {
+            width: 10,
+            filled: "█".to_string(),
+            empty: "─".to_string(),
+        }
+    }
+}
+
+impl BarStyle {
+    /// A bar filled to `percent`, e.g. `[████████──]`
+    ///
+    /// Percentages outside 0 to 100 draw an empty or a full bar.
+    pub fn bar(&self, percent: f64) -> String {
+        let ratio = (percent / 100.0).clamp(0.0, 1.0);
+        let filled = (ratio * self.width as f64).round() as usize;
+        format!(
+            "[{}{}]",
+            self.filled.repeat(filled),
+            self.empty.repeat(self.width - filled)
+        )
+    }
+
+    /// One line of a value shown as `mode` asks, given its percentage
+    pub fn apply(&self, mode: BarMode, percent: f64, line: &str) -> String {
+        match mode {
+            BarMode::Off => line.to_string(),
+            BarMode::On => format!("{} {percent:.0}%", self.bar(percent)),
+            BarMode::Prefix => format!("{} {line}", self.bar(percent)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar() {
+        let style = BarStyle::default();
+        assert_eq!(style.bar(78.0), "[████████──]");
+        assert_eq!(style.bar(0.0), "[──────────]");
+        assert_eq!(style.bar(140.0), "[██████████]");
+
+        let ascii = BarStyle {
+            width: 4,
+            filled: "#".to_string(),
+            empty: ".".to_string(),
+        };
+        assert_eq!(
+            ascii.apply(BarMode::On, 50.0, "4 GiB / 8 GiB"),
+            "[##..] 50%"
+        );
+        assert_eq!(
+            ascii.apply(BarMode::Prefix, 50.0, "4 GiB / 8 GiB"),
+            "[##..] 4 GiB / 8 GiB"
+        );
+        assert_eq!("PREFIX".parse::<BarMode>(), Ok(BarMode::Prefix));
+        assert!("half".parse::<BarMode>().is_err());
+    }
+}
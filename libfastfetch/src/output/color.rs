@@ -1,8 +1,90 @@
 //! Custom ANSI color code support
 //!
 //! Provides color formatting for terminal output without external dependencies.
+//!
+//! Whether escapes are written at all is decided once per process from a
+//! [`ColorMode`]: by default only when stdout is a terminal, unless
+//! `NO_COLOR` or `CLICOLOR_FORCE` says otherwise.
+
+use crate::context::SystemContext;
+use std::{
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use unicode_width::UnicodeWidthChar;
+
+/// Whether [`StyledString::format`] and logos write ANSI escapes
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turn ANSI escapes on or off for the rest of the process
+pub fn set_colors_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether output is colored; on unless [`set_colors_enabled`] turned it off
+pub fn colors_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// When to color output, as chosen with `--color`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Color a terminal, following `NO_COLOR` and `CLICOLOR_FORCE`
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    /// Whether to color output going to a terminal (`is_terminal`) or not
+    ///
+    /// In auto mode a non-empty `NO_COLOR` turns colors off, and otherwise
+    /// a `CLICOLOR_FORCE` other than "0" turns them on even in a pipe.
+    pub fn resolve(self, ctx: &dyn SystemContext, is_terminal: bool) -> bool {
+        self.decide(
+            ctx.get_env("NO_COLOR").as_deref(),
+            ctx.get_env("CLICOLOR_FORCE").as_deref(),
+            is_terminal,
+        )
+    }
+
+    fn decide(self, no_color: Option<&str>, force: Option<&str>, is_terminal: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto if no_color.is_some_and(|value| !value.is_empty()) => false,
+            Self::Auto if force.is_some_and(|value| !value.is_empty() && value != "0") => true,
+            Self::Auto => is_terminal,
+        }
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
 
-use std::fmt;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!(
+                "Unknown color mode: {s} (expected auto, always or never)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
 
 /// ANSI color code for terminal styling
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,6 +106,8 @@ pub enum Color {
     BrightCyan,
     BrightWhite,
     Rgb(u8, u8, u8),
+    /// A color of the 256-color palette, for terminals without truecolor
+    Indexed(u8),
 }
 
 impl Color {
@@ -46,17 +130,124 @@ impl Color {
             Self::BrightMagenta => "\x1b[95m",
             Self::BrightCyan => "\x1b[96m",
             Self::BrightWhite => "\x1b[97m",
-            Self::Rgb(_, _, _) => "", // Handled separately
+            Self::Rgb(_, _, _) | Self::Indexed(_) => "", // Handled separately
         }
     }
 
-    /// Format RGB color as ANSI escape sequence
+    /// Color `index` of the 256-color palette: one of the 16 named colors,
+    /// or the palette entry itself so terminals without truecolor show it
+    pub const fn from_index(index: u8) -> Self {
+        const NAMED: [Color; 16] = [
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::White,
+            Color::BrightBlack,
+            Color::BrightRed,
+            Color::BrightGreen,
+            Color::BrightYellow,
+            Color::BrightBlue,
+            Color::BrightMagenta,
+            Color::BrightCyan,
+            Color::BrightWhite,
+        ];
+
+        match index {
+            0..=15 => NAMED[index as usize],
+            _ => Self::Indexed(index),
+        }
+    }
+
+    /// Format RGB or palette color as ANSI escape sequence
     pub fn fg_rgb_code(&self) -> Option<String> {
         match self {
             Self::Rgb(r, g, b) => Some(format!("\x1b[38;2;{r};{g};{b}m")),
+            Self::Indexed(index) => Some(format!("\x1b[38;5;{index}m")),
             _ => None,
         }
     }
+
+    /// Get the ANSI escape code for background color
+    pub fn bg_code(self) -> String {
+        let code = match self {
+            Self::Black => "\x1b[40m",
+            Self::Red => "\x1b[41m",
+            Self::Green => "\x1b[42m",
+            Self::Yellow => "\x1b[43m",
+            Self::Blue => "\x1b[44m",
+            Self::Magenta => "\x1b[45m",
+            Self::Cyan => "\x1b[46m",
+            Self::White => "\x1b[47m",
+            Self::BrightBlack => "\x1b[100m",
+            Self::BrightRed => "\x1b[101m",
+            Self::BrightGreen => "\x1b[102m",
+            Self::BrightYellow => "\x1b[103m",
+            Self::BrightBlue => "\x1b[104m",
+            Self::BrightMagenta => "\x1b[105m",
+            Self::BrightCyan => "\x1b[106m",
+            Self::BrightWhite => "\x1b[107m",
+            Self::Rgb(r, g, b) => return format!("\x1b[48;2;{r};{g};{b}m"),
+            Self::Indexed(index) => return format!("\x1b[48;5;{index}m"),
+        };
+        code.to_string()
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    /// Parse a color name such as "red" or "bright_blue", a "#rrggbb" hex
+    /// code or a 256-color palette index such as "214" or "color214"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace(['-', ' '], "_");
+        if let Ok(index) = name.parse::<u8>() {
+            return Ok(Self::from_index(index));
+        }
+        if let Some(index) = name.strip_prefix("color") {
+            return index
+                .parse()
+                .map(Self::from_index)
+                .map_err(|_| format!("Invalid palette color: {s} (expected color0 to color255)"));
+        }
+        let color = match name.as_str() {
+            "black" => Self::Black,
+            "red" => Self::Red,
+            "green" => Self::Green,
+            "yellow" => Self::Yellow,
+            "blue" => Self::Blue,
+            "magenta" => Self::Magenta,
+            "cyan" => Self::Cyan,
+            "white" => Self::White,
+            "bright_black" => Self::BrightBlack,
+            "bright_red" => Self::BrightRed,
+            "bright_green" => Self::BrightGreen,
+            "bright_yellow" => Self::BrightYellow,
+            "bright_blue" => Self::BrightBlue,
+            "bright_magenta" => Self::BrightMagenta,
+            "bright_cyan" => Self::BrightCyan,
+            "bright_white" => Self::BrightWhite,
+            hex => {
+                let digits = hex
+                    .strip_prefix('#')
+                    .filter(|d| d.len() == 6)
+                    .ok_or_else(|| format!("Unknown color: {s}"))?;
+                // Checked first so slicing below stays on char boundaries
+                if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(format!("Invalid hex color: {s}"));
+                }
+                let channel = |i: usize| {
+                    u8::from_str_radix(&digits[i..i + 2], 16)
+                        .map_err(|_| format!("Invalid hex color: {s}"))
+                };
+                Self::Rgb(channel(0)?, channel(2)?, channel(4)?)
+            }
+        };
+        Ok(color)
+    }
 }
 
 /// ANSI style modifiers
@@ -66,6 +257,9 @@ pub enum Style {
     Dim,
     Italic,
     Underline,
+    Blink,
+    Reverse,
+    Strikethrough,
     Reset,
 }
 
@@ -76,17 +270,106 @@ impl Style {
             Self::Dim => "\x1b[2m",
             Self::Italic => "\x1b[3m",
             Self::Underline => "\x1b[4m",
+            Self::Blink => "\x1b[5m",
+            Self::Reverse => "\x1b[7m",
+            Self::Strikethrough => "\x1b[9m",
             Self::Reset => "\x1b[0m",
         }
     }
 }
 
+impl FromStr for Style {
+    type Err = String;
+
+    /// Parse "bold", "dim", "italic", "underline", "blink", "reverse" or
+    /// "strikethrough"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "bold" => Ok(Self::Bold),
+            "dim" => Ok(Self::Dim),
+            "italic" => Ok(Self::Italic),
+            "underline" => Ok(Self::Underline),
+            "blink" => Ok(Self::Blink),
+            "reverse" | "inverse" => Ok(Self::Reverse),
+            "strikethrough" | "strike" => Ok(Self::Strikethrough),
+            _ => Err(format!("Unknown style: {s}")),
+        }
+    }
+}
+
+/// Colors and modifiers applied together, written like "bold cyan",
+/// "bold underline #ff8000" or "black on yellow"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextStyle {
+    pub color: Option<Color>,
+    /// Background color, written after "on"
+    pub background: Option<Color>,
+    /// Modifiers in the order they were written
+    pub styles: Vec<Style>,
+}
+
+impl TextStyle {
+    /// Style `text`
+    pub fn apply<S: Into<String>>(&self, text: S) -> StyledString {
+        let mut styled = StyledString::new(text);
+        styled.fg_color = self.color;
+        styled.bg_color = self.background;
+        for &style in &self.styles {
+            styled = styled.style(style);
+        }
+        styled
+    }
+}
+
+impl From<Color> for TextStyle {
+    fn from(color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..Self::default()
+        }
+    }
+}
+
+impl FromStr for TextStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut text_style = Self::default();
+        let mut words = s.split_whitespace();
+        while let Some(word) = words.next() {
+            if word.eq_ignore_ascii_case("on") {
+                let color = words
+                    .next()
+                    .ok_or_else(|| format!("Expected a background color after \"on\" in {s}"))?;
+                let color = color
+                    .parse()
+                    .map_err(|_| format!("Unknown background color: {color}"))?;
+                text_style.background = Some(color);
+                continue;
+            }
+            match word.parse::<Style>() {
+                Ok(style) if !text_style.styles.contains(&style) => text_style.styles.push(style),
+                Ok(_) => {}
+                Err(_) => {
+                    let color = word
+                        .parse()
+                        .map_err(|_| format!("Unknown color or style: {word}"))?;
+                    text_style.color = Some(color);
+                }
+            }
+        }
+        Ok(text_style)
+    }
+}
+
 /// A styled string with color and style information
 #[derive(Debug, Clone)]
 pub struct StyledString {
     text: String,
     fg_color: Option<Color>,
-    style: Option<Style>,
+    bg_color: Option<Color>,
+    /// Modifiers in the order they were added
+    styles: Vec<Style>,
 }
 
 impl StyledString {
@@ -95,7 +378,8 @@ impl StyledString {
         Self {
             text: text.into(),
             fg_color: None,
-            style: None,
+            bg_color: None,
+            styles: Vec::new(),
         }
     }
 
@@ -105,18 +389,36 @@ impl StyledString {
         self
     }
 
-    /// Set style
+    /// Set background color
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg_color = Some(color);
+        self
+    }
+
+    /// Add a style on top of those already set, e.g.
+    /// `.style(Style::Bold).style(Style::Underline)`
     pub fn style(mut self, style: Style) -> Self {
-        self.style = Some(style);
+        if !self.styles.contains(&style) {
+            self.styles.push(style);
+        }
         self
     }
 
-    /// Format the string with ANSI codes
+    /// Format the string with ANSI codes, or as plain text when colors are
+    /// turned off
     pub fn format(&self) -> String {
+        self.paint(colors_enabled())
+    }
+
+    /// The string with ANSI codes when `colors` is set
+    pub fn paint(&self, colors: bool) -> String {
+        if !colors {
+            return self.text.clone();
+        }
         let mut result = String::new();
 
-        // Add style
-        if let Some(style) = self.style {
+        // Add styles
+        for style in &self.styles {
             result.push_str(style.code());
         }
 
@@ -129,11 +431,16 @@ impl StyledString {
             }
         }
 
+        // Add background color
+        if let Some(color) = self.bg_color {
+            result.push_str(&color.bg_code());
+        }
+
         // Add text
         result.push_str(&self.text);
 
         // Reset if any styling was applied
-        if self.fg_color.is_some() || self.style.is_some() {
+        if self.fg_color.is_some() || self.bg_color.is_some() || !self.styles.is_empty() {
             result.push_str(Style::Reset.code());
         }
 
@@ -147,6 +454,113 @@ impl fmt::Display for StyledString {
     }
 }
 
+/// Part of a string as a terminal sees it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Piece<'a> {
+    /// An ANSI escape sequence, which takes up no room
+    Escape(&'a str),
+    /// A character shown on screen
+    Char(char),
+}
+
+/// `text` split into escape sequences and the characters between them
+pub(crate) fn pieces(text: &str) -> impl Iterator<Item = Piece<'_>> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let c = rest.chars().next()?;
+        let len = match c {
+            '\x1b' => escape_len(rest),
+            c => c.len_utf8(),
+        };
+        let (piece, tail) = rest.split_at(len);
+        rest = tail;
+        Some(match c {
+            '\x1b' => Piece::Escape(piece),
+            c => Piece::Char(c),
+        })
+    })
+}
+
+/// Length in bytes of the escape sequence at the start of `text`
+fn escape_len(text: &str) -> usize {
+    let body = &text[1..];
+    let len = match body.chars().next() {
+        // CSI: parameters up to a final byte in '@'..='~', e.g. "\x1b[38;2;255;0;0m"
+        Some('[') => body[1..]
+            .find(|c| ('@'..='~').contains(&c))
+            .map(|end| end + 2),
+        // OSC, e.g. a hyperlink: up to BEL or ESC '\\'
+        Some(']') => {
+            let bel = body.find('\x07').map(|end| end + 1);
+            let st = body.find("\x1b\\").map(|end| end + 2);
+            bel.into_iter().chain(st).min()
+        }
+        // Any other escape is a single character
+        Some(c) => Some(c.len_utf8()),
+        None => Some(0),
+    };
+    // An unfinished sequence swallows the rest of the text
+    1 + len.unwrap_or(body.len())
+}
+
+/// Columns `c` takes up in a terminal: 2 for wide characters such as CJK
+/// and most emoji, 0 for combining marks and control characters
+pub fn char_width(c: char) -> usize {
+    UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Columns `text` takes up in a terminal, skipping ANSI escape sequences
+pub fn visible_width(text: &str) -> usize {
+    pieces(text)
+        .map(|piece| match piece {
+            Piece::Escape(_) => 0,
+            Piece::Char(c) => char_width(c),
+        })
+        .sum()
+}
+
+/// `text` without its ANSI escape sequences
+pub fn strip_ansi(text: &str) -> String {
+    pieces(text)
+        .filter_map(|piece| match piece {
+            Piece::Escape(_) => None,
+            Piece::Char(c) => Some(c),
+        })
+        .collect()
+}
+
+/// `text` cut to `width` columns, ending in `…` where it was cut
+///
+/// Escape sequences are all kept, so styles still end where they did.
+pub fn truncate(text: &str, width: usize) -> String {
+    if visible_width(text) <= width {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut used = 0;
+    let mut cut = false;
+    for piece in pieces(text) {
+        match piece {
+            Piece::Escape(escape) => out.push_str(escape),
+            Piece::Char(_) if cut => {}
+            Piece::Char(c) if used + char_width(c) < width => {
+                used += char_width(c);
+                out.push(c);
+            }
+            Piece::Char(_) => {
+                cut = true;
+                if width > 0 {
+                    out.push(ELLIPSIS);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Marks where a value was cut short
+const ELLIPSIS: char = '…';
+
 /// Helper functions for common color operations
 pub mod helpers {
     use super::{Color, StyledString};
@@ -183,6 +597,122 @@ mod tests {
         assert_eq!(styled.format(), "\x1b[31mtest\x1b[0m");
     }
 
+    #[test]
+    fn test_paint() {
+        let styled = StyledString::new("test").fg(Color::Red).style(Style::Bold);
+        assert_eq!(styled.paint(true), "\x1b[1m\x1b[31mtest\x1b[0m");
+        assert_eq!(styled.paint(false), "test");
+
+        let styled = StyledString::new("test")
+            .style(Style::Bold)
+            .style(Style::Strikethrough)
+            .fg(Color::White)
+            .bg(Color::Blue);
+        assert_eq!(
+            styled.paint(true),
+            "\x1b[1m\x1b[9m\x1b[37m\x1b[44mtest\x1b[0m"
+        );
+        assert_eq!(
+            StyledString::new("x").bg(Color::Rgb(1, 2, 3)).paint(true),
+            "\x1b[48;2;1;2;3mx\x1b[0m"
+        );
+        assert_eq!("Inverse".parse::<Style>(), Ok(Style::Reverse));
+        assert_eq!("blink".parse::<Style>(), Ok(Style::Blink));
+    }
+
+    #[test]
+    fn test_color_mode() {
+        assert_eq!("Never".parse(), Ok(ColorMode::Never));
+        assert!("sometimes".parse::<ColorMode>().is_err());
+
+        let auto = ColorMode::Auto;
+        assert!(auto.decide(None, None, true));
+        assert!(!auto.decide(None, None, false));
+        assert!(!auto.decide(Some("1"), None, true));
+        assert!(auto.decide(Some(""), None, true));
+        assert!(auto.decide(None, Some("1"), false));
+        assert!(!auto.decide(None, Some("0"), false));
+        assert!(!auto.decide(Some("1"), Some("1"), false));
+        assert!(ColorMode::Always.decide(Some("1"), None, false));
+        assert!(!ColorMode::Never.decide(None, Some("1"), true));
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!("Bright-Blue".parse::<Color>(), Ok(Color::BrightBlue));
+        assert_eq!("#ff8000".parse::<Color>(), Ok(Color::Rgb(255, 128, 0)));
+        assert!("#ff80".parse::<Color>().is_err());
+        assert!("#aééb".parse::<Color>().is_err());
+        assert!("mauve".parse::<Color>().is_err());
+        assert_eq!("9".parse::<Color>(), Ok(Color::BrightRed));
+        assert_eq!("214".parse::<Color>(), Ok(Color::Indexed(214)));
+        assert_eq!("244".parse::<Color>(), Ok(Color::Indexed(244)));
+        assert!("256".parse::<Color>().is_err());
+        assert_eq!("color214".parse::<Color>(), Ok(Color::Indexed(214)));
+        assert!("color256".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_parse_text_style() {
+        assert_eq!(
+            "bold cyan".parse::<TextStyle>(),
+            Ok(TextStyle {
+                color: Some(Color::Cyan),
+                styles: vec![Style::Bold],
+                ..TextStyle::default()
+            })
+        );
+        let style: TextStyle = "bold underline blue on #202020".parse().unwrap();
+        assert_eq!(style.styles, [Style::Bold, Style::Underline]);
+        assert_eq!(style.background, Some(Color::Rgb(32, 32, 32)));
+        assert_eq!(
+            style.apply("x").paint(true),
+            "\x1b[1m\x1b[4m\x1b[34m\x1b[48;2;32;32;32mx\x1b[0m"
+        );
+        assert!("red on".parse::<TextStyle>().is_err());
+        assert_eq!("".parse::<TextStyle>(), Ok(TextStyle::default()));
+        assert!("bold mauve".parse::<TextStyle>().is_err());
+        assert_eq!(
+            "italic #ff8000"
+                .parse::<TextStyle>()
+                .unwrap()
+                .apply("x")
+                .format(),
+            "\x1b[3m\x1b[38;2;255;128;0mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_visible_width() {
+        assert_eq!(visible_width("plain"), 5);
+        assert_eq!(
+            visible_width("\x1b[1muser\x1b[0m@\x1b[38;2;255;128;0mhost\x1b[0m"),
+            9
+        );
+        assert_eq!(
+            strip_ansi("\x1b[1muser\x1b[0m@\x1b[38;2;255;128;0mhost\x1b[0m"),
+            "user@host"
+        );
+        // Wide characters take two columns, combining marks none
+        assert_eq!(visible_width("██ 日本 e\u{301}"), 9);
+        // A hyperlink's OSC sequences and a cursor movement are skipped too
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07\x1b[2Kok"),
+            "linkok"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Intel Core i7-9700K", 10), "Intel Cor…");
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(
+            truncate("\x1b[1mAMD Ryzen\x1b[0m 7", 5),
+            "\x1b[1mAMD …\x1b[0m"
+        );
+        assert_eq!(truncate("日本語", 4), "日…");
+    }
+
     #[test]
     fn test_rgb_color() {
         let color = Color::Rgb(255, 128, 0);
@@ -191,4 +721,11 @@ mod tests {
             Some("\x1b[38;2;255;128;0m".to_string())
         );
     }
+
+    #[test]
+    fn test_indexed_color() {
+        let styled = StyledString::new("x").fg(Color::Indexed(214));
+        assert_eq!(styled.paint(true), "\x1b[38;5;214mx\x1b[0m");
+        assert_eq!(Color::Indexed(0).bg_code(), "\x1b[48;5;0m");
+    }
 }
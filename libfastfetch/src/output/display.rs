@@ -0,0 +1,251 @@
+//! Layout settings for module lines
+
+use super::{
+    BarStyle, Layout, TextStyle,
+    color::{Piece, char_width, pieces},
+    visible_width,
+};
+use crate::{context::SystemContext, modules::IconSet};
+use std::{borrow::Cow, fmt, str::FromStr};
+
+/// How labels are lined up in front of their values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyAlign {
+    /// Padded on the right, so values start in one column
+    #[default]
+    Left,
+    /// Padded on the left, so labels end in one column
+    Right,
+    /// Not padded; each value follows its label directly
+    None,
+}
+
+impl FromStr for KeyAlign {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "none" => Ok(Self::None),
+            _ => Err(format!(
+                "Unknown key alignment: {s} (expected left, right or none)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for KeyAlign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "left"),
+            Self::Right => write!(f, "right"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Options controlling how each "label: value" line is laid out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Text between a label and its value
+    pub separator: String,
+    pub key_align: KeyAlign,
+    /// Width labels are padded to; defaults to the widest label shown
+    pub key_width: Option<usize>,
+    /// Style of every label, unless a module sets its own
+    pub key_style: TextStyle,
+    /// Style of every value, unless a module sets its own
+    pub value_style: TextStyle,
+    /// Leave out modules whose detection failed
+    pub hide_errors: bool,
+    /// Leave out modules with nothing to show
+    pub hide_unavailable: bool,
+    /// Show how long each module's detection took, and the total
+    pub stat: bool,
+    /// Columns a line may take up, logo included; longer values wrap
+    pub width: Option<usize>,
+    /// How percentage bars are drawn
+    pub bar: BarStyle,
+    /// End with two rows of swatches of the terminal's 16 colors
+    pub color_blocks: bool,
+    /// Glyphs put in front of labels
+    pub icons: IconSet,
+    /// Draw a box around the module lines, with the title in its top border
+    pub boxed: bool,
+    /// Where the logo goes and how the modules are arranged
+    pub layout: Layout,
+    /// Show every value on one line, without labels or logo
+    pub oneline: bool,
+    /// Text between the values on that line
+    pub delimiter: String,
+    /// Columns a value line may take up before it is cut short with `…`
+    pub max_length: Option<usize>,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            separator: DEFAULT_SEPARATOR.to_string(),
+            key_align: KeyAlign::default(),
+            key_width: None,
+            key_style: TextStyle::default(),
+            value_style: TextStyle::default(),
+            hide_errors: false,
+            hide_unavailable: false,
+            stat: false,
+            width: None,
+            bar: BarStyle::default(),
+            color_blocks: false,
+            icons: IconSet::default(),
+            boxed: false,
+            layout: Layout::default(),
+            oneline: false,
+            delimiter: DEFAULT_DELIMITER.to_string(),
+            max_length: None,
+        }
+    }
+}
+
+/// Separator used when none is configured
+pub const DEFAULT_SEPARATOR: &str = ": ";
+
+/// Delimiter between values on one line when none is configured
+pub const DEFAULT_DELIMITER: &str = " | ";
+
+impl DisplayOptions {
+    /// Whether `module` is left out of the output
+    pub(crate) fn hides(&self, module: &super::RenderedModule) -> bool {
+        match (&module.value, &module.error) {
+            (Some(_), _) => false,
+            (None, Some(_)) => self.hide_errors,
+            (None, None) => self.hide_unavailable,
+        }
+    }
+
+    /// Label shown for `module`, with its icon in front if there is one
+    pub(crate) fn label<'a>(&self, module: &'a super::RenderedModule) -> Cow<'a, str> {
+        match module.kind.icon(self.icons) {
+            Some(icon) => Cow::Owned(format!("{icon} {}", module.label())),
+            None => Cow::Borrowed(module.label()),
+        }
+    }
+
+    /// `label` padded to `width` columns according to the alignment
+    pub(crate) fn key(&self, label: &str, width: usize) -> String {
+        let width = self.key_width.unwrap_or(width);
+        let padding = " ".repeat(width.saturating_sub(visible_width(label)));
+        match self.key_align {
+            KeyAlign::Left => format!("{label}{padding}"),
+            KeyAlign::Right => format!("{padding}{label}"),
+            KeyAlign::None => label.to_string(),
+        }
+    }
+}
+
+/// Narrowest column values are wrapped to
+pub const MIN_VALUE_WIDTH: usize = 20;
+
+/// A width asked for with `--width` or `display.width`: 0 to turn wrapping
+/// off, or at least [`MIN_VALUE_WIDTH`] columns
+pub fn check_width(columns: usize) -> Result<usize, String> {
+    if columns != 0 && columns < MIN_VALUE_WIDTH {
+        return Err(format!(
+            "Width {columns} is too narrow (at least {MIN_VALUE_WIDTH}, or 0 to turn wrapping off)"
+        ));
+    }
+    Ok(columns)
+}
+
+/// Columns of the terminal on standard output, or `$COLUMNS` when it can't
+/// be asked
+pub fn terminal_width(ctx: &dyn SystemContext) -> Option<usize> {
+    window_columns().or_else(|| {
+        ctx.get_env("COLUMNS")?
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&columns| columns > 0)
+    })
+}
+
+#[cfg(unix)]
+fn window_columns() -> Option<usize> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    (result == 0 && size.ws_col > 0).then_some(usize::from(size.ws_col))
+}
+
+#[cfg(not(unix))]
+fn window_columns() -> Option<usize> {
+    None
+}
+
+/// `text` split into lines of at most `width` columns
+///
+/// Lines break at spaces; a word longer than a whole line is cut where the
+/// line ends. ANSI escape sequences take up no room and stay in place.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    if visible_width(text) <= width {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in text.split(' ') {
+        if line_width > 0 && line_width + 1 + visible_width(word) > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if line_width > 0 {
+            line.push(' ');
+            line_width += 1;
+        }
+
+        for piece in pieces(word) {
+            let c = match piece {
+                Piece::Escape(escape) => {
+                    line.push_str(escape);
+                    continue;
+                }
+                Piece::Char(c) => c,
+            };
+            if line_width > 0 && line_width + char_width(c) > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(c);
+            line_width += char_width(c);
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_width() {
+        assert_eq!(check_width(0), Ok(0));
+        assert_eq!(check_width(MIN_VALUE_WIDTH), Ok(MIN_VALUE_WIDTH));
+        assert!(check_width(10).is_err());
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("AMD Ryzen 7", 20), ["AMD Ryzen 7"]);
+        assert_eq!(
+            wrap("AMD Ryzen 7 5800X 8-Core Processor", 16),
+            ["AMD Ryzen 7", "5800X 8-Core", "Processor"]
+        );
+        assert_eq!(wrap("abcdefghij kl", 4), ["abcd", "efgh", "ij", "kl"]);
+        assert_eq!(
+            wrap("\x1b[1mbold\x1b[0m text", 4),
+            ["\x1b[1mbold\x1b[0m", "text"]
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Reports across several machines for the `inventory` subcommand
+//!
+//! Each host's `--format json` output is collected into a [`HostReport`]. The
+//! reports are shown as a table with one row per host and one column per
+//! module, or written as a single document in the structured formats:
+//!
+//! ```yaml
+//! hosts:
+//! - host: nas
+//!   status: ok
+//!   modules:
+//!     os: Debian GNU/Linux 12 (bookworm) x86_64
+//!     kernel: Linux 6.1.0-18-amd64
+//! - host: pi
+//!   status: error
+//!   error: 'ssh: connect to host pi port 22: No route to host'
+//! ```
+//!
+//! The `pipe` format prefixes each `id=value` line with the host, e.g.
+//! `nas:os=Debian GNU/Linux 12 (bookworm) x86_64`; hosts that failed are
+//! left out. `csv` has a row per host and module, and one row with the
+//! error for a host that failed.
+
+use super::{
+    OutputFormat,
+    snapshot::Snapshot,
+    structured::{Status, csv_field, serialize},
+    visible_width,
+};
+use crate::ModuleKind;
+use serde::{Serialize, Serializer};
+
+/// What one host reported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostReport {
+    pub host: String,
+    /// Module values by id in display order, or why the host couldn't be queried
+    pub result: Result<Vec<(String, String)>, String>,
+}
+
+impl HostReport {
+    /// Report from the output of `fastfetch-rs --format json` on `host`
+    ///
+    /// Modules without a value are left out. Lines of a multi-line value,
+    /// and the values of repeated modules, are joined with ", ".
+    pub fn from_json(host: impl Into<String>, output: &str) -> Self {
+        let snapshot = match Snapshot::parse(output, OutputFormat::Json) {
+            Ok(snapshot) => snapshot,
+            Err(err) => return Self::failed(host, format!("Unreadable results: {err}")),
+        };
+        let mut values: Vec<(String, String)> = Vec::new();
+        let shown = snapshot
+            .modules
+            .into_iter()
+            .filter(|entry| entry.status == Status::Ok);
+        for entry in shown {
+            let Some(value) = entry.value else { continue };
+            let value = value.lines().collect::<Vec<_>>().join(", ");
+            match values.iter_mut().find(|(known, _)| *known == entry.module) {
+                Some((_, existing)) => {
+                    existing.push_str(", ");
+                    existing.push_str(&value);
+                }
+                None => values.push((entry.module, value)),
+            }
+        }
+        Self {
+            host: host.into(),
+            result: Ok(values),
+        }
+    }
+
+    /// Report for a host that couldn't be queried
+    pub fn failed(host: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            result: Err(error.into()),
+        }
+    }
+}
+
+/// One host as written to a structured document
+#[derive(Serialize)]
+struct HostRecord<'a> {
+    host: &'a str,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    #[serde(
+        skip_serializing_if = "<[_]>::is_empty",
+        serialize_with = "ordered_map"
+    )]
+    modules: &'a [(String, String)],
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    hosts: Vec<HostRecord<'a>>,
+}
+
+/// Write the reports in `format`
+pub fn render_inventory(reports: &[HostReport], format: OutputFormat) -> String {
+    let document = Document {
+        hosts: reports
+            .iter()
+            .map(|report| HostRecord {
+                host: &report.host,
+                status: match report.result {
+                    Ok(_) => Status::Ok,
+                    Err(_) => Status::Error,
+                },
+                error: report.result.as_ref().err().map(String::as_str),
+                modules: report.result.as_deref().unwrap_or_default(),
+            })
+            .collect(),
+    };
+    match format {
+        OutputFormat::Text => table(reports),
+        OutputFormat::Pipe => pipe_lines(reports),
+        OutputFormat::Csv => csv_rows(reports),
+        OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Toml => {
+            serialize(&document, format, true).trim_end().to_string()
+        }
+    }
+}
+
+/// Aligned columns: the host, then every module any host reported
+fn table(reports: &[HostReport]) -> String {
+    let mut ids: Vec<&str> = Vec::new();
+    for values in reports
+        .iter()
+        .filter_map(|report| report.result.as_ref().ok())
+    {
+        for (id, _) in values {
+            if !ids.contains(&id.as_str()) {
+                ids.push(id);
+            }
+        }
+    }
+
+    let header = std::iter::once("Host".to_string())
+        .chain(ids.iter().map(|id| match id.parse::<ModuleKind>() {
+            Ok(kind) => kind.name().to_string(),
+            Err(_) => id.to_string(),
+        }))
+        .collect();
+    let mut rows: Vec<Vec<String>> = vec![header];
+    for report in reports {
+        let mut row = vec![report.host.clone()];
+        match &report.result {
+            Ok(values) => row.extend(ids.iter().map(|id| {
+                values
+                    .iter()
+                    .find(|(known, _)| known == id)
+                    .map_or_else(|| "-".to_string(), |(_, value)| value.clone())
+            })),
+            Err(error) => row.push(format!("error: {error}")),
+        }
+        rows.push(row);
+    }
+
+    let mut widths = vec![0; ids.len() + 1];
+    for (row, report) in rows
+        .iter()
+        .zip(std::iter::once(None).chain(reports.iter().map(Some)))
+    {
+        // An error message runs across the module columns instead of widening one
+        let failed = report.is_some_and(|report| report.result.is_err());
+        let cells = if failed { &row[..1] } else { &row[..] };
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(visible_width(cell));
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let line: String = row
+                .iter()
+                .enumerate()
+                .map(|(index, cell)| {
+                    let width = widths.get(index).copied().unwrap_or_default();
+                    let padding = width.saturating_sub(visible_width(cell));
+                    format!("{cell}{}  ", " ".repeat(padding))
+                })
+                .collect();
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `host:id=value` lines
+fn pipe_lines(reports: &[HostReport]) -> String {
+    reports
+        .iter()
+        .filter_map(|report| Some((&report.host, report.result.as_ref().ok()?)))
+        .flat_map(|(host, values)| {
+            values
+                .iter()
+                .map(move |(id, value)| format!("{host}:{id}={value}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A header and a `host,status,module,value` row per module
+fn csv_rows(reports: &[HostReport]) -> String {
+    let mut rows = vec!["host,status,module,value".to_string()];
+    for report in reports {
+        let host = csv_field(&report.host);
+        match &report.result {
+            Ok(values) => rows.extend(
+                values
+                    .iter()
+                    .map(|(id, value)| format!("{host},ok,{},{}", csv_field(id), csv_field(value))),
+            ),
+            Err(error) => rows.push(format!("{host},error,,{}", csv_field(error))),
+        }
+    }
+    rows.join("\n")
+}
+
+/// Serialize pairs as a map, keeping their order
+fn ordered_map<S: Serializer>(
+    pairs: &&[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(pairs.iter().map(|(key, value)| (key, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<HostReport> {
+        vec![
+            HostReport::from_json(
+                "nas",
+                r#"{"modules": [
+                    {"module": "os", "label": "OS", "status": "ok", "value": "Debian"},
+                    {"module": "kernel", "label": "Kernel", "status": "ok", "value": "Linux 6.1"},
+                    {"module": "battery", "label": "Battery", "status": "unavailable"},
+                    {"module": "monitor", "label": "Monitor", "status": "ok", "value": "eDP-1\nHDMI-1"}
+                ]}"#,
+            ),
+            HostReport::failed("pi", "No route to host"),
+            HostReport::from_json(
+                "desktop",
+                r#"{"modules": [{"module": "os", "label": "OS", "status": "ok", "value": "Arch Linux"}]}"#,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_from_json() {
+        let report = &reports()[0];
+        assert_eq!(
+            report.result.as_deref().unwrap(),
+            [
+                ("os".to_string(), "Debian".to_string()),
+                ("kernel".to_string(), "Linux 6.1".to_string()),
+                ("monitor".to_string(), "eDP-1, HDMI-1".to_string()),
+            ]
+        );
+        assert!(HostReport::from_json("nas", "os=Debian").result.is_err());
+    }
+
+    #[test]
+    fn test_render_table() {
+        assert_eq!(
+            render_inventory(&reports(), OutputFormat::Text),
+            "Host     OS          Kernel     Monitor\n\
+             nas      Debian      Linux 6.1  eDP-1, HDMI-1\n\
+             pi       error: No route to host\n\
+             desktop  Arch Linux  -          -"
+        );
+    }
+
+    #[test]
+    fn test_render_yaml() {
+        let reports = &reports()[1..];
+        assert_eq!(
+            render_inventory(reports, OutputFormat::Yaml),
+            "hosts:\n\
+             - host: pi\n  status: error\n  error: No route to host\n\
+             - host: desktop\n  status: ok\n  modules:\n    os: Arch Linux"
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            render_inventory(&reports()[..2], OutputFormat::Csv),
+            "host,status,module,value\n\
+             nas,ok,os,Debian\n\
+             nas,ok,kernel,Linux 6.1\n\
+             nas,ok,monitor,\"eDP-1, HDMI-1\"\n\
+             pi,error,,No route to host"
+        );
+    }
+
+    #[test]
+    fn test_render_pipe() {
+        assert_eq!(
+            render_inventory(&reports()[1..], OutputFormat::Pipe),
+            "desktop:os=Arch Linux"
+        );
+    }
+}
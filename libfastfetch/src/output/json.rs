@@ -0,0 +1,93 @@
+//! JSON documents of module results
+//!
+//! [`JsonFormatter`] writes the same records as the other structured
+//! formats, so library users get the results as JSON without going through
+//! the CLI:
+//!
+//! ```json
+//! {"modules":[{"module":"os","label":"OS","status":"ok","value":"Arch Linux x86_64"}]}
+//! ```
+//!
+//! `--format json` prints the same document, indented.
+
+use super::{
+    DisplayOptions, OutputFormat, OutputRenderer, RenderedModule,
+    structured::{Document, serialize},
+};
+use std::time::Duration;
+
+/// Renders module results as a JSON object with a `modules` array
+#[derive(Debug, Clone, Default)]
+pub struct JsonFormatter {
+    display: DisplayOptions,
+    total: Option<Duration>,
+    pretty: bool,
+}
+
+impl JsonFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leave out modules hidden by `display` and add timings when it says so
+    pub fn with_display(mut self, display: DisplayOptions) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Add the time the whole run took as `total_ms` when timings are shown
+    pub fn with_total(mut self, total: Duration) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Indent the document instead of writing it on one line
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+}
+
+impl OutputRenderer for JsonFormatter {
+    fn render(&self, modules: &[RenderedModule]) -> String {
+        let document = Document::new(modules, &self.display, self.total);
+        serialize(&document, OutputFormat::Json, self.pretty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModuleKind;
+
+    #[test]
+    fn test_render_json() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string())
+                .with_elapsed(Duration::from_micros(1340)),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::error(ModuleKind::Kernel, "uname \"failed\"".to_string()),
+        ];
+        assert_eq!(
+            JsonFormatter::new().render(&modules),
+            concat!(
+                r#"{"modules":[{"module":"os","label":"OS","status":"ok","value":"Linux"},"#,
+                r#"{"module":"kernel","label":"Kernel","status":"error","error":"uname \"failed\""}]}"#
+            )
+        );
+
+        let display = DisplayOptions {
+            stat: true,
+            hide_errors: true,
+            ..DisplayOptions::default()
+        };
+        let output = JsonFormatter::new()
+            .with_display(display)
+            .with_total(Duration::from_millis(2))
+            .render(&modules);
+        assert_eq!(
+            output,
+            r#"{"total_ms":2.0,"modules":[{"module":"os","label":"OS","status":"ok","value":"Linux","elapsed_ms":1.34}]}"#
+        );
+    }
+}
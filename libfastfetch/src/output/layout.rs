@@ -0,0 +1,248 @@
+//! Arrangements of the logo and the module lines
+//!
+//! `side` puts the logo to the left of the modules, `top` centers it above
+//! them, and `grid` sets the modules in two columns next to the logo, for
+//! wide terminals. Each layout decides where the logo goes and how much
+//! room a value line gets.
+//!
+//! The logo's own position can move it to the right of the modules or
+//! under them; side by side, the logo and the modules are two panes set
+//! next to each other in either order.
+
+use super::visible_width;
+use crate::logo::LogoPosition;
+use std::{fmt, str::FromStr};
+
+/// Space between the logo and the module lines
+const LOGO_GAP: &str = "  ";
+
+/// Space between the two columns of the grid layout
+const COLUMN_GAP: &str = "   ";
+
+/// How the logo and the modules are arranged
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// The logo on the left, the modules beside it
+    #[default]
+    Side,
+    /// The logo centered above the modules
+    Top,
+    /// The logo on the left, the modules beside it in two columns
+    Grid,
+}
+
+impl FromStr for Layout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "side" => Ok(Self::Side),
+            "top" => Ok(Self::Top),
+            "grid" => Ok(Self::Grid),
+            _ => Err(format!("Unknown layout: {s} (expected side, top or grid)")),
+        }
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Side => write!(f, "side"),
+            Self::Top => write!(f, "top"),
+            Self::Grid => write!(f, "grid"),
+        }
+    }
+}
+
+impl Layout {
+    /// Columns a module line may take up in a line `width` wide, with a
+    /// logo `logo_width` wide at `position`
+    pub(crate) fn line_width(
+        self,
+        width: usize,
+        logo_width: Option<usize>,
+        position: LogoPosition,
+    ) -> usize {
+        let beside = match logo_width {
+            Some(logo) if self.logo_beside(position) => width.saturating_sub(logo + LOGO_GAP.len()),
+            _ => width,
+        };
+        match self {
+            Self::Grid => beside.saturating_sub(COLUMN_GAP.len()) / 2,
+            _ => beside,
+        }
+    }
+
+    /// Module lines arranged as the layout asks, before the logo is added
+    ///
+    /// `starts` are the indices where each detecting module's lines begin;
+    /// the grid keeps everything before the first of them, such as the title
+    /// and a separator under it, above both columns and only breaks between
+    /// modules.
+    pub(crate) fn arrange(self, lines: Vec<String>, starts: &[usize]) -> Vec<String> {
+        match (self, starts.first()) {
+            (Self::Grid, Some(&first)) => columns(lines, first, starts),
+            _ => lines,
+        }
+    }
+
+    /// Whether a logo at `position` shares lines with the modules, rather
+    /// than going above or under them
+    pub(crate) fn logo_beside(self, position: LogoPosition) -> bool {
+        self != Self::Top && position != LogoPosition::Bottom
+    }
+
+    /// The logo's lines and the module lines put together
+    ///
+    /// A logo at the bottom goes under the modules whatever the layout; the
+    /// `top` layout otherwise puts it above them on either side.
+    pub(crate) fn with_logo(
+        self,
+        logo_lines: &[String],
+        logo_width: usize,
+        position: LogoPosition,
+        lines: &[String],
+    ) -> String {
+        let rows = match (self, position) {
+            (_, LogoPosition::Bottom) => stacked(lines, logo_lines, logo_width, false),
+            (Self::Top, _) => stacked(lines, logo_lines, logo_width, true),
+            (_, LogoPosition::Left) => panes(logo_lines, logo_width, lines),
+            (_, LogoPosition::Right) => panes(lines, widest(lines), logo_lines),
+        };
+        rows.join("\n")
+    }
+}
+
+/// Width of the widest of `lines`
+fn widest(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| visible_width(line))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Lines from `first` on in two columns split at the module start that
+/// balances them best
+fn columns(mut lines: Vec<String>, first: usize, starts: &[usize]) -> Vec<String> {
+    let end = lines.len();
+    let split = starts
+        .iter()
+        .copied()
+        .filter(|&start| start > first)
+        .min_by_key(|&start| (2 * start).abs_diff(first + end))
+        .unwrap_or(end);
+
+    let right = lines.split_off(split);
+    let left = lines.split_off(first);
+    let left_width = widest(&left);
+    let rows = left.len().max(right.len());
+    for row in 0..rows {
+        let left = left.get(row).map(String::as_str).unwrap_or("");
+        let line = match right.get(row) {
+            Some(right) => {
+                let padding = left_width - visible_width(left);
+                format!("{left}{:padding$}{COLUMN_GAP}{right}", "")
+            }
+            None => left.to_string(),
+        };
+        lines.push(line);
+    }
+    lines
+}
+
+/// Two panes side by side, the left one padded to `left_width`
+fn panes(left: &[String], left_width: usize, right: &[String]) -> Vec<String> {
+    let total_lines = left.len().max(right.len());
+    (0..total_lines)
+        .map(|idx| {
+            let left = left.get(idx).map(String::as_str).unwrap_or("");
+            let right = right.get(idx).map(String::as_str).unwrap_or("");
+            beside_line(left, left_width, right)
+        })
+        .collect()
+}
+
+/// A line of the left pane, padded to `left_width`, and one of the right
+pub(crate) fn beside_line(left: &str, left_width: usize, right: &str) -> String {
+    let padding = left_width.saturating_sub(visible_width(left));
+    format!("{left}{:padding$}{LOGO_GAP}{right}", "")
+}
+
+/// The logo centered over or under the module lines, with a blank line
+/// between
+fn stacked(lines: &[String], logo_lines: &[String], logo_width: usize, above: bool) -> Vec<String> {
+    let indent = widest(lines).saturating_sub(logo_width) / 2;
+    let logo = logo_lines
+        .iter()
+        .map(|line| format!("{:indent$}{line}", "").trim_end().to_string());
+    let gap = std::iter::once(String::new());
+    match above {
+        true => logo.chain(gap).chain(lines.iter().cloned()).collect(),
+        false => lines.iter().cloned().chain(gap).chain(logo).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_columns() {
+        let lines = strings(&[
+            "me@box",
+            "------",
+            "OS: Linux",
+            "CPU: one",
+            "    two",
+            "GPU: x",
+            "WM: y",
+        ]);
+        assert_eq!(
+            Layout::Grid.arrange(lines, &[2, 3, 5, 6]),
+            strings(&[
+                "me@box",
+                "------",
+                "OS: Linux   GPU: x",
+                "CPU: one    WM: y",
+                "    two",
+            ])
+        );
+        assert_eq!(
+            Layout::Grid.line_width(80, Some(30), LogoPosition::Left),
+            22
+        );
+        assert_eq!(Layout::Top.line_width(80, Some(30), LogoPosition::Left), 80);
+        assert_eq!(
+            Layout::Side.line_width(80, Some(30), LogoPosition::Bottom),
+            80
+        );
+    }
+
+    #[test]
+    fn test_with_logo() {
+        let logo = strings(&["/\\", "\\/"]);
+        let lines = strings(&["OS: Linux"]);
+        assert_eq!(
+            Layout::Top.with_logo(&logo, 2, LogoPosition::Left, &lines),
+            "   /\\\n   \\/\n\nOS: Linux"
+        );
+        assert_eq!(
+            Layout::Side.with_logo(&logo, 2, LogoPosition::Left, &lines),
+            "/\\  OS: Linux\n\\/  "
+        );
+        assert_eq!(
+            Layout::Side.with_logo(&logo, 2, LogoPosition::Right, &lines),
+            "OS: Linux  /\\\n           \\/"
+        );
+        assert_eq!(
+            Layout::Grid.with_logo(&logo, 2, LogoPosition::Bottom, &lines),
+            "OS: Linux\n\n   /\\\n   \\/"
+        );
+        assert_eq!("GRID".parse::<Layout>(), Ok(Layout::Grid));
+    }
+}
@@ -1,45 +1,180 @@
 //! Terminal output and formatting layer.
 //!
 //! Provides a small vertical slice for formatting module results, with
-//! optional logo rendering and values-only output.
+//! optional logo rendering and values-only output, and structured
+//! documents for other programs.
 
+pub mod bar;
 pub mod color;
+pub mod display;
+pub mod inventory;
+pub mod json;
+pub mod layout;
+pub mod snapshot;
+pub mod stream;
+pub mod structured;
+pub mod template;
+pub mod threshold;
 
 use crate::{ModuleKind, logo::Logo};
-pub use color::{Color, Style, StyledString};
+pub use bar::{BarMode, BarStyle};
+pub use color::{
+    Color, ColorMode, Style, StyledString, TextStyle, colors_enabled, set_colors_enabled,
+    strip_ansi, truncate, visible_width,
+};
+pub use display::{DisplayOptions, KeyAlign, MIN_VALUE_WIDTH, check_width, terminal_width};
+pub use inventory::{HostReport, render_inventory};
+pub use json::JsonFormatter;
+pub use layout::Layout;
+use std::time::Duration;
+pub use stream::LineStream;
+pub use structured::{OutputFormat, render_structured};
+pub use template::{Template, TemplateError};
+pub use threshold::Thresholds;
 
 /// Render-ready module entry containing formatted value or error text.
 #[derive(Debug, Clone)]
 pub struct RenderedModule {
     pub kind: ModuleKind,
+    /// Label overriding the module kind's name
+    pub label: Option<String>,
+    /// Style of this module's label, overriding the shared key style
+    pub key_style: Option<TextStyle>,
+    /// Style of this module's value, overriding the shared value style
+    pub value_style: Option<TextStyle>,
     pub value: Option<String>,
     pub error: Option<String>,
+    /// The value came from a user template and carries its own styling
+    pub templated: bool,
+    /// How full the module is, in percent, for each line of the value
+    pub percentages: Vec<f64>,
+    /// Styles for the value lines by percentage, over the value style
+    pub thresholds: Option<Thresholds>,
+    /// Columns a value line may take up before it is cut short, over the
+    /// shared limit
+    pub max_length: Option<usize>,
+    /// Time detection took, when it was measured
+    pub elapsed: Option<Duration>,
 }
 
 impl RenderedModule {
     pub fn value(kind: ModuleKind, value: String) -> Self {
         Self {
             kind,
+            label: None,
+            key_style: None,
+            value_style: None,
             value: Some(value),
             error: None,
+            templated: false,
+            percentages: Vec::new(),
+            thresholds: None,
+            max_length: None,
+            elapsed: None,
+        }
+    }
+
+    /// A value rendered through a user template
+    pub fn templated(kind: ModuleKind, value: String) -> Self {
+        Self {
+            templated: true,
+            ..Self::value(kind, value)
         }
     }
 
     pub fn unavailable(kind: ModuleKind) -> Self {
         Self {
             kind,
+            label: None,
+            key_style: None,
+            value_style: None,
             value: None,
             error: None,
+            templated: false,
+            percentages: Vec::new(),
+            thresholds: None,
+            max_length: None,
+            elapsed: None,
         }
     }
 
     pub fn error(kind: ModuleKind, error: String) -> Self {
         Self {
             kind,
+            label: None,
+            key_style: None,
+            value_style: None,
             value: None,
             error: Some(error),
+            templated: false,
+            percentages: Vec::new(),
+            thresholds: None,
+            max_length: None,
+            elapsed: None,
         }
     }
+
+    /// Show `label` instead of the module kind's name
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Draw the label in `style` instead of the shared key style
+    pub fn with_key_style(mut self, style: Option<TextStyle>) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Draw the value in `style` instead of the shared value style
+    pub fn with_value_style(mut self, style: Option<TextStyle>) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// Record how full the module is for each line of the value
+    pub fn with_percentages(mut self, percentages: Vec<f64>) -> Self {
+        self.percentages = percentages;
+        self
+    }
+
+    /// Draw value lines in the style their percentage's band asks for
+    pub fn with_thresholds(mut self, thresholds: Option<Thresholds>) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Cut value lines longer than `max_length` columns short
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Style value line `line` gets from the thresholds, if any
+    pub fn threshold_style(&self, line: usize) -> Option<TextStyle> {
+        let percent = *self.percentages.get(line)?;
+        self.thresholds.as_ref()?.style_for(percent)
+    }
+
+    /// Record how long detection took
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+
+    /// Label to display for this module
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(self.kind.name())
+    }
+}
+
+/// Turns the results of a run into output
+///
+/// [`OutputFormatter`] and [`JsonFormatter`] are the built-in renderers;
+/// library users can implement this to present results their own way.
+pub trait OutputRenderer {
+    /// Render the modules, given in display order
+    fn render(&self, modules: &[RenderedModule]) -> String;
 }
 
 /// Formats output for the terminal, optionally combining a logo with module lines.
@@ -47,85 +182,784 @@ impl RenderedModule {
 pub struct OutputFormatter {
     values_only: bool,
     logo: Option<Logo>,
+    display: DisplayOptions,
+    total: Option<Duration>,
 }
 
 impl OutputFormatter {
     pub fn new(values_only: bool, logo: Option<Logo>) -> Self {
-        Self { values_only, logo }
+        Self {
+            values_only,
+            logo,
+            display: DisplayOptions::default(),
+            total: None,
+        }
+    }
+
+    /// Lay out module lines according to `display`.
+    pub fn with_display(mut self, display: DisplayOptions) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Add a line with the time the whole run took when timings are shown.
+    pub fn with_total(mut self, total: Duration) -> Self {
+        self.total = Some(total);
+        self
     }
 
     /// Format results into a single string ready for printing.
     pub fn render(&self, modules: &[RenderedModule]) -> String {
         let mut lines = Vec::new();
+        let mut heading = None;
+        let mut starts = Vec::new();
 
-        if !self.values_only {
-            lines.push("fastfetch-rs".to_string());
-            lines.push(String::new());
+        let modules: Vec<&RenderedModule> = modules
+            .iter()
+            .filter(|module| !self.display.hides(module))
+            .collect();
+        let total = self.total.filter(|_| self.display.stat);
+        if self.display.oneline {
+            return self.oneline(&modules);
+        }
+
+        let label_width = self.label_width(&modules, total.is_some());
+        let separator = self.separator(self.content_width(&modules, label_width));
+
+        for module in modules {
+            if module.kind != ModuleKind::Title && !module.kind.is_layout() {
+                starts.push(lines.len());
+            }
+            self.push_module(&mut lines, &mut heading, module, label_width, &separator);
+        }
+
+        lines = self.display.layout.arrange(lines, &starts);
+        self.push_trailer(&mut lines, label_width, total);
+
+        if self.boxed() {
+            lines = framed(lines, heading.as_deref());
+        }
+
+        match &self.logo {
+            Some(logo) => {
+                self.display
+                    .layout
+                    .with_logo(&logo.lines(), logo.width(), logo.position(), &lines)
+            }
+            None => lines.join("\n"),
         }
+    }
+
+    /// Every value on one line, separated by the delimiter; lines of a
+    /// multi-line value are joined with ", "
+    fn oneline(&self, modules: &[&RenderedModule]) -> String {
+        modules
+            .iter()
+            .filter(|module| !module.kind.is_layout())
+            .filter_map(|module| {
+                let value = module.value.as_deref()?;
+                let lines: Vec<_> = value
+                    .lines()
+                    .enumerate()
+                    .map(|(index, line)| self.value(module, index, &self.limit(module, line)))
+                    .collect();
+                Some(lines.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join(&self.display.delimiter)
+    }
 
-        let label_width = modules
+    /// Width labels are padded to: that of the widest label among
+    /// `modules`, and of the total's if it's shown
+    fn label_width(&self, modules: &[&RenderedModule], total: bool) -> usize {
+        modules
             .iter()
-            .map(|m| m.kind.name().len())
+            .filter(|m| !m.kind.is_layout() && !Self::is_unlabeled(m.kind))
+            .map(|m| visible_width(&self.display.label(m)))
+            .chain(total.then_some(TOTAL_LABEL.len()))
             .max()
-            .unwrap_or(0);
+            .unwrap_or(0)
+    }
 
-        for module in modules {
-            match (&module.value, &module.error) {
-                (Some(value), _) if self.values_only => {
+    /// A separator line `width` columns wide, or as wide as lines may be
+    fn separator(&self, width: usize) -> String {
+        "-".repeat(match self.value_width(0) {
+            Some(limit) => width.min(limit),
+            None => width,
+        })
+    }
+
+    /// Add the lines showing `module`; a title drawn in the box's border
+    /// goes to `heading` instead
+    fn push_module(
+        &self,
+        lines: &mut Vec<String>,
+        heading: &mut Option<String>,
+        module: &RenderedModule,
+        label_width: usize,
+        separator: &str,
+    ) {
+        match module.kind {
+            ModuleKind::Separator => {
+                lines.push(separator.to_string());
+                return;
+            }
+            ModuleKind::Break => {
+                lines.push(String::new());
+                return;
+            }
+            _ => {}
+        }
+
+        match (&module.value, &module.error) {
+            (Some(value), _) if module.kind == ModuleKind::Title && self.boxed() => {
+                *heading = Some(match module.templated {
+                    true => value.clone(),
+                    false => self.title_line(value),
+                });
+            }
+            (Some(value), _) if module.kind == ModuleKind::Title && !self.values_only => {
+                if module.templated {
                     lines.push(value.clone());
+                } else {
+                    lines.push(self.title_line(value));
                 }
-                (Some(value), _) => {
-                    lines.push(format!("{:<label_width$}: {value}", module.kind.name()));
-                }
-                (None, Some(err)) if !self.values_only => {
-                    lines.push(format!(
-                        "{:<label_width$}: Error - {err}",
-                        module.kind.name()
-                    ));
-                }
-                (None, None) if !self.values_only => {
-                    lines.push(format!(
-                        "{:<label_width$}: Not available",
-                        module.kind.name()
-                    ));
-                }
-                _ => {}
+                lines.push("-".repeat(visible_width(value)));
+            }
+            (Some(value), _) if module.kind == ModuleKind::Text => {
+                lines.extend(self.value_lines(module, value, 0));
+                self.append_elapsed(lines, module);
+            }
+            (Some(value), _) if self.values_only => {
+                lines.extend(
+                    value
+                        .lines()
+                        .flat_map(|line| self.wrap(&self.limit(module, line), 0)),
+                );
+                self.append_elapsed(lines, module);
+            }
+            (Some(value), _) => {
+                let prefix = self.prefix(module, label_width);
+                let indent = visible_width(&prefix);
+                let value_lines = self.value_lines(module, value, indent);
+                self.push_labeled(lines, module, prefix, value_lines);
+            }
+            (None, Some(err)) if !self.values_only => {
+                let prefix = self.prefix(module, label_width);
+                let error = self.wrap(&format!("Error - {err}"), visible_width(&prefix));
+                self.push_labeled(lines, module, prefix, error);
             }
+            (None, None) if !self.values_only => {
+                let prefix = self.prefix(module, label_width);
+                lines.push(format!("{prefix}Not available"));
+                self.append_elapsed(lines, module);
+            }
+            _ => {}
         }
+    }
 
-        match &self.logo {
-            Some(logo) => self.merge_with_logo(lines, logo),
-            None => lines.join("\n"),
+    /// Add the lines after the modules: the total time and the color blocks
+    fn push_trailer(&self, lines: &mut Vec<String>, label_width: usize, total: Option<Duration>) {
+        if let Some(total) = total {
+            let total = format_duration(total);
+            if self.values_only {
+                lines.push(total);
+            } else {
+                let key = self.display.key(TOTAL_LABEL, label_width);
+                lines.push(format!("{key}{}{total}", self.display.separator));
+            }
+        }
+
+        if self.display.color_blocks && !self.values_only && colors_enabled() {
+            lines.push(String::new());
+            lines.extend(color_blocks());
+        }
+    }
+
+    /// The label and separator in front of a module's value
+    fn prefix(&self, module: &RenderedModule, label_width: usize) -> String {
+        let label = self.display.label(module);
+        let label = label.as_ref();
+        let mut key = self.display.key(label, label_width);
+        let style = module.key_style.as_ref().unwrap_or(&self.display.key_style);
+        if !self.values_only && *style != TextStyle::default() {
+            // Style the label alone, not its padding
+            key = key.replacen(label, &style.apply(label).format(), 1);
+        }
+        format!("{key}{}", self.display.separator)
+    }
+
+    /// Whether the module lines are drawn in a box
+    fn boxed(&self) -> bool {
+        self.display.boxed && !self.values_only
+    }
+
+    /// `text_lines` after `prefix`, further lines aligned under the first
+    fn push_labeled(
+        &self,
+        lines: &mut Vec<String>,
+        module: &RenderedModule,
+        prefix: String,
+        text_lines: Vec<String>,
+    ) {
+        let indent = visible_width(&prefix);
+        let mut text_lines = text_lines.into_iter();
+        lines.push(format!("{prefix}{}", text_lines.next().unwrap_or_default()));
+        self.append_elapsed(lines, module);
+        lines.extend(text_lines.map(|line| format!("{:indent$}{line}", "")));
+    }
+
+    /// Columns left for text starting `indent` columns after the logo, when
+    /// lines are limited to a width
+    ///
+    /// Never less than [`MIN_VALUE_WIDTH`]: in a terminal too narrow for
+    /// that, long lines overflow rather than shrink to a few letters each.
+    fn value_width(&self, indent: usize) -> Option<usize> {
+        let frame = if self.boxed() { BOX_FRAME_WIDTH } else { 0 };
+        let line = self.display.layout.line_width(
+            self.display.width?.saturating_sub(frame),
+            self.logo.as_ref().map(Logo::width),
+            self.logo.as_ref().map(Logo::position).unwrap_or_default(),
+        );
+        let width = line.saturating_sub(indent);
+        Some(width.max(MIN_VALUE_WIDTH))
+    }
+
+    /// `line` split to fit after `indent` columns
+    fn wrap(&self, line: &str, indent: usize) -> Vec<String> {
+        match self.value_width(indent) {
+            Some(width) => display::wrap(line, width),
+            None => vec![line.to_string()],
+        }
+    }
+
+    /// Add the module's detection time to the last line when timings are shown
+    fn append_elapsed(&self, lines: &mut [String], module: &RenderedModule) {
+        if let (true, Some(elapsed), Some(line)) =
+            (self.display.stat, module.elapsed, lines.last_mut())
+        {
+            line.push_str(&format!(" [{}]", format_duration(elapsed)));
+        }
+    }
+
+    /// A module's value wrapped to fit after `indent` columns, each line in
+    /// its style
+    fn value_lines(&self, module: &RenderedModule, value: &str, indent: usize) -> Vec<String> {
+        value
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                self.wrap(&self.limit(module, line), indent)
+                    .into_iter()
+                    .map(move |part| self.value(module, index, &part))
+            })
+            .collect()
+    }
+
+    /// A value line cut short at the module's or the shared maximum length;
+    /// the title is shown whole
+    fn limit(&self, module: &RenderedModule, line: &str) -> String {
+        match module.max_length.or(self.display.max_length) {
+            Some(max_length) if module.kind != ModuleKind::Title => truncate(line, max_length),
+            _ => line.to_string(),
+        }
+    }
+
+    /// Part of value line `index` of a module in its value style, or the
+    /// style its percentage calls for
+    fn value(&self, module: &RenderedModule, index: usize, line: &str) -> String {
+        let threshold = module.threshold_style(index);
+        let style = threshold
+            .as_ref()
+            .or(module.value_style.as_ref())
+            .unwrap_or(&self.display.value_style);
+        match style {
+            style if !self.values_only && *style != TextStyle::default() => {
+                style.apply(line).format()
+            }
+            _ => line.to_string(),
         }
     }
 
-    fn merge_with_logo(&self, lines: Vec<String>, logo: &Logo) -> String {
-        let logo_lines = logo.lines();
-        let total_lines = lines.len().max(logo_lines.len());
-        let mut rendered = Vec::with_capacity(total_lines);
-        let spacer = "  ";
+    /// Modules whose value stands on its own without a label
+    fn is_unlabeled(kind: ModuleKind) -> bool {
+        matches!(kind, ModuleKind::Title | ModuleKind::Text)
+    }
 
-        for idx in 0..total_lines {
-            let logo_line = logo_lines.get(idx).map(String::as_str).unwrap_or("");
-            let content_line = lines.get(idx).map(String::as_str).unwrap_or("");
+    /// Width of the widest plain-text line, which separator lines span
+    fn content_width(&self, modules: &[&RenderedModule], label_width: usize) -> usize {
+        modules
+            .iter()
+            .filter(|m| !m.kind.is_layout())
+            .filter_map(|m| {
+                let widest = m
+                    .value
+                    .as_deref()?
+                    .lines()
+                    .map(|line| visible_width(&self.limit(m, line)))
+                    .max()?;
+                let label = if self.values_only || Self::is_unlabeled(m.kind) {
+                    0
+                } else {
+                    visible_width(&self.prefix(m, label_width))
+                };
+                Some(label + widest)
+            })
+            .max()
+            .unwrap_or(0)
+    }
 
-            // Calculate visible width (excluding ANSI codes)
-            let visible_width = logo_line
-                .chars()
-                .filter(|&c| c != '\x1b')
-                .collect::<String>()
-                .replace("[0m", "")
-                .replace("[1m", "")
-                .replace(
-                    |c: char| c.is_ascii_digit() || c == '[' || c == ';' || c == 'm',
-                    "",
-                )
-                .len();
+    /// Style "user@hostname" with the logo's accent color, leaving the "@" plain
+    fn title_line(&self, title: &str) -> String {
+        let accent = |text: &str| {
+            let styled = StyledString::new(text).style(Style::Bold);
+            match self.logo.as_ref().and_then(Logo::color) {
+                Some(color) => styled.fg(color).format(),
+                None => styled.format(),
+            }
+        };
 
-            let padding = logo.width().saturating_sub(visible_width);
-            rendered.push(format!("{logo_line}{:padding$}{spacer}{content_line}", ""));
+        match title.split_once('@') {
+            Some((user, host)) => format!("{}@{}", accent(user), accent(host)),
+            None => accent(title),
         }
+    }
+}
+
+impl OutputRenderer for OutputFormatter {
+    fn render(&self, modules: &[RenderedModule]) -> String {
+        OutputFormatter::render(self, modules)
+    }
+}
+
+/// Label of the line with the run's total time
+const TOTAL_LABEL: &str = "Total";
+
+/// Width of one swatch of the color blocks
+const COLOR_BLOCK_WIDTH: usize = 3;
+
+/// Columns the box's sides and their padding take up
+const BOX_FRAME_WIDTH: usize = 4;
+
+/// `lines` inside a rounded box, with `heading` set into the top border
+///
+/// The box fits the widest line, or the heading if that is wider.
+fn framed(lines: Vec<String>, heading: Option<&str>) -> Vec<String> {
+    let heading_width = heading.map_or(0, |heading| visible_width(heading) + 2);
+    let inner = lines
+        .iter()
+        .map(|line| visible_width(line))
+        .max()
+        .unwrap_or(0)
+        .max(heading_width);
+
+    let top = match heading {
+        Some(heading) => {
+            let rest = "─".repeat(inner + 2 - heading_width - 1);
+            format!("╭─ {heading} {rest}╮")
+        }
+        None => format!("╭{}╮", "─".repeat(inner + 2)),
+    };
+    let body = lines.into_iter().map(|line| {
+        let padding = inner - visible_width(&line);
+        format!("│ {line}{:padding$} │", "")
+    });
+    std::iter::once(top)
+        .chain(body)
+        .chain(std::iter::once(format!("╰{}╯", "─".repeat(inner + 2))))
+        .collect()
+}
+
+/// The normal and the bright terminal colors as rows of background swatches
+fn color_blocks() -> [String; 2] {
+    let row = |first: u8| {
+        let swatches: String = (first..first + 8)
+            .map(|index| {
+                let code = Color::from_index(index).bg_code();
+                format!("{code}{:COLOR_BLOCK_WIDTH$}", "")
+            })
+            .collect();
+        format!("{swatches}{}", Style::Reset.code())
+    };
+    [row(0), row(8)]
+}
+
+/// A short duration for display, e.g. "1.3ms" or "40µs"
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else {
+        format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::IconSet;
+
+    #[test]
+    fn test_render_multiline_value() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "first\nsecond".to_string()),
+        ];
+
+        let output = OutputFormatter::new(false, None).render(&modules);
+        assert_eq!(output, "OS    : Linux\nKernel: first\n        second");
+    }
+
+    #[test]
+    fn test_render_wraps_to_width() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(
+                ModuleKind::Cpu,
+                "AMD Ryzen 7 5800X 8-Core Processor".to_string(),
+            ),
+        ];
+        let display = DisplayOptions {
+            width: Some(30),
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules);
+        assert_eq!(
+            output,
+            "OS : Linux\nCPU: AMD Ryzen 7 5800X 8-Core\n     Processor"
+        );
+    }
+
+    #[test]
+    fn test_render_with_wide_logo() {
+        let config = crate::config::LogoConfig {
+            ascii_art: Some("日本\n$1█$$1".to_string()),
+            ..Default::default()
+        };
+        let logo = Logo::from_config(&config).unwrap();
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "6.1".to_string()),
+        ];
+
+        let output = strip_ansi(&OutputFormatter::new(false, Some(logo)).render(&modules));
+        assert_eq!(output, "日本  OS    : Linux\n█$1   Kernel: 6.1");
+    }
+
+    #[test]
+    fn test_render_thresholds() {
+        let thresholds = Thresholds::new(vec![
+            (50.0, Color::Yellow.into()),
+            (80.0, Color::Red.into()),
+        ]);
+        let module = RenderedModule::value(ModuleKind::Memory, "12 GiB / 16 GiB".to_string())
+            .with_percentages(vec![75.0])
+            .with_thresholds(Some(thresholds));
+
+        let output = OutputFormatter::new(false, None).render(&[module]);
+        let yellow = TextStyle::from(Color::Yellow).apply("12 GiB / 16 GiB");
+        assert_eq!(output, format!("Memory: {}", yellow.format()));
+    }
+
+    #[test]
+    fn test_render_color_blocks() {
+        let modules = [RenderedModule::value(ModuleKind::Os, "Linux".to_string())];
+        let display = DisplayOptions {
+            color_blocks: true,
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules);
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "");
+        assert!(lines[2].starts_with("\x1b[40m   \x1b[41m   "));
+        assert!(lines[3].starts_with("\x1b[100m   "));
+        assert_eq!(visible_width(lines[3]), 24);
+    }
+
+    #[test]
+    fn test_render_boxed() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Title, "me@box".to_string()),
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::value(ModuleKind::Memory, "\x1b[31m1 GiB\x1b[0m".to_string()),
+        ];
+        let display = DisplayOptions {
+            boxed: true,
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display.clone())
+            .render(&modules);
+        assert_eq!(
+            strip_ansi(&output),
+            "╭─ me@box ──────╮\n\
+             │ OS    : Linux │\n\
+             │ ------------- │\n\
+             │ Memory: 1 GiB │\n\
+             ╰───────────────╯"
+        );
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules[1..2]);
+        assert_eq!(output, "╭───────────╮\n│ OS: Linux │\n╰───────────╯");
+    }
+
+    #[test]
+    fn test_render_oneline() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Arch".to_string()),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::value(ModuleKind::Kernel, "6.9.1".to_string()),
+            RenderedModule::unavailable(ModuleKind::Battery),
+            RenderedModule::value(ModuleKind::Monitor, "eDP-1\nHDMI-1".to_string()),
+        ];
+        let display = DisplayOptions {
+            oneline: true,
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules);
+        assert_eq!(output, "Arch | 6.9.1 | eDP-1, HDMI-1");
+    }
+
+    #[test]
+    fn test_render_max_length() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Cpu, "Intel Core i7-9700K".to_string()),
+            RenderedModule::value(
+                ModuleKind::Packages,
+                "1024 (pacman), 12 (flatpak)".to_string(),
+            )
+            .with_max_length(Some(12)),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+        ];
+        let display = DisplayOptions {
+            max_length: Some(10),
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules);
+        assert_eq!(
+            strip_ansi(&output),
+            format!(
+                "CPU     : Intel Cor…\nPackages: 1024 (pacma…\n{}",
+                "-".repeat(22)
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_icons() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Memory, "1 GiB".to_string()),
+            RenderedModule::value(ModuleKind::Custom, "hi".to_string())
+                .with_label(Some("Greeting".to_string())),
+        ];
+        let display = DisplayOptions {
+            icons: IconSet::Emoji,
+            ..DisplayOptions::default()
+        };
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display)
+            .render(&modules);
+        assert_eq!(
+            output,
+            "🐧 OS    : Linux\n\
+             🧮 Memory: 1 GiB\n\
+             Greeting : hi"
+        );
+    }
+
+    #[test]
+    fn test_render_display_options() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "first\nsecond".to_string()),
+        ];
+        let render = |key_align, key_width| {
+            let display = DisplayOptions {
+                separator: " -> ".to_string(),
+                key_align,
+                key_width,
+                ..DisplayOptions::default()
+            };
+            OutputFormatter::new(false, None)
+                .with_display(display)
+                .render(&modules)
+        };
+
+        assert_eq!(
+            render(KeyAlign::Right, None),
+            "    OS -> Linux\nKernel -> first\n          second"
+        );
+        assert_eq!(
+            render(KeyAlign::None, None),
+            "OS -> Linux\nKernel -> first\n          second"
+        );
+        assert_eq!(
+            render(KeyAlign::Left, Some(8)),
+            "OS       -> Linux\nKernel   -> first\n            second"
+        );
+    }
+
+    #[test]
+    fn test_render_key_value_styles() {
+        let display = DisplayOptions {
+            key_style: "bold".parse().unwrap(),
+            value_style: Color::Green.into(),
+            ..DisplayOptions::default()
+        };
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "6.9".to_string())
+                .with_key_style(Some(Color::Red.into()))
+                .with_value_style(Some(TextStyle::default())),
+        ];
+
+        let output = OutputFormatter::new(false, None)
+            .with_display(display.clone())
+            .render(&modules);
+        assert_eq!(
+            output,
+            "\x1b[1mOS\x1b[0m    : \x1b[32mLinux\x1b[0m\n\x1b[31mKernel\x1b[0m: 6.9"
+        );
+
+        let output = OutputFormatter::new(true, None)
+            .with_display(display)
+            .render(&modules);
+        assert_eq!(output, "Linux\n6.9");
+    }
+
+    #[test]
+    fn test_render_hidden_states() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::error(ModuleKind::Kernel, "uname failed".to_string()),
+            RenderedModule::unavailable(ModuleKind::Battery),
+        ];
+        let render = |hide_errors, hide_unavailable| {
+            let display = DisplayOptions {
+                hide_errors,
+                hide_unavailable,
+                ..DisplayOptions::default()
+            };
+            OutputFormatter::new(false, None)
+                .with_display(display)
+                .render(&modules)
+        };
+
+        assert_eq!(
+            render(false, false),
+            "OS     : Linux\nKernel : Error - uname failed\nBattery: Not available"
+        );
+        assert_eq!(
+            render(true, false),
+            "OS     : Linux\nBattery: Not available"
+        );
+        // Hidden modules don't widen the label column
+        assert_eq!(
+            render(false, true),
+            "OS    : Linux\nKernel: Error - uname failed"
+        );
+        assert_eq!(render(true, true), "OS: Linux");
+    }
+
+    #[test]
+    fn test_render_stat() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Cpu, "Ryzen 7\n8 cores".to_string())
+                .with_elapsed(Duration::from_micros(1340)),
+            RenderedModule::unavailable(ModuleKind::Battery)
+                .with_elapsed(Duration::from_micros(20)),
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+        ];
+        let render = |stat| {
+            let display = DisplayOptions {
+                stat,
+                ..DisplayOptions::default()
+            };
+            OutputFormatter::new(false, None)
+                .with_display(display)
+                .with_total(Duration::from_millis(12))
+                .render(&modules)
+        };
+
+        assert_eq!(
+            render(true),
+            "CPU    : Ryzen 7 [1.3ms]\n         8 cores\n\
+             Battery: Not available [20µs]\nOS     : Linux\nTotal  : 12.0ms"
+        );
+        assert_eq!(
+            render(false),
+            "CPU    : Ryzen 7\n         8 cores\nBattery: Not available\nOS     : Linux"
+        );
+    }
+
+    #[test]
+    fn test_render_title() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Title, "user@host".to_string()),
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+        ];
+
+        let output = OutputFormatter::new(false, None).render(&modules);
+        assert_eq!(
+            output,
+            "\x1b[1muser\x1b[0m@\x1b[1mhost\x1b[0m\n---------\nOS: Linux"
+        );
+
+        let output = OutputFormatter::new(true, None).render(&modules);
+        assert_eq!(output, "user@host\nLinux");
+    }
+
+    #[test]
+    fn test_render_templated_title() {
+        let modules = [RenderedModule::templated(
+            ModuleKind::Title,
+            "\x1b[32mhost\x1b[0m".to_string(),
+        )];
+
+        let output = OutputFormatter::new(false, None).render(&modules);
+        assert_eq!(output, "\x1b[32mhost\x1b[0m\n----");
+    }
+
+    #[test]
+    fn test_render_layout_modules() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::value(ModuleKind::Cpu, "Ryzen 7".to_string()),
+            RenderedModule::value(ModuleKind::Break, String::new()),
+        ];
+
+        let output = OutputFormatter::new(false, None).render(&modules);
+        assert_eq!(output, "OS : Linux\n------------\nCPU: Ryzen 7\n");
+    }
+
+    #[test]
+    fn test_render_text() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Text, "motto".to_string())
+                .with_value_style(Some(Color::Green.into())),
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+        ];
 
-        rendered.join("\n")
+        let output = OutputFormatter::new(false, None).render(&modules);
+        assert_eq!(output, "\x1b[32mmotto\x1b[0m\nOS: Linux");
     }
 }
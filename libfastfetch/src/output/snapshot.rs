@@ -0,0 +1,244 @@
+//! Saved results to compare later runs against
+//!
+//! `--snapshot FILE` writes the results as the same records `--format`
+//! uses (JSON or TOML for a `.json` or `.toml` file, YAML otherwise),
+//! without timings or colors. `--diff FILE` then lists what changed since,
+//! e.g. after an upgrade:
+//!
+//! ```text
+//! ~ Kernel: Linux 6.1.0-17-amd64 -> Linux 6.1.0-18-amd64
+//! + GPU Driver: nvidia 550.54.14
+//! - Battery: 85% (Discharging)
+//! ```
+//!
+//! Modules are matched by id and label, so entries of one kind with
+//! different labels are compared on their own. Every module is recorded,
+//! even those hidden from the output.
+
+use super::{
+    Color, DisplayOptions, OutputFormat, RenderedModule,
+    color::helpers::colored,
+    strip_ansi,
+    structured::{Status, records, serialize},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// One module's result as saved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    /// Module id, as used in configs
+    pub module: String,
+    pub label: String,
+    pub status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SnapshotEntry {
+    /// What the module showed, on one line
+    fn summary(&self) -> String {
+        match (self.status, &self.value, &self.error) {
+            (Status::Ok, Some(value), _) => value.lines().collect::<Vec<_>>().join(", "),
+            (Status::Error, _, Some(error)) => format!("error: {error}"),
+            _ => "unavailable".to_string(),
+        }
+    }
+
+    fn same_module(&self, other: &Self) -> bool {
+        self.module == other.module && self.label == other.label
+    }
+}
+
+/// Results of a run, in display order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    #[serde(default)]
+    pub modules: Vec<SnapshotEntry>,
+}
+
+/// A difference between a snapshot and a later run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// A module that wasn't in the snapshot
+    Added(SnapshotEntry),
+    /// A module that is gone
+    Removed(SnapshotEntry),
+    /// A module showing something else than before
+    Changed {
+        old: SnapshotEntry,
+        new: SnapshotEntry,
+    },
+}
+
+impl Snapshot {
+    /// Snapshot of rendered modules, leaving out layout modules
+    pub fn new(modules: &[RenderedModule]) -> Self {
+        let modules = records(modules, &DisplayOptions::default())
+            .into_iter()
+            .map(|record| SnapshotEntry {
+                module: record.module.to_string(),
+                label: record.label.to_string(),
+                status: record.status,
+                value: record.value.map(strip_ansi),
+                error: record.error.map(strip_ansi),
+            })
+            .collect();
+        Self { modules }
+    }
+
+    /// Format of a snapshot file: JSON or TOML for a `.json` or `.toml`
+    /// file, YAML otherwise
+    pub fn format_for(path: &Path) -> OutputFormat {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => OutputFormat::Json,
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => OutputFormat::Toml,
+            _ => OutputFormat::Yaml,
+        }
+    }
+
+    /// Read a snapshot written in `format`
+    pub fn parse(content: &str, format: OutputFormat) -> Result<Self, String> {
+        match format {
+            OutputFormat::Json => serde_json::from_str(content).map_err(|err| err.to_string()),
+            OutputFormat::Toml => toml::from_str(content).map_err(|err| err.to_string()),
+            _ => serde_yaml::from_str(content).map_err(|err| err.to_string()),
+        }
+    }
+
+    /// The snapshot as text in `format`: JSON, TOML or YAML
+    pub fn render(&self, format: OutputFormat) -> String {
+        let output = serialize(self, format, true);
+        match format {
+            OutputFormat::Json => output + "\n",
+            _ => output,
+        }
+    }
+
+    /// What changed from this snapshot to `current`, in `current`'s order
+    /// with removed modules last
+    pub fn diff(&self, current: &Snapshot) -> Vec<Change> {
+        let mut matched = vec![false; self.modules.len()];
+        let mut changes = Vec::new();
+
+        for new in &current.modules {
+            let old = self
+                .modules
+                .iter()
+                .enumerate()
+                .find(|(index, old)| !matched[*index] && old.same_module(new));
+            match old {
+                Some((index, old)) => {
+                    matched[index] = true;
+                    if old.summary() != new.summary() {
+                        changes.push(Change::Changed {
+                            old: old.clone(),
+                            new: new.clone(),
+                        });
+                    }
+                }
+                None => changes.push(Change::Added(new.clone())),
+            }
+        }
+
+        changes.extend(
+            self.modules
+                .iter()
+                .zip(matched)
+                .filter(|(_, matched)| !matched)
+                .map(|(old, _)| Change::Removed(old.clone())),
+        );
+        changes
+    }
+}
+
+/// One line per change, marked `~`, `+` or `-`
+pub fn render_diff(changes: &[Change], colors: bool) -> String {
+    let paint = |text: String, color: Color| match colors {
+        true => colored(text, color).to_string(),
+        false => text,
+    };
+    changes
+        .iter()
+        .map(|change| match change {
+            Change::Added(new) => {
+                paint(format!("+ {}: {}", new.label, new.summary()), Color::Green)
+            }
+            Change::Removed(old) => {
+                paint(format!("- {}: {}", old.label, old.summary()), Color::Red)
+            }
+            Change::Changed { old, new } => format!(
+                "{} {}: {} -> {}",
+                paint("~".to_string(), Color::Yellow),
+                new.label,
+                paint(old.summary(), Color::Red),
+                paint(new.summary(), Color::Green),
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModuleKind;
+
+    #[test]
+    fn test_round_trip() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Os, "\x1b[1mDebian\x1b[0m".to_string()),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::error(ModuleKind::Kernel, "uname failed".to_string()),
+            RenderedModule::unavailable(ModuleKind::Battery),
+        ];
+        let snapshot = Snapshot::new(&modules);
+        assert_eq!(snapshot.modules.len(), 3);
+        assert_eq!(snapshot.modules[0].value.as_deref(), Some("Debian"));
+
+        for format in [OutputFormat::Json, OutputFormat::Yaml, OutputFormat::Toml] {
+            let text = snapshot.render(format);
+            assert_eq!(
+                Snapshot::parse(&text, format),
+                Ok(snapshot.clone()),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Snapshot::new(&[
+            RenderedModule::value(ModuleKind::Os, "Debian 12".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "Linux 6.1.0-17".to_string()),
+            RenderedModule::value(ModuleKind::Battery, "85%".to_string()),
+        ]);
+        let after = Snapshot::new(&[
+            RenderedModule::value(ModuleKind::Os, "Debian 12".to_string()),
+            RenderedModule::value(ModuleKind::Kernel, "Linux 6.1.0-18".to_string()),
+            RenderedModule::value(ModuleKind::Monitor, "eDP-1\nHDMI-1".to_string()),
+        ]);
+
+        assert_eq!(
+            render_diff(&before.diff(&after), false),
+            "~ Kernel: Linux 6.1.0-17 -> Linux 6.1.0-18\n\
+             + Monitor: eDP-1, HDMI-1\n\
+             - Battery: 85%"
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn test_format_for() {
+        assert_eq!(
+            Snapshot::format_for(Path::new("before.TOML")),
+            OutputFormat::Toml
+        );
+        assert_eq!(
+            Snapshot::format_for(Path::new("before.snap")),
+            OutputFormat::Yaml
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Output written module by module as results arrive
+//!
+//! [`LineStream`] turns each module into its lines as soon as it is
+//! detected, so slow modules don't hold back the ones before them. Labels
+//! are padded to the widest label of all the modules expected. Separator
+//! lines span the widest line so far. Layouts that need every line first
+//! fall back to something that can be drawn as it goes: the box is left
+//! out, `grid` shows one column, a logo above or under the modules isn't
+//! centered, and a logo on the right goes under them.
+
+use super::{Layout, OutputFormatter, RenderedModule, layout::beside_line, visible_width};
+use crate::logo::LogoPosition;
+use std::time::Duration;
+
+/// Renders modules one at a time for streaming output
+#[derive(Debug, Clone)]
+pub struct LineStream {
+    formatter: OutputFormatter,
+    label_width: usize,
+    /// Logo lines not printed yet
+    logo: Vec<String>,
+    logo_width: usize,
+    /// The logo is on the left of the modules rather than above or under
+    beside: bool,
+    /// The logo goes above the modules rather than beside or under
+    above: bool,
+    /// Lines printed so far, logo lines above the modules included
+    row: usize,
+    /// Widest module line so far
+    widest: usize,
+}
+
+impl OutputFormatter {
+    /// Render modules as they arrive, given placeholders for the modules to
+    /// come in display order
+    ///
+    /// Only a placeholder's kind and label matter, e.g.
+    /// `RenderedModule::unavailable(kind).with_label(label)`.
+    pub fn stream(&self, expected: &[RenderedModule]) -> LineStream {
+        let mut formatter = self.clone();
+        formatter.display.boxed = false;
+        if formatter.display.layout == Layout::Grid {
+            formatter.display.layout = Layout::Side;
+        }
+
+        let expected: Vec<&RenderedModule> = expected.iter().collect();
+        let label_width = formatter.label_width(&expected, self.display.stat);
+        let (logo, logo_width, position) = match &formatter.logo {
+            Some(logo) => (logo.lines(), logo.width(), logo.position()),
+            None => (Vec::new(), 0, LogoPosition::default()),
+        };
+        let beside = position == LogoPosition::Left && formatter.display.layout != Layout::Top;
+        let above = position != LogoPosition::Bottom && formatter.display.layout == Layout::Top;
+        LineStream {
+            formatter,
+            label_width,
+            logo,
+            logo_width,
+            beside,
+            above,
+            row: 0,
+            widest: 0,
+        }
+    }
+}
+
+impl LineStream {
+    /// Lines to print before any module, such as a logo above them
+    pub fn start(&mut self) -> Vec<String> {
+        if !self.above || self.logo.is_empty() {
+            return Vec::new();
+        }
+        let mut lines = std::mem::take(&mut self.logo);
+        lines.push(String::new());
+        self.row = lines.len();
+        lines
+    }
+
+    /// Lines showing `module`, which is the next module in display order
+    pub fn push(&mut self, module: &RenderedModule) -> Vec<String> {
+        if self.formatter.display.hides(module) {
+            return Vec::new();
+        }
+        let separator = self.formatter.separator(self.widest);
+        let mut lines = Vec::new();
+        let mut heading = None;
+        self.formatter.push_module(
+            &mut lines,
+            &mut heading,
+            module,
+            self.label_width,
+            &separator,
+        );
+        self.place(lines)
+    }
+
+    /// Lines after the last module: the total time if timings are shown,
+    /// the color blocks, and what is left of the logo
+    pub fn finish(&mut self, total: Duration) -> Vec<String> {
+        let total = Some(total).filter(|_| self.formatter.display.stat);
+        let mut lines = Vec::new();
+        self.formatter
+            .push_trailer(&mut lines, self.label_width, total);
+        let mut lines = self.place(lines);
+        if self.beside {
+            // The rest of a logo taller than the modules
+            while self.row < self.logo.len() {
+                lines.extend(self.place(vec![String::new()]));
+            }
+        } else if !self.logo.is_empty() {
+            lines.push(String::new());
+            lines.append(&mut self.logo);
+        }
+        lines
+    }
+
+    /// Module lines ready to print, next to the logo if it is beside them
+    fn place(&mut self, lines: Vec<String>) -> Vec<String> {
+        lines
+            .into_iter()
+            .map(|line| {
+                self.widest = self.widest.max(visible_width(&line));
+                let placed = match self.beside && !self.logo.is_empty() {
+                    false => line,
+                    true => {
+                        let logo_line = self.logo.get(self.row).map_or("", String::as_str);
+                        beside_line(logo_line, self.logo_width, &line)
+                    }
+                };
+                self.row += 1;
+                placed
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModuleKind, output::strip_ansi};
+
+    #[test]
+    fn test_stream_matches_render() {
+        let modules = [
+            RenderedModule::value(ModuleKind::Title, "me@box".to_string()),
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::unavailable(ModuleKind::Battery),
+        ];
+        let expected: Vec<_> = modules
+            .iter()
+            .map(|module| RenderedModule::unavailable(module.kind))
+            .collect();
+        let formatter = OutputFormatter::new(false, None);
+
+        let mut stream = formatter.stream(&expected);
+        let mut lines = stream.start();
+        for module in &modules {
+            lines.extend(stream.push(module));
+        }
+        lines.extend(stream.finish(Duration::ZERO));
+        assert_eq!(
+            strip_ansi(&lines.join("\n")),
+            strip_ansi(&formatter.render(&modules))
+        );
+    }
+}
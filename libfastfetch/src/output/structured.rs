@@ -0,0 +1,385 @@
+//! Structured output for other programs to consume
+//!
+//! Instead of aligned "label: value" lines, the results are written as a
+//! document with one record per module, in JSON, YAML or TOML; e.g. in YAML:
+//!
+//! ```yaml
+//! modules:
+//! - module: os
+//!   label: OS
+//!   status: ok
+//!   value: Arch Linux x86_64
+//! - module: battery
+//!   label: Battery
+//!   status: unavailable
+//! ```
+//!
+//! Records follow the display order and leave out layout modules such as
+//! separators. Modules hidden by `hide_errors` or `hide_unavailable` are
+//! left out here too. Values are plain text without colors. With `stat`
+//! set, each record also has an `elapsed_ms` and the document a `total_ms`.
+//!
+//! The `pipe` format is flatter still, for `grep` and `cut`: one
+//! `id=value` line per value line of each module that has a value, e.g.
+//! `os=Arch Linux x86_64`.
+//!
+//! `csv` is for spreadsheets: a header, then one row per module with its
+//! id, label, value (or error), status and detection time in milliseconds,
+//! which is always included.
+
+use super::{DisplayOptions, JsonFormatter, OutputRenderer, RenderedModule};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr, time::Duration};
+
+/// What the results are written as
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Lines for the terminal, next to the logo
+    #[default]
+    Text,
+    Json,
+    Yaml,
+    Toml,
+    /// Plain `id=value` lines
+    Pipe,
+    /// Comma-separated rows with a header
+    Csv,
+}
+
+impl OutputFormat {
+    /// Whether the output is a document for other programs rather than text for people
+    pub const fn is_structured(self) -> bool {
+        !matches!(self, Self::Text)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "toml" => Ok(Self::Toml),
+            "pipe" => Ok(Self::Pipe),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "Unknown output format: {s} (expected text, json, yaml, toml, pipe or csv)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+            Self::Yaml => write!(f, "yaml"),
+            Self::Toml => write!(f, "toml"),
+            Self::Pipe => write!(f, "pipe"),
+            Self::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// Outcome of one module's detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Unavailable,
+}
+
+/// One module's result as written to a structured document
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModuleRecord<'a> {
+    /// Module id, as used in configs
+    pub module: &'static str,
+    pub label: &'a str,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<&'a str>,
+    /// Detection time in milliseconds, when timings are shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<f64>,
+}
+
+impl<'a> From<&'a RenderedModule> for ModuleRecord<'a> {
+    fn from(module: &'a RenderedModule) -> Self {
+        let status = match (&module.value, &module.error) {
+            (Some(_), _) => Status::Ok,
+            (None, Some(_)) => Status::Error,
+            (None, None) => Status::Unavailable,
+        };
+        Self {
+            module: module.kind.id(),
+            label: module.label(),
+            status,
+            value: module.value.as_deref(),
+            error: module.error.as_deref(),
+            elapsed_ms: None,
+        }
+    }
+}
+
+/// The whole document; TOML needs a table at the top
+#[derive(Serialize)]
+pub(super) struct Document<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_ms: Option<f64>,
+    modules: Vec<ModuleRecord<'a>>,
+}
+
+impl<'a> Document<'a> {
+    /// The shown modules, with the run's `total` time when timings are shown
+    pub(super) fn new(
+        modules: &'a [RenderedModule],
+        display: &DisplayOptions,
+        total: Option<Duration>,
+    ) -> Self {
+        Self {
+            total_ms: total.filter(|_| display.stat).map(millis),
+            modules: records(modules, display),
+        }
+    }
+}
+
+/// Records for the modules that are shown, in display order
+pub fn records<'a>(
+    modules: &'a [RenderedModule],
+    display: &DisplayOptions,
+) -> Vec<ModuleRecord<'a>> {
+    modules
+        .iter()
+        .filter(|module| !module.kind.is_layout() && !display.hides(module))
+        .map(|module| ModuleRecord {
+            elapsed_ms: module.elapsed.filter(|_| display.stat).map(millis),
+            ..ModuleRecord::from(module)
+        })
+        .collect()
+}
+
+/// Write the shown modules as a `format` document; `total` is the time
+/// the run took
+///
+/// Text output isn't a document; it's handled by [`super::OutputFormatter`]
+/// and yields an empty string here.
+pub fn render_structured(
+    modules: &[RenderedModule],
+    display: &DisplayOptions,
+    format: OutputFormat,
+    total: Duration,
+) -> String {
+    let document = Document::new(modules, display, Some(total));
+    match format {
+        OutputFormat::Text => String::new(),
+        OutputFormat::Json => JsonFormatter::new()
+            .with_display(display.clone())
+            .with_total(total)
+            .pretty(true)
+            .render(modules),
+        OutputFormat::Pipe => pipe_lines(&document.modules),
+        OutputFormat::Csv => {
+            let timed = DisplayOptions {
+                stat: true,
+                ..display.clone()
+            };
+            csv_rows(&records(modules, &timed))
+        }
+        OutputFormat::Yaml | OutputFormat::Toml => {
+            serialize(&document, format, true).trim_end().to_string()
+        }
+    }
+}
+
+/// `document` written as JSON or TOML, and as YAML for any other format;
+/// JSON is indented when `pretty`
+///
+/// Every document written this way is a struct of strings, numbers and
+/// lists or string-keyed maps of those, with absent values skipped rather
+/// than written as null. That is within what all three serializers accept:
+/// TOML's lack of null and JSON's string-only keys never come up, so
+/// serializing can't fail.
+pub(super) fn serialize(document: &impl Serialize, format: OutputFormat, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json if pretty => {
+            serde_json::to_string_pretty(document).map_err(|err| err.to_string())
+        }
+        OutputFormat::Json => serde_json::to_string(document).map_err(|err| err.to_string()),
+        OutputFormat::Toml => toml::to_string(document).map_err(|err| err.to_string()),
+        _ => serde_yaml::to_string(document).map_err(|err| err.to_string()),
+    }
+    .expect("documents only hold values every format can write")
+}
+
+/// `id=value` lines, repeating the id for each line of a multi-line value
+fn pipe_lines(records: &[ModuleRecord<'_>]) -> String {
+    records
+        .iter()
+        .filter_map(|record| Some((record.module, record.value?)))
+        .flat_map(|(id, value)| value.lines().map(move |line| format!("{id}={line}")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A header and one row per record
+fn csv_rows(records: &[ModuleRecord<'_>]) -> String {
+    let rows = records.iter().map(|record| {
+        let status = match record.status {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Unavailable => "unavailable",
+        };
+        let elapsed = record.elapsed_ms.map(|ms| ms.to_string());
+        [
+            csv_field(record.module),
+            csv_field(record.label),
+            csv_field(record.value.or(record.error).unwrap_or_default()),
+            status.to_string(),
+            elapsed.unwrap_or_default(),
+        ]
+        .join(",")
+    });
+    std::iter::once("module,key,value,status,elapsed_ms".to_string())
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A CSV field, quoted when it contains a separator, quote or line break
+pub(super) fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Milliseconds rounded to a microsecond
+fn millis(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1e6).round() / 1e3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModuleKind;
+
+    fn modules() -> Vec<RenderedModule> {
+        vec![
+            RenderedModule::value(ModuleKind::Os, "Linux".to_string()),
+            RenderedModule::value(ModuleKind::Separator, String::new()),
+            RenderedModule::error(ModuleKind::Kernel, "uname failed".to_string())
+                .with_label(Some("Kern".to_string())),
+            RenderedModule::unavailable(ModuleKind::Battery),
+        ]
+    }
+
+    #[test]
+    fn test_render_yaml() {
+        let output = render_structured(
+            &modules(),
+            &DisplayOptions::default(),
+            OutputFormat::Yaml,
+            Duration::ZERO,
+        );
+        assert_eq!(
+            output,
+            "modules:\n\
+             - module: os\n  label: OS\n  status: ok\n  value: Linux\n\
+             - module: kernel\n  label: Kern\n  status: error\n  error: uname failed\n\
+             - module: battery\n  label: Battery\n  status: unavailable"
+        );
+    }
+
+    #[test]
+    fn test_render_toml() {
+        let display = DisplayOptions {
+            hide_unavailable: true,
+            ..DisplayOptions::default()
+        };
+        let output = render_structured(&modules(), &display, OutputFormat::Toml, Duration::ZERO);
+        assert_eq!(
+            output,
+            "[[modules]]\nmodule = \"os\"\nlabel = \"OS\"\nstatus = \"ok\"\nvalue = \"Linux\"\n\n\
+             [[modules]]\nmodule = \"kernel\"\nlabel = \"Kern\"\nstatus = \"error\"\nerror = \"uname failed\""
+        );
+    }
+
+    #[test]
+    fn test_render_pipe() {
+        let mut modules = modules();
+        modules.push(
+            RenderedModule::value(ModuleKind::Monitor, "eDP-1\nHDMI-1".to_string())
+                .with_label(Some("Display".to_string())),
+        );
+        let output = render_structured(
+            &modules,
+            &DisplayOptions::default(),
+            OutputFormat::Pipe,
+            Duration::ZERO,
+        );
+        assert_eq!(output, "os=Linux\nmonitor=eDP-1\nmonitor=HDMI-1");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let mut modules = modules();
+        modules[0] = modules[0].clone().with_elapsed(Duration::from_micros(1340));
+        modules.push(RenderedModule::value(
+            ModuleKind::Monitor,
+            "eDP-1, \"built-in\"\nHDMI-1".to_string(),
+        ));
+        let output = render_structured(
+            &modules,
+            &DisplayOptions::default(),
+            OutputFormat::Csv,
+            Duration::ZERO,
+        );
+        assert_eq!(
+            output,
+            "module,key,value,status,elapsed_ms\n\
+             os,OS,Linux,ok,1.34\n\
+             kernel,Kern,uname failed,error,\n\
+             battery,Battery,,unavailable,\n\
+             monitor,Monitor,\"eDP-1, \"\"built-in\"\"\nHDMI-1\",ok,"
+        );
+    }
+
+    #[test]
+    fn test_render_stat() {
+        let modules = [RenderedModule::value(ModuleKind::Os, "Linux".to_string())
+            .with_elapsed(Duration::from_micros(1340))];
+        let display = DisplayOptions {
+            stat: true,
+            ..DisplayOptions::default()
+        };
+        let output = render_structured(
+            &modules,
+            &display,
+            OutputFormat::Yaml,
+            Duration::from_millis(12),
+        );
+        assert_eq!(
+            output,
+            "total_ms: 12.0\nmodules:\n\
+             - module: os\n  label: OS\n  status: ok\n  value: Linux\n  elapsed_ms: 1.34"
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("YAML".parse(), Ok(OutputFormat::Yaml));
+        assert_eq!("yml".parse(), Ok(OutputFormat::Yaml));
+        assert_eq!("toml".parse(), Ok(OutputFormat::Toml));
+        assert_eq!("pipe".parse(), Ok(OutputFormat::Pipe));
+        assert_eq!("CSV".parse(), Ok(OutputFormat::Csv));
+        assert_eq!("JSON".parse(), Ok(OutputFormat::Json));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
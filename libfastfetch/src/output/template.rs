@@ -0,0 +1,345 @@
+//! Output line templates
+//!
+//! A small template language shared by every module's format string and the
+//! Title line:
+//!
+//! - `{name}` inserts a field; `{name:<12}` / `{name:>12}` pad it to a width
+//! - `{?name}...{?}` keeps its body only when `name` has a value,
+//!   `{/name}...{/}` only when it doesn't
+//! - `{#green}`, `{#bold}` or `{#ff8800}` switch styling, `{#}` resets it
+//! - `{{` and `}}` are literal braces
+//!
+//! For example `{name} {version}{?arch} ({arch}){?}` renders
+//! "Linux 6.9.1 (x86_64)", or "Linux 6.9.1" when the architecture is unknown.
+
+use super::{Color, Style, visible_width};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Errors in a template string
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    #[error("Unclosed '{{' in template")]
+    UnclosedTag,
+    #[error("Missing {{{0}}} to close {{{0}{1}}}")]
+    UnclosedSection(char, String),
+    #[error("Unexpected {{{0}}} without an opening section")]
+    UnexpectedClose(char),
+    #[error("Unknown style '{0}'")]
+    InvalidStyle(String),
+    #[error("Invalid padding '{0}', expected e.g. '<12' or '>12'")]
+    InvalidPadding(String),
+}
+
+/// A parsed template, ready to render against any set of fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Field {
+        name: String,
+        pad: Option<Pad>,
+    },
+    /// `{?name}` (`present`) or `{/name}` (`!present`) section
+    Section {
+        name: String,
+        present: bool,
+        body: Vec<Node>,
+    },
+    Style(Option<TagStyle>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pad {
+    Left(usize),
+    Right(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagStyle {
+    Color(Color),
+    Style(Style),
+}
+
+impl Template {
+    /// Parse a template string
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut pos = 0;
+        let nodes = parse_nodes(&chars, &mut pos, None)?;
+        Ok(Self {
+            source: source.to_string(),
+            nodes,
+        })
+    }
+
+    /// The template as written
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Render with `lookup` supplying field values
+    ///
+    /// Empty values count as missing. Style tags are dropped unless `colors`
+    /// is set, and any style still active at the end is reset.
+    pub fn render(&self, lookup: &dyn Fn(&str) -> Option<String>, colors: bool) -> String {
+        let mut out = String::new();
+        let styled = render_nodes(&self.nodes, lookup, colors, &mut out);
+        if styled {
+            out.push_str(Style::Reset.code());
+        }
+        out
+    }
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Parse until the end of input or the `{?}`/`{/}` tag closing a section
+fn parse_nodes(
+    chars: &[char],
+    pos: &mut usize,
+    closing: Option<(char, &str)>,
+) -> Result<Vec<Node>, TemplateError> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        let next = chars.get(*pos + 1).copied();
+
+        if (c == '{' && next == Some('{')) || (c == '}' && next == Some('}')) {
+            text.push(c);
+            *pos += 2;
+            continue;
+        }
+        if c != '{' {
+            text.push(c);
+            *pos += 1;
+            continue;
+        }
+
+        let end = chars[*pos..]
+            .iter()
+            .position(|&c| c == '}')
+            .ok_or(TemplateError::UnclosedTag)?;
+        let tag: String = chars[*pos + 1..*pos + end].iter().collect();
+        *pos += end + 1;
+
+        if !text.is_empty() {
+            nodes.push(Node::Text(std::mem::take(&mut text)));
+        }
+
+        let mut tag_chars = tag.chars();
+        match (tag_chars.next(), tag_chars.as_str()) {
+            (Some(marker @ ('?' | '/')), "") => {
+                return match closing {
+                    Some((open, _)) if open == marker => Ok(nodes),
+                    _ => Err(TemplateError::UnexpectedClose(marker)),
+                };
+            }
+            (Some(marker @ ('?' | '/')), name) => {
+                let body = parse_nodes(chars, pos, Some((marker, name)))?;
+                nodes.push(Node::Section {
+                    name: name.to_string(),
+                    present: marker == '?',
+                    body,
+                });
+            }
+            (Some('#'), "") => nodes.push(Node::Style(None)),
+            (Some('#'), style) => nodes.push(Node::Style(Some(parse_style(style)?))),
+            _ => nodes.push(parse_field(&tag)?),
+        }
+    }
+
+    if let Some((marker, name)) = closing {
+        return Err(TemplateError::UnclosedSection(marker, name.to_string()));
+    }
+    if !text.is_empty() {
+        nodes.push(Node::Text(text));
+    }
+    Ok(nodes)
+}
+
+fn parse_field(tag: &str) -> Result<Node, TemplateError> {
+    let (name, spec) = match tag.split_once(':') {
+        Some((name, spec)) => (name, Some(spec)),
+        None => (tag, None),
+    };
+
+    let pad = match spec {
+        None => None,
+        Some(spec) => {
+            let width = |width: &str| {
+                width
+                    .parse()
+                    .map_err(|_| TemplateError::InvalidPadding(spec.to_string()))
+            };
+            Some(match spec.strip_prefix('>') {
+                Some(right) => Pad::Right(width(right)?),
+                None => Pad::Left(width(spec.strip_prefix('<').unwrap_or(spec))?),
+            })
+        }
+    };
+
+    Ok(Node::Field {
+        name: name.trim().to_string(),
+        pad,
+    })
+}
+
+/// A style name, a color name, or a bare or `#`-prefixed hex code
+fn parse_style(name: &str) -> Result<TagStyle, TemplateError> {
+    if let Ok(style) = name.parse::<Style>() {
+        return Ok(TagStyle::Style(style));
+    }
+
+    let hex = format!("#{}", name.trim_start_matches('#'));
+    let color = name
+        .parse::<Color>()
+        .or_else(|_| hex.parse())
+        .map_err(|_| TemplateError::InvalidStyle(name.to_string()))?;
+    Ok(TagStyle::Color(color))
+}
+
+/// Render nodes into `out`, returning whether styling is still active
+fn render_nodes(
+    nodes: &[Node],
+    lookup: &dyn Fn(&str) -> Option<String>,
+    colors: bool,
+    out: &mut String,
+) -> bool {
+    let mut styled = false;
+    let value = |name: &str| lookup(name).filter(|value| !value.is_empty());
+
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Field { name, pad } => {
+                let value = value(name).unwrap_or_default();
+                let padding =
+                    |width: usize| " ".repeat(width.saturating_sub(visible_width(&value)));
+                match pad {
+                    Some(Pad::Left(width)) => out.push_str(&format!("{value}{}", padding(*width))),
+                    Some(Pad::Right(width)) => out.push_str(&format!("{}{value}", padding(*width))),
+                    None => out.push_str(&value),
+                }
+            }
+            Node::Section {
+                name,
+                present,
+                body,
+            } => {
+                if value(name).is_some() == *present {
+                    styled |= render_nodes(body, lookup, colors, out);
+                }
+            }
+            Node::Style(_) if !colors => {}
+            Node::Style(None) => {
+                out.push_str(Style::Reset.code());
+                styled = false;
+            }
+            Node::Style(Some(TagStyle::Style(style))) => {
+                out.push_str(style.code());
+                styled = true;
+            }
+            Node::Style(Some(TagStyle::Color(color))) => {
+                match color.fg_rgb_code() {
+                    Some(code) => out.push_str(&code),
+                    None => out.push_str(color.fg_code()),
+                }
+                styled = true;
+            }
+        }
+    }
+    styled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, colors: bool) -> String {
+        let lookup = |name: &str| match name {
+            "name" => Some("Linux".to_string()),
+            "version" => Some("6.9.1".to_string()),
+            "empty" => Some(String::new()),
+            _ => None,
+        };
+        Template::parse(template).unwrap().render(&lookup, colors)
+    }
+
+    #[test]
+    fn test_fields_and_padding() {
+        assert_eq!(render("{name} {version}", false), "Linux 6.9.1");
+        assert_eq!(
+            render("[{name:<7}][{version:>7}]", false),
+            "[Linux  ][  6.9.1]"
+        );
+        assert_eq!(render("{{{name}}} {missing}!", false), "{Linux} !");
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(
+            render("{name}{?version} v{version}{?}", false),
+            "Linux v6.9.1"
+        );
+        assert_eq!(render("{name}{?arch} ({arch}){?}", false), "Linux");
+        assert_eq!(render("{/empty}none{/}{?empty}some{?}", false), "none");
+        assert_eq!(
+            render("{?name}{?version}{name} {version}{?}{?}", false),
+            "Linux 6.9.1"
+        );
+    }
+
+    #[test]
+    fn test_styles() {
+        assert_eq!(
+            render("{#green}{name}{#} {#bold}{version}", true),
+            "\x1b[32mLinux\x1b[0m \x1b[1m6.9.1\x1b[0m"
+        );
+        assert_eq!(
+            render("{#ff8000}{name}{#}", true),
+            "\x1b[38;2;255;128;0mLinux\x1b[0m"
+        );
+        assert_eq!(render("{#green}{name}{#}", false), "Linux");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Template::parse("{name"), Err(TemplateError::UnclosedTag));
+        assert_eq!(
+            Template::parse("{?arch}x"),
+            Err(TemplateError::UnclosedSection('?', "arch".to_string()))
+        );
+        assert_eq!(
+            Template::parse("x{?}"),
+            Err(TemplateError::UnexpectedClose('?'))
+        );
+        assert!(matches!(
+            Template::parse("{#mauve}"),
+            Err(TemplateError::InvalidStyle(_))
+        ));
+        assert!(matches!(
+            Template::parse("{name:wide}"),
+            Err(TemplateError::InvalidPadding(_))
+        ));
+    }
+}
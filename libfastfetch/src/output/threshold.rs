@@ -0,0 +1,56 @@
+//! Value colors that depend on how full a module is
+//!
+//! Capacity-style modules report a percentage per value line; thresholds
+//! map the lowest percentage of a band to the style its lines are drawn
+//! in. In a config, bands are keyed by that lower bound:
+//!
+//! ```jsonc
+//! "options": { "memory": { "thresholds": { "50": "yellow", "80": "bold red" } } }
+//! ```
+//!
+//! draws Memory yellow from 50% and bold red from 80%, and in the usual
+//! value style below 50%.
+
+use super::TextStyle;
+
+/// Styles for percentage bands, each from its lower bound upwards
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Thresholds {
+    /// Lower bounds in percent and their styles, lowest first
+    bands: Vec<(f64, TextStyle)>,
+}
+
+impl Thresholds {
+    /// Thresholds from `(lower bound, style)` pairs given in any order
+    pub fn new(mut bands: Vec<(f64, TextStyle)>) -> Self {
+        bands.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self { bands }
+    }
+
+    /// Style of the band `percent` falls in, if it reaches the lowest bound
+    pub fn style_for(&self, percent: f64) -> Option<TextStyle> {
+        self.bands
+            .iter()
+            .rev()
+            .find(|(bound, _)| percent >= *bound)
+            .map(|(_, style)| style.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Color;
+
+    #[test]
+    fn test_style_for() {
+        let thresholds = Thresholds::new(vec![
+            (80.0, Color::Red.into()),
+            (50.0, Color::Yellow.into()),
+        ]);
+        assert_eq!(thresholds.style_for(20.0), None);
+        assert_eq!(thresholds.style_for(50.0), Some(Color::Yellow.into()));
+        assert_eq!(thresholds.style_for(93.5), Some(Color::Red.into()));
+        assert!(Thresholds::default().style_for(100.0).is_none());
+    }
+}
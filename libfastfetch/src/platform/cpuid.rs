@@ -0,0 +1,29 @@
+//! x86 CPUID queries
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::__cpuid;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::__cpuid;
+
+/// Leaf 1 ECX bit set by hypervisors for their guests
+const HYPERVISOR_PRESENT: u32 = 1 << 31;
+
+/// Hypervisor vendor signature from leaf 0x40000000, e.g. "KVMKVMKVM"
+///
+/// Returns `None` on bare metal, where the hypervisor-present bit is clear.
+pub fn hypervisor_signature() -> Option<String> {
+    if __cpuid(1).ecx & HYPERVISOR_PRESENT == 0 {
+        return None;
+    }
+
+    let leaf = __cpuid(0x4000_0000);
+    let bytes: Vec<u8> = [leaf.ebx, leaf.ecx, leaf.edx]
+        .iter()
+        .flat_map(|reg| reg.to_le_bytes())
+        .collect();
+
+    let signature = String::from_utf8_lossy(&bytes)
+        .trim_end_matches('\0')
+        .to_string();
+    Some(signature)
+}
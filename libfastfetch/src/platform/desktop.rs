@@ -0,0 +1,172 @@
+//! Desktop settings shared by the appearance modules
+//!
+//! GTK, Qt and KDE keep their theme configuration in small INI-style files
+//! (`settings.ini`, `.gtkrc-2.0`, `kdeglobals`, `qt5ct.conf`), while GNOME
+//! stores it in dconf behind `gsettings`.
+
+use crate::{context::SystemContext, platform::xdg};
+
+/// A parsed INI-style settings file
+#[derive(Debug, Clone, Default)]
+pub struct IniFile {
+    /// (section, key, value) in file order; keys before any header have an empty section
+    entries: Vec<(String, String, String)>,
+}
+
+impl IniFile {
+    /// Parse `[Section]` headers and `key=value` lines, ignoring `#`/`;` comments
+    pub fn parse(content: &str) -> Self {
+        let mut section = String::new();
+        let mut entries = Vec::new();
+
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = header.trim().to_string();
+            } else if let Some((key, value)) = line.split_once('=') {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .unwrap_or(value);
+                entries.push((section.clone(), key.trim().to_string(), value.to_string()));
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Look up a key; later assignments override earlier ones
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(s, k, _)| s == section && k == key)
+            .map(|(_, _, value)| value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// Read and parse the first existing settings file among the XDG candidates
+pub fn read_ini(
+    ctx: &dyn SystemContext,
+    config_relative: &[&str],
+    home_relative: &[&str],
+) -> Option<IniFile> {
+    let candidates = xdg::config_candidates(ctx, config_relative, home_relative);
+    let (_, content) = xdg::read_first(ctx, &candidates)?;
+    Some(IniFile::parse(&content))
+}
+
+/// Read a key from GTK 3/4 `settings.ini` (GTK 3 first), then `~/.gtkrc-2.0`
+pub fn gtk_setting(ctx: &dyn SystemContext, key: &str) -> Option<String> {
+    gtk_settings_ini(ctx, "gtk-3.0", key)
+        .or_else(|| gtk_settings_ini(ctx, "gtk-4.0", key))
+        .or_else(|| gtk2_setting(ctx, key))
+}
+
+/// Read a key from one GTK `settings.ini`, e.g. `gtk_settings_ini(ctx, "gtk-4.0", key)`
+pub fn gtk_settings_ini(ctx: &dyn SystemContext, dir: &str, key: &str) -> Option<String> {
+    read_ini(ctx, &[&format!("{dir}/settings.ini")], &[])?
+        .get("Settings", key)
+        .map(str::to_string)
+}
+
+/// Read a key from `~/.gtkrc-2.0`
+pub fn gtk2_setting(ctx: &dyn SystemContext, key: &str) -> Option<String> {
+    read_ini(ctx, &[], &[".gtkrc-2.0"])?
+        .get("", key)
+        .map(str::to_string)
+}
+
+/// Read a key from KDE's `kdeglobals`
+pub fn kde_setting(ctx: &dyn SystemContext, section: &str, key: &str) -> Option<String> {
+    read_ini(ctx, &["kdeglobals"], &[])?
+        .get(section, key)
+        .map(str::to_string)
+}
+
+/// Read a key from the qt6ct or qt5ct configuration
+pub fn qtct_setting(ctx: &dyn SystemContext, section: &str, key: &str) -> Option<String> {
+    ["qt6ct/qt6ct.conf", "qt5ct/qt5ct.conf"]
+        .iter()
+        .find_map(|file| {
+            read_ini(ctx, &[file], &[])?
+                .get(section, key)
+                .map(str::to_string)
+        })
+}
+
+/// Read a gsettings key, stripping GVariant string quotes
+pub fn gsettings_get(ctx: &dyn SystemContext, schema: &str, key: &str) -> Option<String> {
+    let output = ctx
+        .execute_command("gsettings", &["get", schema, key])
+        .ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .trim_matches('\'')
+        .to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Split a Pango font description like "JetBrains Mono 11" into name and size
+pub fn parse_pango_font(font: &str) -> (String, Option<f64>) {
+    match font.rsplit_once(' ') {
+        Some((name, size)) if size.parse::<f64>().is_ok() => (name.to_string(), size.parse().ok()),
+        _ => (font.to_string(), None),
+    }
+}
+
+/// Split a serialized QFont like "Noto Sans,10,-1,5,50,0,0,0,0,0" into name and size
+pub fn parse_qt_font(font: &str) -> (String, Option<f64>) {
+    let mut fields = font.split(',');
+    let name = fields.next().unwrap_or_default().trim().to_string();
+    let size = fields
+        .next()
+        .and_then(|size| size.trim().parse::<f64>().ok())
+        .filter(|size| *size > 0.0);
+    (name, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ini() {
+        let settings = IniFile::parse(
+            "# comment\n[Settings]\ngtk-icon-theme-name = Papirus-Dark\ngtk-font-name=Cantarell 11\n",
+        );
+        assert_eq!(
+            settings.get("Settings", "gtk-icon-theme-name"),
+            Some("Papirus-Dark")
+        );
+        assert_eq!(
+            settings.get("Settings", "gtk-font-name"),
+            Some("Cantarell 11")
+        );
+        assert_eq!(settings.get("Icons", "Theme"), None);
+
+        let gtkrc = IniFile::parse("gtk-theme-name=\"Adwaita\"\ninclude \"/usr/share/themes\"\n");
+        assert_eq!(gtkrc.get("", "gtk-theme-name"), Some("Adwaita"));
+    }
+
+    #[test]
+    fn test_parse_pango_font() {
+        assert_eq!(
+            parse_pango_font("Source Code Pro 10"),
+            ("Source Code Pro".to_string(), Some(10.0))
+        );
+        assert_eq!(
+            parse_qt_font("Noto Sans,10,-1,5,50,0,0,0,0,0"),
+            ("Noto Sans".to_string(), Some(10.0))
+        );
+    }
+}
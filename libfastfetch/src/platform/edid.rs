@@ -0,0 +1,227 @@
+//! EDID (Extended Display Identification Data) parser
+//!
+//! Decodes the 128-byte EDID base block monitors report over DDC, as exposed
+//! by Linux under `/sys/class/drm/<connector>/edid`. Extension blocks are
+//! ignored; the base block carries everything needed for identification.
+
+use serde::Serialize;
+use std::io;
+
+/// Size of the EDID base block
+pub const BLOCK_SIZE: usize = 128;
+
+const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+/// Offsets of the four 18-byte descriptors in the base block
+const DESCRIPTOR_OFFSETS: [usize; 4] = [54, 72, 90, 108];
+
+/// Display descriptor tag for the monitor name
+const TAG_MONITOR_NAME: u8 = 0xFC;
+
+/// Decoded EDID base block
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Edid {
+    /// Three-letter PNP manufacturer ID, e.g. "DEL"
+    pub manufacturer_id: String,
+    pub product_code: u16,
+    /// Name from the monitor name descriptor
+    pub name: Option<String>,
+    /// Native resolution from the preferred timing
+    pub resolution: Option<(u32, u32)>,
+    /// Refresh rate of the preferred timing in Hz
+    pub refresh_rate: Option<f64>,
+    /// Physical image size in millimetres
+    pub size_mm: Option<(u32, u32)>,
+}
+
+impl Edid {
+    /// Manufacturer name for well-known PNP IDs, falling back to the ID itself
+    pub fn manufacturer(&self) -> &str {
+        pnp_vendor(&self.manufacturer_id).unwrap_or(&self.manufacturer_id)
+    }
+
+    /// Diagonal size in inches
+    pub fn diagonal_inches(&self) -> Option<f64> {
+        let (width, height) = self.size_mm?;
+        let diagonal_mm = f64::from(width).hypot(f64::from(height));
+        Some(diagonal_mm / 25.4)
+    }
+}
+
+/// Parse an EDID blob
+pub fn parse_edid(data: &[u8]) -> io::Result<Edid> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    let block = data
+        .get(..BLOCK_SIZE)
+        .ok_or_else(|| invalid("EDID too short"))?;
+    if block[..8] != HEADER {
+        return Err(invalid("Missing EDID header"));
+    }
+    if block.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+        return Err(invalid("EDID checksum mismatch"));
+    }
+
+    // Three 5-bit letters packed big-endian, 1 = 'A'
+    let packed = u16::from_be_bytes([block[8], block[9]]);
+    let manufacturer_id = [10, 5, 0]
+        .iter()
+        .map(|shift| char::from(b'@' + ((packed >> shift) & 0x1F) as u8))
+        .collect();
+    let product_code = u16::from_le_bytes([block[10], block[11]]);
+
+    let mut name = None;
+    let mut preferred = None;
+    for offset in DESCRIPTOR_OFFSETS {
+        let descriptor = &block[offset..offset + 18];
+        if descriptor[0] != 0 || descriptor[1] != 0 {
+            // The first detailed timing is the preferred (native) mode
+            preferred.get_or_insert_with(|| parse_timing(descriptor));
+        } else if descriptor[3] == TAG_MONITOR_NAME {
+            name = descriptor_text(&descriptor[5..]);
+        }
+    }
+
+    let (resolution, refresh_rate, timing_size) = match preferred {
+        Some(timing) => (Some(timing.resolution), timing.refresh_rate, timing.size_mm),
+        None => (None, None, None),
+    };
+
+    // Prefer the millimetre size from the timing; bytes 21/22 only carry centimetres
+    let size_mm = timing_size.or_else(|| {
+        let (width_cm, height_cm) = (u32::from(block[21]), u32::from(block[22]));
+        (width_cm > 0 && height_cm > 0).then_some((width_cm * 10, height_cm * 10))
+    });
+
+    Ok(Edid {
+        manufacturer_id,
+        product_code,
+        name,
+        resolution,
+        refresh_rate,
+        size_mm,
+    })
+}
+
+struct Timing {
+    resolution: (u32, u32),
+    refresh_rate: Option<f64>,
+    size_mm: Option<(u32, u32)>,
+}
+
+/// Decode an 18-byte detailed timing descriptor
+fn parse_timing(d: &[u8]) -> Timing {
+    let pixel_clock = u32::from(u16::from_le_bytes([d[0], d[1]])) * 10_000;
+    let h_active = u32::from(d[2]) | (u32::from(d[4] & 0xF0) << 4);
+    let h_blank = u32::from(d[3]) | (u32::from(d[4] & 0x0F) << 8);
+    let v_active = u32::from(d[5]) | (u32::from(d[7] & 0xF0) << 4);
+    let v_blank = u32::from(d[6]) | (u32::from(d[7] & 0x0F) << 8);
+    let width_mm = u32::from(d[12]) | (u32::from(d[14] & 0xF0) << 4);
+    let height_mm = u32::from(d[13]) | (u32::from(d[14] & 0x0F) << 8);
+
+    let total = (h_active + h_blank) * (v_active + v_blank);
+    Timing {
+        resolution: (h_active, v_active),
+        refresh_rate: (total > 0).then(|| f64::from(pixel_clock) / f64::from(total)),
+        size_mm: (width_mm > 0 && height_mm > 0).then_some((width_mm, height_mm)),
+    }
+}
+
+/// Text in display descriptors ends at a newline and is padded with spaces
+fn descriptor_text(data: &[u8]) -> Option<String> {
+    let end = data.iter().position(|&b| b == 0x0A).unwrap_or(data.len());
+    let text = String::from_utf8_lossy(&data[..end]).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Common display vendors by PNP ID
+fn pnp_vendor(id: &str) -> Option<&'static str> {
+    let vendor = match id {
+        "ACR" => "Acer",
+        "AOC" => "AOC",
+        "APP" => "Apple",
+        "AUO" => "AU Optronics",
+        "AUS" => "ASUS",
+        "BNQ" => "BenQ",
+        "BOE" => "BOE",
+        "CMN" => "Chimei Innolux",
+        "DEL" => "Dell",
+        "ENC" => "EIZO",
+        "GBT" => "Gigabyte",
+        "GSM" => "LG",
+        "HWP" => "HP",
+        "IVM" => "iiyama",
+        "LEN" => "Lenovo",
+        "LGD" => "LG Display",
+        "MSI" => "MSI",
+        "NEC" => "NEC",
+        "PHL" => "Philips",
+        "SAM" => "Samsung",
+        "SDC" => "Samsung Display",
+        "SHP" => "Sharp",
+        "SNY" => "Sony",
+        "VSC" => "ViewSonic",
+        _ => return None,
+    };
+    Some(vendor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a base block for a 27" 2560x1440@60 Dell with a name descriptor
+    fn fixture_dell() -> Vec<u8> {
+        let mut edid = vec![0u8; BLOCK_SIZE];
+        edid[..8].copy_from_slice(&HEADER);
+        // "DEL" = 4, 5, 12
+        edid[8..10].copy_from_slice(&((4u16 << 10) | (5 << 5) | 12).to_be_bytes());
+        edid[10..12].copy_from_slice(&0xA0F5u16.to_le_bytes());
+        edid[21] = 60;
+        edid[22] = 34;
+
+        // 2560x1440, 160 h-blank, 41 v-blank, 241.5 MHz, 597x336 mm
+        let timing = &mut edid[54..72];
+        timing[..2].copy_from_slice(&24150u16.to_le_bytes());
+        timing[2] = 0x00;
+        timing[3] = 0xA0;
+        timing[4] = 0xA0;
+        timing[5] = 0xA0;
+        timing[6] = 0x29;
+        timing[7] = 0x50;
+        timing[12] = 0x55;
+        timing[13] = 0x50;
+        timing[14] = 0x21;
+
+        let name = &mut edid[72..90];
+        name[3] = TAG_MONITOR_NAME;
+        name[5..18].copy_from_slice(b"DELL S2721DGF");
+
+        let checksum = edid.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        edid[127] = 0u8.wrapping_sub(checksum);
+        edid
+    }
+
+    #[test]
+    fn test_parse_edid() {
+        let edid = parse_edid(&fixture_dell()).unwrap();
+
+        assert_eq!(edid.manufacturer_id, "DEL");
+        assert_eq!(edid.manufacturer(), "Dell");
+        assert_eq!(edid.product_code, 0xA0F5);
+        assert_eq!(edid.name.as_deref(), Some("DELL S2721DGF"));
+        assert_eq!(edid.resolution, Some((2560, 1440)));
+        assert_eq!(edid.size_mm, Some((597, 336)));
+        assert_eq!(edid.refresh_rate.map(f64::round), Some(60.0));
+        assert_eq!(edid.diagonal_inches().map(f64::round), Some(27.0));
+    }
+
+    #[test]
+    fn test_parse_edid_rejects_corrupt_blobs() {
+        let mut edid = fixture_dell();
+        edid[100] ^= 0xFF;
+        assert!(parse_edid(&edid).is_err());
+        assert!(parse_edid(&edid[..64]).is_err());
+        assert!(parse_edid(&[0u8; BLOCK_SIZE]).is_err());
+    }
+}
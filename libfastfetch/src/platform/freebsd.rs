@@ -1,3 +1,14 @@
 //! FreeBSD-specific implementations
 
-// Platform-specific detection functions will go here
+use crate::context::SystemContext;
+
+/// Read a kernel environment variable such as `smbios.planar.maker`
+pub fn kenv(ctx: &dyn SystemContext, name: &str) -> Option<String> {
+    let output = ctx.execute_command("kenv", &["-q", name]).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
@@ -0,0 +1,358 @@
+//! D-Bus access for Linux via `busctl`
+//!
+//! Replies are decoded from busctl's command-line output format, where each
+//! value follows its type signature, arrays are prefixed with their length
+//! and variants with their contained signature.
+
+use crate::context::SystemContext;
+use std::io;
+
+/// Message bus to connect to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbusBus {
+    System,
+    Session,
+}
+
+impl DbusBus {
+    /// busctl flag selecting this bus
+    pub const fn flag(self) -> &'static str {
+        match self {
+            Self::System => "--system",
+            Self::Session => "--user",
+        }
+    }
+}
+
+/// A decoded D-Bus value
+#[derive(Debug, Clone, PartialEq)]
+pub enum DbusValue {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Double(f64),
+    /// Strings, object paths and signatures
+    String(String),
+    Array(Vec<DbusValue>),
+    Dict(Vec<(DbusValue, DbusValue)>),
+    Struct(Vec<DbusValue>),
+    Variant(Box<DbusValue>),
+}
+
+impl DbusValue {
+    /// Look through any variant wrappers
+    pub fn unwrap_variant(&self) -> &Self {
+        match self {
+            Self::Variant(inner) => inner.unwrap_variant(),
+            other => other,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self.unwrap_variant() {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.unwrap_variant() {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.unwrap_variant() {
+            Self::UInt(n) => Some(*n),
+            Self::Int(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.unwrap_variant() {
+            Self::Double(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[DbusValue]> {
+        match self.unwrap_variant() {
+            Self::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_dict(&self) -> Option<&[(DbusValue, DbusValue)]> {
+        match self.unwrap_variant() {
+            Self::Dict(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Look up a string key in a dictionary, unwrapping variant values
+    pub fn get(&self, key: &str) -> Option<&DbusValue> {
+        self.as_dict()?
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, v)| v.unwrap_variant())
+    }
+}
+
+/// Read a property through `org.freedesktop.DBus.Properties.Get`
+pub fn get_property(
+    ctx: &dyn SystemContext,
+    bus: DbusBus,
+    destination: &str,
+    path: &str,
+    interface: &str,
+    property: &str,
+) -> io::Result<DbusValue> {
+    ctx.dbus_call(
+        bus,
+        destination,
+        path,
+        "org.freedesktop.DBus.Properties",
+        "Get",
+        &[interface, property],
+    )
+    .map(|value| value.unwrap_variant().clone())
+}
+
+/// List the names currently owned on a bus
+pub fn list_names(ctx: &dyn SystemContext, bus: DbusBus) -> io::Result<Vec<String>> {
+    let reply = ctx.dbus_call(
+        bus,
+        "org.freedesktop.DBus",
+        "/org/freedesktop/DBus",
+        "org.freedesktop.DBus",
+        "ListNames",
+        &[],
+    )?;
+
+    Ok(reply
+        .as_array()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|name| name.as_str().map(str::to_string))
+        .collect())
+}
+
+/// Parse the output of `busctl call` or `busctl get-property`
+///
+/// Replies carrying more than one value are returned as a `Struct`.
+pub fn parse_busctl_output(output: &str) -> io::Result<DbusValue> {
+    let mut tokens = tokenize(output).into_iter();
+    let signature = tokens.next().ok_or_else(|| invalid("empty reply"))?;
+
+    let mut values = split_types(&signature)?
+        .into_iter()
+        .map(|ty| parse_value(ty, &mut tokens))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    if values.len() == 1 {
+        Ok(values.remove(0))
+    } else {
+        Ok(DbusValue::Struct(values))
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid busctl output: {msg}"),
+    )
+}
+
+/// Decode one value of complete type `ty` from the token stream
+fn parse_value(ty: &str, tokens: &mut impl Iterator<Item = String>) -> io::Result<DbusValue> {
+    let value = match ty.as_bytes().first() {
+        Some(b'b') => DbusValue::Bool(next_token(tokens)? == "true"),
+        Some(b'y' | b'q' | b'u' | b't' | b'h') => DbusValue::UInt(
+            next_token(tokens)?
+                .parse()
+                .map_err(|_| invalid("bad unsigned integer"))?,
+        ),
+        Some(b'n' | b'i' | b'x') => DbusValue::Int(
+            next_token(tokens)?
+                .parse()
+                .map_err(|_| invalid("bad integer"))?,
+        ),
+        Some(b'd') => DbusValue::Double(
+            next_token(tokens)?
+                .parse()
+                .map_err(|_| invalid("bad double"))?,
+        ),
+        Some(b's' | b'o' | b'g') => DbusValue::String(next_token(tokens)?),
+        Some(b'v') => {
+            let signature = next_token(tokens)?;
+            DbusValue::Variant(Box::new(parse_value(&signature, tokens)?))
+        }
+        Some(b'a') => {
+            let count: usize = next_token(tokens)?
+                .parse()
+                .map_err(|_| invalid("bad array length"))?;
+            let element = &ty[1..];
+
+            if let Some(entry) = element.strip_prefix('{').and_then(|e| e.strip_suffix('}')) {
+                let [key_ty, value_ty] = split_types(entry)?[..] else {
+                    return Err(invalid("bad dict entry signature"));
+                };
+                let mut entries = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let key = parse_value(key_ty, tokens)?;
+                    entries.push((key, parse_value(value_ty, tokens)?));
+                }
+                DbusValue::Dict(entries)
+            } else {
+                let items = (0..count)
+                    .map(|_| parse_value(element, tokens))
+                    .collect::<io::Result<Vec<_>>>()?;
+                DbusValue::Array(items)
+            }
+        }
+        Some(b'(') => {
+            let inner = &ty[1..ty.len() - 1];
+            let fields = split_types(inner)?
+                .into_iter()
+                .map(|field| parse_value(field, tokens))
+                .collect::<io::Result<Vec<_>>>()?;
+            DbusValue::Struct(fields)
+        }
+        _ => return Err(invalid("unsupported signature")),
+    };
+
+    Ok(value)
+}
+
+fn next_token(tokens: &mut impl Iterator<Item = String>) -> io::Result<String> {
+    tokens.next().ok_or_else(|| invalid("truncated reply"))
+}
+
+/// Split a signature into its complete types
+fn split_types(signature: &str) -> io::Result<Vec<&str>> {
+    let mut types = Vec::new();
+    let mut rest = signature;
+
+    while !rest.is_empty() {
+        let len = type_len(rest.as_bytes()).ok_or_else(|| invalid("bad signature"))?;
+        types.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+
+    Ok(types)
+}
+
+/// Length of the first complete type in a signature
+fn type_len(signature: &[u8]) -> Option<usize> {
+    match signature.first()? {
+        b'a' => type_len(&signature[1..]).map(|len| len + 1),
+        open @ (b'(' | b'{') => {
+            let close = if *open == b'(' { b')' } else { b'}' };
+            let mut depth = 0;
+            for (i, &c) in signature.iter().enumerate() {
+                if c == *open {
+                    depth += 1;
+                } else if c == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i + 1);
+                    }
+                }
+            }
+            None
+        }
+        _ => Some(1),
+    }
+}
+
+/// Split output on whitespace, decoding C-escaped double-quoted strings
+fn tokenize(output: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = output.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut bytes = Vec::new();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => break,
+                    '\\' => match chars.next() {
+                        Some('n') => bytes.push(b'\n'),
+                        Some('t') => bytes.push(b'\t'),
+                        Some('r') => bytes.push(b'\r'),
+                        Some('x') => {
+                            let hex: String = chars.by_ref().take(2).collect();
+                            if let Ok(byte) = u8::from_str_radix(&hex, 16) {
+                                bytes.push(byte);
+                            }
+                        }
+                        Some(other) => {
+                            bytes.extend_from_slice(other.encode_utf8(&mut [0; 4]).as_bytes())
+                        }
+                        None => break,
+                    },
+                    _ => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                }
+            }
+            tokens.push(String::from_utf8_lossy(&bytes).into_owned());
+        } else {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_variant_property() {
+        let value = parse_busctl_output("v s \"Caf\\xc3\\xa9 \\\"Radio\\\"\"\n").unwrap();
+        assert_eq!(value.as_str(), Some("Café \"Radio\""));
+
+        let value =
+            parse_busctl_output("as 2 \"org.bluez\" \"org.mpris.MediaPlayer2.spotify\"").unwrap();
+        assert_eq!(value.as_array().map(<[_]>::len), Some(2));
+    }
+
+    #[test]
+    fn test_parse_managed_objects() {
+        let output = concat!(
+            "a{oa{sa{sv}}} 2 ",
+            "\"/org/bluez/hci0\" 1 \"org.bluez.Adapter1\" 2 \"Alias\" s \"laptop\" \"Powered\" b true ",
+            "\"/org/bluez/hci0/dev_AA\" 2 \"org.bluez.Device1\" 1 \"Connected\" b true ",
+            "\"org.bluez.Battery1\" 1 \"Percentage\" y 80\n"
+        );
+
+        let objects = parse_busctl_output(output).unwrap();
+        let adapter = objects.get("/org/bluez/hci0").unwrap();
+        assert_eq!(
+            adapter
+                .get("org.bluez.Adapter1")
+                .unwrap()
+                .get("Alias")
+                .unwrap()
+                .as_str(),
+            Some("laptop")
+        );
+
+        let device = objects.get("/org/bluez/hci0/dev_AA").unwrap();
+        let battery = device.get("org.bluez.Battery1").unwrap().get("Percentage");
+        assert_eq!(battery.and_then(DbusValue::as_u64), Some(80));
+    }
+}
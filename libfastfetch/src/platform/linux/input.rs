@@ -0,0 +1,211 @@
+//! Input device enumeration for Linux
+//!
+//! Parses `/proc/bus/input/devices`, which lists every registered input
+//! device as a blank-line separated block:
+//!
+//! ```text
+//! I: Bus=0003 Vendor=046d Product=c52b Version=0111
+//! N: Name="Logitech USB Receiver"
+//! H: Handlers=mouse0 event4
+//! B: EV=17
+//! ```
+
+use crate::context::SystemContext;
+use std::path::Path;
+
+/// `EV_REP` bit of the event type bitmap; set by devices with key autorepeat
+const EV_REP: u64 = 1 << 20;
+
+/// Bus an input device is attached through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputBus {
+    Usb,
+    Bluetooth,
+    Ps2,
+    I2c,
+    Virtual,
+    Other(u16),
+}
+
+impl InputBus {
+    /// Map a `BUS_*` constant from `linux/input.h`
+    pub const fn from_id(id: u16) -> Self {
+        match id {
+            0x03 => Self::Usb,
+            0x05 => Self::Bluetooth,
+            0x06 => Self::Virtual,
+            0x11 => Self::Ps2,
+            0x18 => Self::I2c,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// A device from `/proc/bus/input/devices`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDevice {
+    pub bus: InputBus,
+    pub vendor: u16,
+    pub product: u16,
+    pub name: String,
+    pub handlers: Vec<String>,
+    /// Supported event types bitmap (`B: EV=`)
+    pub events: u64,
+}
+
+impl InputDevice {
+    /// Mice, touchpads and trackballs get a `mouseN` handler
+    pub fn is_pointer(&self) -> bool {
+        self.handlers.iter().any(|h| h.starts_with("mouse"))
+    }
+
+    /// Keyboards get a `kbd` handler and support key autorepeat, which
+    /// excludes power buttons and media-key-only devices
+    pub fn is_keyboard(&self) -> bool {
+        self.handlers.iter().any(|h| h == "kbd") && self.events & EV_REP != 0
+    }
+}
+
+/// Parse the content of `/proc/bus/input/devices`
+pub fn parse_input_devices(content: &str) -> Vec<InputDevice> {
+    content
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut device = InputDevice {
+                bus: InputBus::Other(0),
+                vendor: 0,
+                product: 0,
+                name: String::new(),
+                handlers: Vec::new(),
+                events: 0,
+            };
+
+            for line in block.lines() {
+                let Some((tag, rest)) = line.split_once(": ") else {
+                    continue;
+                };
+                match tag {
+                    "I" => {
+                        for field in rest.split_whitespace() {
+                            let Some((key, value)) = field.split_once('=') else {
+                                continue;
+                            };
+                            let value = u16::from_str_radix(value, 16).unwrap_or_default();
+                            match key {
+                                "Bus" => device.bus = InputBus::from_id(value),
+                                "Vendor" => device.vendor = value,
+                                "Product" => device.product = value,
+                                _ => {}
+                            }
+                        }
+                    }
+                    "N" => {
+                        let name = rest.strip_prefix("Name=").unwrap_or(rest);
+                        device.name = name.trim_matches('"').to_string();
+                    }
+                    "H" => {
+                        let handlers = rest.strip_prefix("Handlers=").unwrap_or(rest);
+                        device.handlers = handlers.split_whitespace().map(str::to_string).collect();
+                    }
+                    "B" => {
+                        if let Some(events) = rest.strip_prefix("EV=") {
+                            device.events = u64::from_str_radix(events, 16).unwrap_or_default();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            (!device.name.is_empty()).then_some(device)
+        })
+        .collect()
+}
+
+/// Read and parse `/proc/bus/input/devices`
+pub fn input_devices(ctx: &dyn SystemContext) -> std::io::Result<Vec<InputDevice>> {
+    ctx.read_file(Path::new("/proc/bus/input/devices"))
+        .map(|content| parse_input_devices(&content))
+}
+
+/// Battery levels of wireless peripherals as (model name, percent)
+///
+/// Peripheral batteries appear in `/sys/class/power_supply` with `scope`
+/// set to "Device", as opposed to the system battery.
+pub fn peripheral_batteries(ctx: &dyn SystemContext) -> Vec<(String, u8)> {
+    let base = Path::new("/sys/class/power_supply");
+    let Ok(supplies) = ctx.read_dir(base) else {
+        return Vec::new();
+    };
+
+    supplies
+        .iter()
+        .filter_map(|supply| {
+            let read = |file: &str| {
+                ctx.read_file(&base.join(supply).join(file))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+            };
+
+            if read("scope")? != "Device" {
+                return None;
+            }
+            let capacity = read("capacity")?.parse().ok()?;
+            let model = read("model_name").filter(|model| !model.is_empty())?;
+            Some((model, capacity))
+        })
+        .collect()
+}
+
+/// Find the battery reported for a device, matching model names loosely since
+/// drivers and HID descriptors often disagree on the vendor prefix
+pub fn battery_for(batteries: &[(String, u8)], device_name: &str) -> Option<u8> {
+    batteries
+        .iter()
+        .find(|(model, _)| device_name.contains(model.as_str()) || model.contains(device_name))
+        .map(|&(_, capacity)| capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEVICES: &str = "\
+I: Bus=0011 Vendor=0001 Product=0001 Version=ab41
+N: Name=\"AT Translated Set 2 keyboard\"
+H: Handlers=sysrq kbd event0 leds
+B: EV=120013
+
+I: Bus=0019 Vendor=0000 Product=0001 Version=0000
+N: Name=\"Power Button\"
+H: Handlers=kbd event2
+B: EV=3
+
+I: Bus=0005 Vendor=046d Product=b034 Version=0001
+N: Name=\"MX Master 3S\"
+H: Handlers=sysrq kbd leds event5 mouse1
+B: EV=12001f
+";
+
+    #[test]
+    fn test_parse_input_devices() {
+        let devices = parse_input_devices(DEVICES);
+        assert_eq!(devices.len(), 3);
+
+        assert_eq!(devices[0].bus, InputBus::Ps2);
+        assert!(devices[0].is_keyboard());
+        assert!(!devices[0].is_pointer());
+
+        assert!(!devices[1].is_keyboard());
+
+        assert_eq!(devices[2].bus, InputBus::Bluetooth);
+        assert_eq!(devices[2].vendor, 0x046d);
+        assert!(devices[2].is_pointer());
+    }
+
+    #[test]
+    fn test_battery_for() {
+        let batteries = vec![("MX Master 3S".to_string(), 80)];
+        assert_eq!(battery_for(&batteries, "Logitech MX Master 3S"), Some(80));
+        assert_eq!(battery_for(&batteries, "G305"), None);
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Platform layer for parsing /proc, /sys, and other Linux-specific interfaces
 
+pub mod dbus;
+pub mod input;
+pub mod packages;
 pub mod proc;
 pub mod sys;
+pub mod utmp;
 
 use std::io;
 
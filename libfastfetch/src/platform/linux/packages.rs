@@ -0,0 +1,147 @@
+//! Package manager backends for Linux
+//!
+//! Each backend counts installed packages, preferring to read the package
+//! database directly and only shelling out when the database format is opaque
+//! (rpm's sqlite store, the nix store graph).
+
+use crate::context::SystemContext;
+use std::path::Path;
+
+/// Count entries of a package database directory, skipping hidden and excluded names
+fn count_entries(ctx: &dyn SystemContext, path: &str, exclude: &[&str]) -> Option<usize> {
+    let entries = ctx.read_dir(Path::new(path)).ok()?;
+    let count = entries
+        .iter()
+        .filter(|name| !name.starts_with('.') && !exclude.contains(&name.as_str()))
+        .count();
+    Some(count)
+}
+
+/// Count non-empty stdout lines of a successful command
+fn count_command_lines(ctx: &dyn SystemContext, program: &str, args: &[&str]) -> Option<usize> {
+    let output = ctx.execute_command(program, args).ok()?;
+    if !output.success {
+        return None;
+    }
+
+    let count = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count();
+    Some(count)
+}
+
+/// pacman: one directory per package in the local database
+pub fn count_pacman(ctx: &dyn SystemContext) -> Option<usize> {
+    count_entries(ctx, "/var/lib/pacman/local", &["ALPM_DB_VERSION"])
+}
+
+/// dpkg: entries in the status file marked as installed
+pub fn count_dpkg(ctx: &dyn SystemContext) -> Option<usize> {
+    let status = ctx.read_file(Path::new("/var/lib/dpkg/status")).ok()?;
+    Some(count_dpkg_status(&status))
+}
+
+/// Count installed packages in dpkg status file content
+pub fn count_dpkg_status(status: &str) -> usize {
+    status
+        .lines()
+        .filter(|line| *line == "Status: install ok installed")
+        .count()
+}
+
+/// rpm: the database is sqlite (or Berkeley DB on older systems), so ask rpm
+pub fn count_rpm(ctx: &dyn SystemContext) -> Option<usize> {
+    let has_db = ["/var/lib/rpm", "/usr/lib/sysimage/rpm"]
+        .iter()
+        .any(|path| Path::new(path).is_dir());
+    if !has_db {
+        return None;
+    }
+
+    count_command_lines(ctx, "rpm", &["-qa"])
+}
+
+/// apk: one `P:` (package name) line per installed package
+pub fn count_apk(ctx: &dyn SystemContext) -> Option<usize> {
+    let installed = ctx.read_file(Path::new("/lib/apk/db/installed")).ok()?;
+    Some(
+        installed
+            .lines()
+            .filter(|line| line.starts_with("P:"))
+            .count(),
+    )
+}
+
+/// nix: store paths required by the system profile plus the user profile
+pub fn count_nix(ctx: &dyn SystemContext) -> Option<usize> {
+    let mut total = None;
+
+    let mut profiles = vec!["/run/current-system/sw".to_string()];
+    if let Some(home) = ctx.get_env("HOME") {
+        profiles.push(format!("{home}/.nix-profile"));
+    }
+
+    for profile in &profiles {
+        if !Path::new(profile).exists() {
+            continue;
+        }
+        if let Some(count) =
+            count_command_lines(ctx, "nix-store", &["--query", "--requisites", profile])
+        {
+            *total.get_or_insert(0) += count;
+        }
+    }
+
+    total
+}
+
+/// flatpak: installed apps in the system and user installations
+pub fn count_flatpak(ctx: &dyn SystemContext) -> Option<usize> {
+    let system = count_entries(ctx, "/var/lib/flatpak/app", &[]);
+    let user = ctx
+        .get_env("HOME")
+        .and_then(|home| count_entries(ctx, &format!("{home}/.local/share/flatpak/app"), &[]));
+
+    match (system, user) {
+        (None, None) => None,
+        (system, user) => Some(system.unwrap_or(0) + user.unwrap_or(0)),
+    }
+}
+
+/// snap: one directory per snap under /snap
+pub fn count_snap(ctx: &dyn SystemContext) -> Option<usize> {
+    count_entries(ctx, "/snap", &["bin", "README"])
+}
+
+/// brew (Linuxbrew): formulae in the Cellar plus casks
+pub fn count_brew(ctx: &dyn SystemContext) -> Option<usize> {
+    let prefix = "/home/linuxbrew/.linuxbrew";
+    let cellar = count_entries(ctx, &format!("{prefix}/Cellar"), &[]);
+    let casks = count_entries(ctx, &format!("{prefix}/Caskroom"), &[]);
+
+    match (cellar, casks) {
+        (None, None) => None,
+        (cellar, casks) => Some(cellar.unwrap_or(0) + casks.unwrap_or(0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_dpkg_status() {
+        let status = "\
+Package: bash
+Status: install ok installed
+
+Package: removed-pkg
+Status: deinstall ok config-files
+
+Package: coreutils
+Status: install ok installed
+";
+        assert_eq!(count_dpkg_status(status), 2);
+    }
+}
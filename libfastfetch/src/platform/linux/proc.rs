@@ -69,6 +69,11 @@ pub fn parse_version() -> io::Result<String> {
 /// Parse /proc/loadavg
 pub fn parse_loadavg() -> io::Result<(f64, f64, f64)> {
     let content = std::fs::read_to_string("/proc/loadavg")?;
+    parse_loadavg_content(&content)
+}
+
+/// Parse the 1, 5, and 15 minute load averages from /proc/loadavg content
+pub fn parse_loadavg_content(content: &str) -> io::Result<(f64, f64, f64)> {
     let parts: Vec<&str> = content.split_whitespace().collect();
 
     if parts.len() >= 3 {
@@ -91,6 +96,122 @@ pub fn parse_loadavg() -> io::Result<(f64, f64, f64)> {
     }
 }
 
+/// Parse the command name and parent PID out of `/proc/<pid>/stat` content
+///
+/// The command name is wrapped in parentheses and may itself contain spaces
+/// or parentheses, so the split happens on the last closing parenthesis.
+pub fn parse_stat(content: &str) -> Option<(String, u32)> {
+    let open = content.find('(')?;
+    let close = content.rfind(')')?;
+    let comm = content.get(open + 1..close)?.to_string();
+
+    // Fields after the command name: state, ppid, ...
+    let mut fields = content.get(close + 1..)?.split_whitespace();
+    let _state = fields.next()?;
+    let ppid = fields.next()?.parse().ok()?;
+
+    Some((comm, ppid))
+}
+
+/// Cumulative I/O counters of one block device from /proc/diskstats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskStat {
+    pub name: String,
+    pub sectors_read: u64,
+    pub sectors_written: u64,
+}
+
+/// Parse /proc/diskstats content
+///
+/// Columns: major minor name reads merged sectors_read ms writes merged
+/// sectors_written ... Sectors are always 512 bytes here, regardless of the
+/// device's logical block size.
+pub fn parse_diskstats_content(content: &str) -> Vec<DiskStat> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            Some(DiskStat {
+                name: fields.get(2)?.to_string(),
+                sectors_read: fields.get(5)?.parse().ok()?,
+                sectors_written: fields.get(9)?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// A loaded kernel module from /proc/modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelModule {
+    pub name: String,
+    /// Taint flags such as 'O' (out-of-tree) or 'P' (proprietary)
+    pub taints: Vec<char>,
+}
+
+/// Parse /proc/modules content
+///
+/// Rows look like `nvidia 56885248 1 nvidia_modeset, Live 0x0000000000000000 (POE)`,
+/// with the parenthesised taint flags only present for tainting modules.
+pub fn parse_modules_content(content: &str) -> Vec<KernelModule> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let name = line.split_whitespace().next()?.to_string();
+            let taints = line
+                .trim_end()
+                .strip_suffix(')')
+                .and_then(|rest| rest.rsplit_once('('))
+                .map(|(_, flags)| flags.chars().collect())
+                .unwrap_or_default();
+            Some(KernelModule { name, taints })
+        })
+        .collect()
+}
+
+/// Cumulative traffic counters of one interface from /proc/net/dev
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetDevStat {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Parse /proc/net/dev content
+///
+/// After the two header lines each row is `iface: rx_bytes packets errs drop
+/// fifo frame compressed multicast tx_bytes ...`.
+pub fn parse_net_dev_content(content: &str) -> Vec<NetDevStat> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (name, counters) = line.split_once(':')?;
+            let fields: Vec<&str> = counters.split_whitespace().collect();
+            Some(NetDevStat {
+                name: name.trim().to_string(),
+                rx_bytes: fields.first()?.parse().ok()?,
+                tx_bytes: fields.get(8)?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Interface of the IPv4 default route from /proc/net/route content
+///
+/// The route with the lowest metric wins when several default routes exist.
+pub fn parse_default_route_content(content: &str) -> Option<String> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            // Iface Destination Gateway Flags RefCnt Use Metric ...
+            let metric: u32 = fields.get(6)?.parse().ok()?;
+            (*fields.get(1)? == "00000000").then(|| (metric, fields[0].to_string()))
+        })
+        .min()
+        .map(|(_, iface)| iface)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +229,80 @@ mod tests {
         let info = parse_cpuinfo().unwrap();
         assert!(info.contains_key("model name") || info.contains_key("cpu model"));
     }
+
+    #[test]
+    fn test_parse_loadavg_content() {
+        let loadavg = "0.52 0.61 0.70 2/1234 56789\n";
+        assert_eq!(parse_loadavg_content(loadavg).unwrap(), (0.52, 0.61, 0.70));
+        assert!(parse_loadavg_content("0.52").is_err());
+    }
+
+    #[test]
+    fn test_parse_diskstats_content() {
+        let diskstats = "\
+ 259       0 nvme0n1 120 5 8192 40 300 10 16384 90 0 100 130 0 0 0 0 0 0
+ 259       1 nvme0n1p1 10 0 512 4 0 0 0 0 0 4 4 0 0 0 0 0 0
+";
+        let stats = parse_diskstats_content(diskstats);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].name, "nvme0n1");
+        assert_eq!(stats[0].sectors_read, 8192);
+        assert_eq!(stats[0].sectors_written, 16384);
+    }
+
+    #[test]
+    fn test_parse_net_dev_content() {
+        let net_dev = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:    9000     100    0    0    0     0          0         0     9000     100    0    0    0     0       0          0
+  eth0:123456789 100000   0    0    0     0          0         0 98765432   90000    0    0    0     0       0          0
+";
+        let stats = parse_net_dev_content(net_dev);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[1],
+            NetDevStat {
+                name: "eth0".to_string(),
+                rx_bytes: 123456789,
+                tx_bytes: 98765432,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_default_route_content() {
+        let route = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+eth0\t00000000\t0101A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t0001A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+";
+        assert_eq!(parse_default_route_content(route).as_deref(), Some("eth0"));
+        assert_eq!(parse_default_route_content("Iface\tDestination\n"), None);
+    }
+
+    #[test]
+    fn test_parse_modules_content() {
+        let modules = "\
+nvidia_drm 126976 4 - Live 0x0000000000000000 (PO)
+ext4 1064960 1 - Live 0x0000000000000000
+";
+        let parsed = parse_modules_content(modules);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].name, "nvidia_drm");
+        assert_eq!(parsed[0].taints, ['P', 'O']);
+        assert!(parsed[1].taints.is_empty());
+    }
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = "4242 (tmux: server) S 1 4242 4242 0 -1 4194560 1234";
+        assert_eq!(parse_stat(stat), Some(("tmux: server".to_string(), 1)));
+
+        let stat = "77 (weird) name)) R 76 77 77 0";
+        assert_eq!(parse_stat(stat), Some(("weird) name)".to_string(), 76)));
+
+        assert_eq!(parse_stat("garbage"), None);
+    }
 }
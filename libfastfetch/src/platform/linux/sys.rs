@@ -41,6 +41,16 @@ pub mod dmi {
         read_dmi_field("board_vendor")
     }
 
+    /// Get board version
+    pub fn board_version() -> io::Result<String> {
+        read_dmi_field("board_version")
+    }
+
+    /// Get BIOS vendor
+    pub fn bios_vendor() -> io::Result<String> {
+        read_dmi_field("bios_vendor")
+    }
+
     /// Get BIOS version
     pub fn bios_version() -> io::Result<String> {
         read_dmi_field("bios_version")
@@ -55,6 +65,13 @@ pub mod dmi {
     pub fn chassis_type() -> io::Result<String> {
         read_dmi_field("chassis_type")
     }
+
+    /// Read a DMI field, treating empty and placeholder values as missing
+    pub fn meaningful(read: fn() -> io::Result<String>) -> Option<String> {
+        read()
+            .ok()
+            .filter(|value| !crate::platform::is_smbios_placeholder(value))
+    }
 }
 
 /// Read thermal information
@@ -88,6 +105,75 @@ pub mod thermal {
 
         Ok(zones)
     }
+
+    /// Get the type of a thermal zone (e.g. "x86_pkg_temp", "cpu-thermal", "acpitz")
+    pub fn zone_type(zone: usize) -> io::Result<String> {
+        let path = format!("/sys/class/thermal/thermal_zone{zone}/type");
+        std::fs::read_to_string(&path).map(|s| s.trim().to_string())
+    }
+
+    /// hwmon drivers that report CPU temperatures
+    const CPU_HWMON_DRIVERS: &[&str] =
+        &["coretemp", "k10temp", "zenpower", "k8temp", "cpu_thermal"];
+
+    /// Sensor labels identifying the CPU package (Intel) or die (AMD) reading
+    const CPU_PACKAGE_LABELS: &[&str] = &["Package id 0", "Tctl", "Tdie", "CPU"];
+
+    /// Get the CPU package temperature from hwmon, preferring labelled package sensors
+    pub fn cpu_package_temp() -> io::Result<f64> {
+        for entry in std::fs::read_dir("/sys/class/hwmon")? {
+            let dir = entry?.path();
+            let name = std::fs::read_to_string(dir.join("name")).unwrap_or_default();
+            if !CPU_HWMON_DRIVERS.contains(&name.trim()) {
+                continue;
+            }
+
+            let mut fallback = None;
+            for idx in 1..=32 {
+                let Ok(input) = std::fs::read_to_string(dir.join(format!("temp{idx}_input")))
+                else {
+                    continue;
+                };
+                let Ok(millidegrees) = input.trim().parse::<i64>() else {
+                    continue;
+                };
+                let temp = millidegrees as f64 / 1000.0;
+
+                let label = std::fs::read_to_string(dir.join(format!("temp{idx}_label")))
+                    .unwrap_or_default();
+                if CPU_PACKAGE_LABELS.contains(&label.trim()) {
+                    return Ok(temp);
+                }
+                fallback.get_or_insert(temp);
+            }
+
+            if let Some(temp) = fallback {
+                return Ok(temp);
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No CPU hwmon sensor found",
+        ))
+    }
+
+    /// Get the CPU temperature from the first CPU-typed thermal zone
+    pub fn cpu_zone_temp() -> io::Result<f64> {
+        const CPU_ZONE_TYPES: &[&str] =
+            &["x86_pkg_temp", "cpu-thermal", "cpu_thermal", "soc_thermal"];
+
+        for (zone, temp) in all_zones()? {
+            if zone_type(zone).is_ok_and(|t| CPU_ZONE_TYPES.contains(&t.as_str())) {
+                return Ok(temp);
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No CPU thermal zone found",
+        ))
+    }
 }
 
 /// Read block device information
@@ -120,6 +206,22 @@ pub mod block {
 
         Ok(devices)
     }
+
+    /// Read a sysfs attribute of a block device, e.g. `queue/rotational`
+    pub fn read_attr(device: &str, attr: &str) -> io::Result<String> {
+        let path = format!("/sys/block/{device}/{attr}");
+        std::fs::read_to_string(&path).map(|s| s.trim().to_string())
+    }
+
+    /// Whether the device is backed by hardware (loop, zram and dm devices are not)
+    pub fn is_physical(device: &str) -> bool {
+        std::path::Path::new(&format!("/sys/block/{device}/device")).exists()
+    }
+
+    /// Resolved sysfs path of the device, which encodes the bus it hangs off
+    pub fn device_path(device: &str) -> io::Result<std::path::PathBuf> {
+        std::fs::canonicalize(format!("/sys/block/{device}"))
+    }
 }
 
 /// Read CPU frequency information
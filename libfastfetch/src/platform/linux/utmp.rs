@@ -0,0 +1,95 @@
+//! utmp login record parser for Linux (glibc layout)
+//!
+//! glibc stores login records as fixed-size `struct utmp` entries in
+//! `/run/utmp`. The layout is identical on all 64-bit and most 32-bit
+//! targets because glibc pins `ut_tv` to 32-bit fields.
+
+/// Size of a single `struct utmp` record
+pub const RECORD_SIZE: usize = 384;
+
+/// `ut_type` value for a normal user login
+const USER_PROCESS: i16 = 7;
+
+// Field offsets within a record
+const TYPE_OFFSET: usize = 0;
+const LINE_OFFSET: usize = 8;
+const LINE_LEN: usize = 32;
+const USER_OFFSET: usize = 44;
+const USER_LEN: usize = 32;
+const HOST_OFFSET: usize = 76;
+const HOST_LEN: usize = 256;
+
+/// A logged-in user session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtmpEntry {
+    pub user: String,
+    pub line: String,
+    pub host: Option<String>,
+}
+
+impl UtmpEntry {
+    /// Remote sessions record the client host; local X sessions record a display like ":0"
+    pub fn is_remote(&self) -> bool {
+        self.host
+            .as_deref()
+            .is_some_and(|host| !host.starts_with(':'))
+    }
+}
+
+/// Parse raw utmp content into user-process entries
+pub fn parse_utmp(data: &[u8]) -> Vec<UtmpEntry> {
+    data.chunks_exact(RECORD_SIZE)
+        .filter(|record| {
+            i16::from_ne_bytes([record[TYPE_OFFSET], record[TYPE_OFFSET + 1]]) == USER_PROCESS
+        })
+        .filter_map(|record| {
+            let user = c_string(&record[USER_OFFSET..USER_OFFSET + USER_LEN]);
+            if user.is_empty() {
+                return None;
+            }
+
+            let host = c_string(&record[HOST_OFFSET..HOST_OFFSET + HOST_LEN]);
+            Some(UtmpEntry {
+                user,
+                line: c_string(&record[LINE_OFFSET..LINE_OFFSET + LINE_LEN]),
+                host: (!host.is_empty()).then_some(host),
+            })
+        })
+        .collect()
+}
+
+/// Decode a NUL-padded fixed-size field
+fn c_string(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(ut_type: i16, user: &str, line: &str, host: &str) -> Vec<u8> {
+        let mut record = vec![0u8; RECORD_SIZE];
+        record[TYPE_OFFSET..TYPE_OFFSET + 2].copy_from_slice(&ut_type.to_ne_bytes());
+        record[LINE_OFFSET..LINE_OFFSET + line.len()].copy_from_slice(line.as_bytes());
+        record[USER_OFFSET..USER_OFFSET + user.len()].copy_from_slice(user.as_bytes());
+        record[HOST_OFFSET..HOST_OFFSET + host.len()].copy_from_slice(host.as_bytes());
+        record
+    }
+
+    #[test]
+    fn test_parse_utmp() {
+        let mut data = record(2, "reboot", "~", "6.9.1");
+        data.extend(record(USER_PROCESS, "alice", "tty1", ""));
+        data.extend(record(USER_PROCESS, "alice", "pts/0", ":0"));
+        data.extend(record(USER_PROCESS, "bob", "pts/1", "192.168.1.20"));
+
+        let entries = parse_utmp(&data);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].user, "alice");
+        assert!(!entries[0].is_remote());
+        assert!(!entries[1].is_remote());
+        assert!(entries[2].is_remote());
+        assert_eq!(entries[2].host.as_deref(), Some("192.168.1.20"));
+    }
+}
@@ -1,3 +1,37 @@
 //! macOS-specific implementations
 
-// Platform-specific detection functions will go here
+use crate::context::SystemContext;
+use std::path::Path;
+
+/// Count Homebrew formulae and casks under the Apple Silicon and Intel prefixes
+pub fn count_brew(ctx: &dyn SystemContext) -> Option<usize> {
+    let mut total = None;
+
+    for prefix in ["/opt/homebrew", "/usr/local"] {
+        for dir in ["Cellar", "Caskroom"] {
+            if let Ok(entries) = ctx.read_dir(Path::new(&format!("{prefix}/{dir}"))) {
+                *total.get_or_insert(0) +=
+                    entries.iter().filter(|name| !name.starts_with('.')).count();
+            }
+        }
+    }
+
+    total
+}
+
+/// Read a string property of the IOPlatformExpertDevice from the IORegistry
+///
+/// `ioreg` prints properties as `"model" = <"MacBookPro18,3">`.
+pub fn ioreg_platform_property(ctx: &dyn SystemContext, key: &str) -> Option<String> {
+    let output = ctx
+        .execute_command("ioreg", &["-rd1", "-c", "IOPlatformExpertDevice"])
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let needle = format!("\"{key}\" = ");
+
+    stdout.lines().find_map(|line| {
+        let value = line.trim().strip_prefix(&needle)?;
+        let value = value.strip_prefix("<\"")?.strip_suffix("\">")?;
+        Some(value.trim_end_matches('\0').to_string())
+    })
+}
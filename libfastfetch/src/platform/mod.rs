@@ -15,6 +15,36 @@ pub mod windows;
 #[cfg(target_os = "freebsd")]
 pub mod freebsd;
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod cpuid;
+pub mod desktop;
+pub mod edid;
+pub mod pci_ids;
+pub mod xdg;
+
+/// Filler strings firmware vendors leave in unset SMBIOS fields
+const SMBIOS_PLACEHOLDERS: &[&str] = &[
+    "To be filled by O.E.M.",
+    "Default string",
+    "Not Applicable",
+    "Not Specified",
+    "System Product Name",
+    "System manufacturer",
+    "System Version",
+    "None",
+    "N/A",
+    "0x0000",
+];
+
+/// Whether an SMBIOS/DMI value is empty or a known placeholder
+pub fn is_smbios_placeholder(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty()
+        || SMBIOS_PLACEHOLDERS
+            .iter()
+            .any(|placeholder| placeholder.eq_ignore_ascii_case(value))
+}
+
 /// Current operating system type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Platform {
@@ -54,3 +84,15 @@ impl Platform {
         !matches!(self, Self::Unknown)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smbios_placeholder() {
+        assert!(is_smbios_placeholder("To Be Filled By O.E.M."));
+        assert!(is_smbios_placeholder("  "));
+        assert!(!is_smbios_placeholder("ASUSTeK COMPUTER INC."));
+    }
+}
@@ -0,0 +1,111 @@
+//! PCI ID database lookup
+//!
+//! Resolves numeric vendor/device IDs to names using the `pci.ids` format.
+//! The system copy shipped by hwdata/pciutils is preferred; with the
+//! `pci-ids` feature a bundled subset is compiled in as a fallback.
+
+use crate::context::SystemContext;
+use std::borrow::Cow;
+
+/// Locations distributions install pci.ids to
+const SYSTEM_PATHS: &[&str] = &[
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+    "/usr/local/share/pciids/pci.ids",
+];
+
+#[cfg(feature = "pci-ids")]
+const BUNDLED: &str = include_str!("../../data/pci.ids");
+
+/// Load the pci.ids database, if one is available
+pub fn database(ctx: &dyn SystemContext) -> Option<Cow<'static, str>> {
+    let system = SYSTEM_PATHS
+        .iter()
+        .find_map(|path| ctx.read_file(std::path::Path::new(path)).ok())
+        .map(Cow::Owned);
+
+    #[cfg(feature = "pci-ids")]
+    let system = system.or(Some(Cow::Borrowed(BUNDLED)));
+
+    system
+}
+
+/// Look up vendor and device names
+///
+/// Vendors are unindented, their devices follow indented by one tab, and
+/// subsystems by two. The device class section starting at `C ` ends the
+/// vendor list.
+pub fn lookup(db: &str, vendor: u16, device: u16) -> (Option<&str>, Option<&str>) {
+    let mut vendor_name = None;
+    for line in db.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        if line.starts_with("C ") {
+            break;
+        }
+
+        if let Some(device_line) = line.strip_prefix('\t') {
+            if vendor_name.is_some()
+                && !device_line.starts_with('\t')
+                && let Some((id, name)) = parse_entry(device_line)
+                && id == device
+            {
+                return (vendor_name, Some(name));
+            }
+        } else if vendor_name.is_some() {
+            // Left our vendor's block without finding the device
+            break;
+        } else if let Some((id, name)) = parse_entry(line)
+            && id == vendor
+        {
+            vendor_name = Some(name);
+        }
+    }
+
+    (vendor_name, None)
+}
+
+/// Split an "id  name" line
+fn parse_entry(line: &str) -> Option<(u16, &str)> {
+    let (id, name) = line.split_once("  ")?;
+    Some((u16::from_str_radix(id, 16).ok()?, name.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let db = "\
+# comment
+10de  NVIDIA Corporation
+\t2684  AD102 [GeForce RTX 4090]
+\t\t1043 889d  ROG Strix GeForce RTX 4090
+1af4  Red Hat, Inc.
+\t1041  Virtio 1.0 network device
+
+C 02  Network controller
+";
+        assert_eq!(
+            lookup(db, 0x10de, 0x2684),
+            (Some("NVIDIA Corporation"), Some("AD102 [GeForce RTX 4090]"))
+        );
+        assert_eq!(
+            lookup(db, 0x10de, 0x1043),
+            (Some("NVIDIA Corporation"), None)
+        );
+        assert_eq!(lookup(db, 0x8086, 0x1041), (None, None));
+    }
+
+    #[cfg(feature = "pci-ids")]
+    #[test]
+    fn test_bundled_database() {
+        assert_eq!(
+            lookup(BUNDLED, 0x1af4, 0x1042),
+            (Some("Red Hat, Inc."), Some("Virtio 1.0 block device"))
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Configuration file discovery following the XDG base directory spec
+//!
+//! Helpers for locating user configuration of third-party programs (terminals,
+//! toolkits, desktop environments). All lookups go through `SystemContext`
+//! so callers stay testable.
+
+use crate::context::SystemContext;
+use std::path::{Path, PathBuf};
+
+/// The user's home directory from `$HOME` (or `%USERPROFILE%` on Windows)
+pub fn home_dir(ctx: &dyn SystemContext) -> Option<PathBuf> {
+    ctx.get_env("HOME")
+        .or_else(|| ctx.get_env("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// `$XDG_CONFIG_HOME`, falling back to `~/.config`
+pub fn config_home(ctx: &dyn SystemContext) -> Option<PathBuf> {
+    ctx.get_env("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir(ctx).map(|home| home.join(".config")))
+}
+
+/// Candidate paths for a file relative to the config home, then the home directory
+///
+/// For example `config_candidates(ctx, &["kitty/kitty.conf"], &[".kitty.conf"])`.
+pub fn config_candidates(
+    ctx: &dyn SystemContext,
+    config_relative: &[&str],
+    home_relative: &[&str],
+) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    if let Some(config) = config_home(ctx) {
+        candidates.extend(config_relative.iter().map(|rel| config.join(rel)));
+    }
+    if let Some(home) = home_dir(ctx) {
+        candidates.extend(home_relative.iter().map(|rel| home.join(rel)));
+    }
+
+    candidates
+}
+
+/// Read the first candidate file that exists, returning its path and content
+pub fn read_first<P: AsRef<Path>>(
+    ctx: &dyn SystemContext,
+    candidates: &[P],
+) -> Option<(PathBuf, String)> {
+    candidates.iter().find_map(|path| {
+        let path = path.as_ref();
+        ctx.read_file(path)
+            .ok()
+            .map(|content| (path.to_path_buf(), content))
+    })
+}